@@ -0,0 +1,338 @@
+use serde_json::{Map, Value};
+use solana_sdk::pubkey::Pubkey;
+
+use super::idl::{Idl, IdlFields, IdlType, IdlTypeDefTy};
+
+/// An account decoded against an IDL.
+#[derive(Clone, Debug, PartialEq)]
+pub struct DecodedAccount {
+    /// Name of the matching account type in the IDL.
+    pub name: String,
+    /// Decoded fields. Integers wider than 64 bits, public keys and byte
+    /// strings are rendered as strings, so the tree round-trips through
+    /// [`encode_value`](super::encode_value). The one exception is an empty
+    /// `COption`, whose reserved value bytes decode to `null` and aren't
+    /// written back.
+    pub value: Value,
+}
+
+/// Matches the 8-byte discriminator of `data` against the IDL's account types
+/// and decodes the remaining bytes.
+pub fn decode_account(idl: &Idl, data: &[u8]) -> Result<DecodedAccount, String> {
+    if data.len() < 8 {
+        return Err("Account data is shorter than a discriminator".to_string());
+    }
+    let account = idl
+        .accounts
+        .iter()
+        .find(|acc| acc.discriminator()[..] == data[..8])
+        .ok_or("Account discriminator does not match any type in the IDL")?;
+    decode_account_as(idl, &account.name, data)
+}
+
+/// Decodes `data` as the account type `name`, checking its discriminator.
+pub fn decode_account_as(idl: &Idl, name: &str, data: &[u8]) -> Result<DecodedAccount, String> {
+    let account = idl
+        .accounts
+        .iter()
+        .find(|acc| acc.name == name)
+        .ok_or_else(|| format!("Unknown account type `{}`", name))?;
+    if data.len() < 8 || account.discriminator()[..] != data[..8] {
+        return Err(format!("Account is not a `{}`", name));
+    }
+    let layout = idl
+        .account_layout(name)
+        .ok_or_else(|| format!("No layout for account type `{}`", name))?;
+
+    // Accounts are usually over-allocated, so trailing bytes are ignored.
+    let mut cursor = &data[8..];
+    let value = decode_defined(idl, layout, &mut cursor)?;
+    Ok(DecodedAccount {
+        name: name.to_string(),
+        value,
+    })
+}
+
+pub fn decode_value(idl: &Idl, ty: &IdlType, cursor: &mut &[u8]) -> Result<Value, String> {
+    let value = match ty {
+        IdlType::Bool => Value::Bool(take::<1>(cursor)?[0] != 0),
+        IdlType::U8 => Value::from(take::<1>(cursor)?[0]),
+        IdlType::I8 => Value::from(i8::from_le_bytes(take(cursor)?)),
+        IdlType::U16 => Value::from(u16::from_le_bytes(take(cursor)?)),
+        IdlType::I16 => Value::from(i16::from_le_bytes(take(cursor)?)),
+        IdlType::U32 => Value::from(u32::from_le_bytes(take(cursor)?)),
+        IdlType::I32 => Value::from(i32::from_le_bytes(take(cursor)?)),
+        IdlType::U64 => Value::from(u64::from_le_bytes(take(cursor)?)),
+        IdlType::I64 => Value::from(i64::from_le_bytes(take(cursor)?)),
+        IdlType::U128 => Value::String(u128::from_le_bytes(take(cursor)?).to_string()),
+        IdlType::I128 => Value::String(i128::from_le_bytes(take(cursor)?).to_string()),
+        IdlType::F32 => Value::from(f32::from_le_bytes(take(cursor)?) as f64),
+        IdlType::F64 => Value::from(f64::from_le_bytes(take(cursor)?)),
+        IdlType::String => {
+            let bytes = take_slice(cursor)?;
+            Value::String(
+                String::from_utf8(bytes.to_vec()).map_err(|_| "string is not valid UTF-8")?,
+            )
+        }
+        IdlType::Bytes => Value::String(base64::encode(take_slice(cursor)?)),
+        IdlType::Pubkey => Value::String(Pubkey::from(take::<32>(cursor)?).to_string()),
+        IdlType::Option(inner) => match take::<1>(cursor)?[0] {
+            0 => Value::Null,
+            1 => decode_value(idl, inner, cursor)?,
+            tag => return Err(format!("invalid option tag {}", tag)),
+        },
+        IdlType::COption(inner) => match u32::from_le_bytes(take(cursor)?) {
+            // COption always reserves space for the value.
+            0 => {
+                decode_value(idl, inner, cursor)?;
+                Value::Null
+            }
+            1 => decode_value(idl, inner, cursor)?,
+            tag => return Err(format!("invalid option tag {}", tag)),
+        },
+        IdlType::Vec(inner) => {
+            let len = u32::from_le_bytes(take(cursor)?) as usize;
+            let mut items = Vec::with_capacity(len.min(1024));
+            for _ in 0..len {
+                items.push(decode_value(idl, inner, cursor)?);
+            }
+            Value::Array(items)
+        }
+        IdlType::Array(inner, len) => {
+            let mut items = Vec::with_capacity((*len).min(1024));
+            for _ in 0..*len {
+                items.push(decode_value(idl, inner, cursor)?);
+            }
+            Value::Array(items)
+        }
+        IdlType::Defined(name) => {
+            let def = idl
+                .type_def(name)
+                .ok_or_else(|| format!("unknown type `{}`", name))?;
+            decode_defined(idl, &def.ty, cursor)?
+        }
+    };
+    Ok(value)
+}
+
+fn decode_defined(idl: &Idl, ty: &IdlTypeDefTy, cursor: &mut &[u8]) -> Result<Value, String> {
+    match ty {
+        IdlTypeDefTy::Struct(fields) => decode_fields(idl, fields, cursor),
+        IdlTypeDefTy::Alias(ty) => decode_value(idl, ty, cursor),
+        IdlTypeDefTy::Enum(variants) => {
+            let index = take::<1>(cursor)?[0] as usize;
+            let variant = variants
+                .get(index)
+                .ok_or_else(|| format!("invalid enum variant {}", index))?;
+            match &variant.fields {
+                IdlFields::Named(fields) if fields.is_empty() => Ok(Value::String(variant.name.clone())),
+                fields => {
+                    let mut map = Map::new();
+                    map.insert(variant.name.clone(), decode_fields(idl, fields, cursor)?);
+                    Ok(Value::Object(map))
+                }
+            }
+        }
+    }
+}
+
+fn decode_fields(idl: &Idl, fields: &IdlFields, cursor: &mut &[u8]) -> Result<Value, String> {
+    match fields {
+        IdlFields::Named(fields) => {
+            let mut map = Map::new();
+            for field in fields {
+                let value = decode_value(idl, &field.ty, cursor)
+                    .map_err(|e| format!("{}: {}", field.name, e))?;
+                map.insert(field.name.clone(), value);
+            }
+            Ok(Value::Object(map))
+        }
+        IdlFields::Tuple(types) => types
+            .iter()
+            .map(|ty| decode_value(idl, ty, cursor))
+            .collect::<Result<_, _>>()
+            .map(Value::Array),
+    }
+}
+
+fn take<const N: usize>(cursor: &mut &[u8]) -> Result<[u8; N], String> {
+    if cursor.len() < N {
+        return Err("unexpected end of account data".to_string());
+    }
+    let (head, rest) = cursor.split_at(N);
+    *cursor = rest;
+    Ok(head.try_into().unwrap())
+}
+
+/// Reads a u32 length prefix followed by that many bytes.
+fn take_slice<'a>(cursor: &mut &'a [u8]) -> Result<&'a [u8], String> {
+    let len = u32::from_le_bytes(take(cursor)?) as usize;
+    if cursor.len() < len {
+        return Err("unexpected end of account data".to_string());
+    }
+    let (head, rest) = cursor.split_at(len);
+    *cursor = rest;
+    Ok(head)
+}
+
+/// Accounts listed by [`fetch_program_accounts`]: the ones that decoded, and
+/// why the others didn't.
+#[cfg(feature = "web")]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ProgramAccounts {
+    pub decoded: Vec<(Pubkey, DecodedAccount)>,
+    pub failed: Vec<(Pubkey, String)>,
+}
+
+/// Lists every account of type `name` owned by `program_id`, using a memcmp
+/// filter on the account discriminator. An account that doesn't decode, e.g.
+/// one written by an older program version, doesn't fail the listing.
+#[cfg(feature = "web")]
+pub async fn fetch_program_accounts(
+    client: &solana_client_wasm::WasmClient,
+    program_id: &Pubkey,
+    idl: &Idl,
+    name: &str,
+) -> Result<ProgramAccounts, String> {
+    use crate::solana::{get_program_accounts, memcmp};
+
+    let account = idl
+        .accounts
+        .iter()
+        .find(|acc| acc.name == name)
+        .ok_or_else(|| format!("Unknown account type `{}`", name))?;
+    let filters = vec![memcmp(0, &account.discriminator())];
+
+    let mut listed = ProgramAccounts::default();
+    for (pubkey, account) in get_program_accounts(client, program_id, filters).await? {
+        match decode_account_as(idl, name, &account.data) {
+            Ok(decoded) => listed.decoded.push((pubkey, decoded)),
+            Err(e) => listed.failed.push((pubkey, e)),
+        }
+    }
+    Ok(listed)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::anchor::encode_value;
+    use serde_json::json;
+
+    const DISCRIMINATOR: [u8; 8] = [1, 2, 3, 4, 5, 6, 7, 8];
+
+    fn vault_idl() -> Idl {
+        Idl::from_json(
+            &json!({
+                "instructions": [],
+                "accounts": [{ "name": "Vault", "discriminator": DISCRIMINATOR }],
+                "types": [
+                    {
+                        "name": "Vault",
+                        "type": {
+                            "kind": "struct",
+                            "fields": [
+                                { "name": "amount", "type": "u64" },
+                                { "name": "total", "type": "u128" },
+                                { "name": "offset", "type": "i64" },
+                                { "name": "ratio", "type": "f64" },
+                                { "name": "owner", "type": "pubkey" },
+                                { "name": "label", "type": "string" },
+                                { "name": "data", "type": "bytes" },
+                                { "name": "delegate", "type": { "option": "pubkey" } },
+                                { "name": "limit", "type": { "option": "u64" } },
+                                { "name": "authority", "type": { "coption": "pubkey" } },
+                                { "name": "history", "type": { "vec": "u16" } },
+                                { "name": "seed", "type": { "array": ["u8", 3] } },
+                                { "name": "states", "type": { "vec": { "defined": { "name": "State" } } } }
+                            ]
+                        }
+                    },
+                    {
+                        "name": "State",
+                        "type": {
+                            "kind": "enum",
+                            "variants": [
+                                { "name": "Idle" },
+                                { "name": "Locked", "fields": [{ "name": "until", "type": "i64" }] }
+                            ]
+                        }
+                    }
+                ]
+            })
+            .to_string(),
+        )
+        .unwrap()
+    }
+
+    fn encode_vault(idl: &Idl, value: &Value) -> Vec<u8> {
+        let mut data = DISCRIMINATOR.to_vec();
+        encode_value(idl, &IdlType::Defined("Vault".to_string()), value, &mut data).unwrap();
+        data
+    }
+
+    #[test]
+    fn decoded_account_round_trips_through_encode_value() {
+        let idl = vault_idl();
+        let value = json!({
+            "amount": 42,
+            "total": u128::MAX.to_string(),
+            "offset": -7,
+            "ratio": 0.5,
+            "owner": Pubkey::new_unique().to_string(),
+            "label": "main vault",
+            "data": "AQID",
+            "delegate": null,
+            "limit": 1_000,
+            "authority": Pubkey::new_unique().to_string(),
+            "history": [1, 2, 65_535],
+            "seed": [9, 8, 7],
+            "states": ["Idle", { "Locked": { "until": -5 } }]
+        });
+        let data = encode_vault(&idl, &value);
+
+        let decoded = decode_account(&idl, &data).unwrap();
+        assert_eq!(decoded.name, "Vault");
+        assert_eq!(decoded.value, value);
+        assert_eq!(encode_vault(&idl, &decoded.value), data);
+    }
+
+    #[test]
+    fn empty_coption_drops_its_reserved_bytes() {
+        let idl = vault_idl();
+        let mut value = json!({
+            "amount": 0,
+            "total": "0",
+            "offset": 0,
+            "ratio": 0.0,
+            "owner": Pubkey::default().to_string(),
+            "label": "",
+            "data": "",
+            "delegate": null,
+            "limit": null,
+            "authority": Pubkey::default().to_string(),
+            "history": [],
+            "seed": [0, 0, 0],
+            "states": []
+        });
+        // On chain an empty COption is a zero tag followed by zeroed value bytes.
+        let mut data = encode_vault(&idl, &value);
+        let tag = 8 + 8 + 16 + 8 + 8 + 32 + 4 + 4 + 1 + 1;
+        data[tag..tag + 4].copy_from_slice(&0u32.to_le_bytes());
+
+        let decoded = decode_account(&idl, &data).unwrap();
+        value["authority"] = Value::Null;
+        assert_eq!(decoded.value, value);
+        assert_eq!(encode_vault(&idl, &decoded.value).len(), data.len() - 32);
+    }
+
+    #[test]
+    fn rejects_another_account_type() {
+        let idl = vault_idl();
+        assert!(decode_account(&idl, &[0; 16]).is_err());
+        assert_eq!(
+            decode_account_as(&idl, "Vault", &[0; 16]).unwrap_err(),
+            "Account is not a `Vault`"
+        );
+    }
+}
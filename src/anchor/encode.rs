@@ -0,0 +1,330 @@
+use serde_json::Value;
+use solana_sdk::{
+    instruction::{AccountMeta, Instruction},
+    pubkey::Pubkey,
+};
+use std::str::FromStr;
+
+use super::idl::{Idl, IdlFields, IdlInstruction, IdlType, IdlTypeDefTy};
+
+/// Builds an instruction for `program_id`. `accounts` follows the order of
+/// [`IdlInstruction::flat_accounts`]; `None` marks an omitted optional account,
+/// which Anchor expects to be passed as the program id.
+pub fn build_instruction(
+    idl: &Idl,
+    program_id: Pubkey,
+    instruction: &IdlInstruction,
+    accounts: &[Option<Pubkey>],
+    args: &[Value],
+) -> Result<Instruction, String> {
+    let flat = instruction.flat_accounts();
+    if accounts.len() != flat.len() {
+        return Err(format!("Expected {} accounts, got {}", flat.len(), accounts.len()));
+    }
+
+    let metas = flat
+        .iter()
+        .zip(accounts)
+        .map(|((path, account), pubkey)| match pubkey {
+            Some(pubkey) if account.writable => Ok(AccountMeta::new(*pubkey, account.signer)),
+            Some(pubkey) => Ok(AccountMeta::new_readonly(*pubkey, account.signer)),
+            None if account.optional => Ok(AccountMeta::new_readonly(program_id, false)),
+            None => Err(format!("Account `{}` is required", path)),
+        })
+        .collect::<Result<Vec<_>, String>>()?;
+
+    Ok(Instruction {
+        program_id,
+        accounts: metas,
+        data: encode_instruction_data(idl, instruction, args)?,
+    })
+}
+
+/// Builds instruction data: the discriminator followed by the Borsh-encoded
+/// arguments, in IDL order.
+pub fn encode_instruction_data(
+    idl: &Idl,
+    instruction: &IdlInstruction,
+    args: &[Value],
+) -> Result<Vec<u8>, String> {
+    if args.len() != instruction.args.len() {
+        return Err(format!(
+            "Expected {} arguments, got {}",
+            instruction.args.len(),
+            args.len()
+        ));
+    }
+
+    let mut data = instruction.discriminator().to_vec();
+    for (field, value) in instruction.args.iter().zip(args) {
+        encode_value(idl, &field.ty, value, &mut data)
+            .map_err(|e| format!("{}: {}", field.name, e))?;
+    }
+    Ok(data)
+}
+
+/// Converts raw form input into a value the encoder accepts. Scalars are
+/// taken verbatim, and so are optional scalars, with an empty input for none;
+/// other composite types are entered as JSON.
+pub fn parse_input(ty: &IdlType, input: &str) -> Result<Value, String> {
+    let input = input.trim();
+    match ty {
+        IdlType::Bool => match input {
+            "true" => Ok(Value::Bool(true)),
+            "false" | "" => Ok(Value::Bool(false)),
+            _ => Err("expected true or false".to_string()),
+        },
+        IdlType::String | IdlType::Pubkey => Ok(Value::String(input.to_string())),
+        ty if ty.is_integer() || matches!(ty, IdlType::F32 | IdlType::F64) => {
+            if input.is_empty() {
+                Err("value is required".to_string())
+            } else {
+                Ok(Value::String(input.to_string()))
+            }
+        }
+        IdlType::Option(_) | IdlType::COption(_) if input.is_empty() => Ok(Value::Null),
+        IdlType::Option(inner) | IdlType::COption(inner) if inner.is_scalar() => parse_input(inner, input),
+        _ => serde_json::from_str(input).map_err(|e| format!("invalid JSON: {}", e)),
+    }
+}
+
+pub fn encode_value(idl: &Idl, ty: &IdlType, value: &Value, out: &mut Vec<u8>) -> Result<(), String> {
+    match ty {
+        IdlType::Bool => {
+            let b = value.as_bool().ok_or("expected a boolean")?;
+            out.push(b as u8);
+        }
+        IdlType::U8 => out.push(parse_int::<u8>(value)?),
+        IdlType::I8 => out.extend(parse_int::<i8>(value)?.to_le_bytes()),
+        IdlType::U16 => out.extend(parse_int::<u16>(value)?.to_le_bytes()),
+        IdlType::I16 => out.extend(parse_int::<i16>(value)?.to_le_bytes()),
+        IdlType::U32 => out.extend(parse_int::<u32>(value)?.to_le_bytes()),
+        IdlType::I32 => out.extend(parse_int::<i32>(value)?.to_le_bytes()),
+        IdlType::U64 => out.extend(parse_int::<u64>(value)?.to_le_bytes()),
+        IdlType::I64 => out.extend(parse_int::<i64>(value)?.to_le_bytes()),
+        IdlType::U128 => out.extend(parse_int::<u128>(value)?.to_le_bytes()),
+        IdlType::I128 => out.extend(parse_int::<i128>(value)?.to_le_bytes()),
+        IdlType::F32 => out.extend((parse_float(value)? as f32).to_le_bytes()),
+        IdlType::F64 => out.extend(parse_float(value)?.to_le_bytes()),
+        IdlType::String => {
+            let s = value.as_str().ok_or("expected a string")?;
+            out.extend((s.len() as u32).to_le_bytes());
+            out.extend(s.as_bytes());
+        }
+        IdlType::Bytes => {
+            let bytes = parse_bytes(value)?;
+            out.extend((bytes.len() as u32).to_le_bytes());
+            out.extend(bytes);
+        }
+        IdlType::Pubkey => {
+            let s = value.as_str().ok_or("expected a base58 address")?;
+            let pubkey = Pubkey::from_str(s).map_err(|_| format!("invalid address `{}`", s))?;
+            out.extend(pubkey.to_bytes());
+        }
+        IdlType::Option(inner) => {
+            if value.is_null() {
+                out.push(0);
+            } else {
+                out.push(1);
+                encode_value(idl, inner, value, out)?;
+            }
+        }
+        IdlType::COption(inner) => {
+            if value.is_null() {
+                out.extend(0u32.to_le_bytes());
+            } else {
+                out.extend(1u32.to_le_bytes());
+                encode_value(idl, inner, value, out)?;
+            }
+        }
+        IdlType::Vec(inner) => {
+            let items = value.as_array().ok_or("expected an array")?;
+            out.extend((items.len() as u32).to_le_bytes());
+            for item in items {
+                encode_value(idl, inner, item, out)?;
+            }
+        }
+        IdlType::Array(inner, len) => {
+            let items = value.as_array().ok_or("expected an array")?;
+            if items.len() != *len {
+                return Err(format!("expected {} items, got {}", len, items.len()));
+            }
+            for item in items {
+                encode_value(idl, inner, item, out)?;
+            }
+        }
+        IdlType::Defined(name) => {
+            let def = idl
+                .type_def(name)
+                .ok_or_else(|| format!("unknown type `{}`", name))?;
+            encode_defined(idl, &def.ty, value, out)?;
+        }
+    }
+    Ok(())
+}
+
+fn encode_defined(idl: &Idl, ty: &IdlTypeDefTy, value: &Value, out: &mut Vec<u8>) -> Result<(), String> {
+    match ty {
+        IdlTypeDefTy::Struct(fields) => encode_fields(idl, fields, value, out),
+        IdlTypeDefTy::Alias(ty) => encode_value(idl, ty, value, out),
+        IdlTypeDefTy::Enum(variants) => {
+            // Unit variants are written as `"Name"`, others as `{"Name": fields}`.
+            let (name, fields_value) = match value {
+                Value::String(name) => (name.as_str(), Value::Null),
+                Value::Object(map) if map.len() == 1 => {
+                    let (name, fields) = map.iter().next().unwrap();
+                    (name.as_str(), fields.clone())
+                }
+                _ => return Err("expected a variant name or {\"Variant\": fields}".to_string()),
+            };
+            let index = variants
+                .iter()
+                .position(|variant| variant.name == name)
+                .ok_or_else(|| format!("unknown variant `{}`", name))?;
+            out.push(index as u8);
+            match &variants[index].fields {
+                IdlFields::Named(fields) if fields.is_empty() => Ok(()),
+                fields => encode_fields(idl, fields, &fields_value, out),
+            }
+        }
+    }
+}
+
+fn encode_fields(idl: &Idl, fields: &IdlFields, value: &Value, out: &mut Vec<u8>) -> Result<(), String> {
+    match fields {
+        IdlFields::Named(fields) => {
+            let map = value.as_object().ok_or("expected an object")?;
+            for field in fields {
+                let field_value = map
+                    .get(&field.name)
+                    .ok_or_else(|| format!("missing field `{}`", field.name))?;
+                encode_value(idl, &field.ty, field_value, out)
+                    .map_err(|e| format!("{}: {}", field.name, e))?;
+            }
+        }
+        IdlFields::Tuple(types) => {
+            let items = value.as_array().ok_or("expected an array")?;
+            if items.len() != types.len() {
+                return Err(format!("expected {} items, got {}", types.len(), items.len()));
+            }
+            for (ty, item) in types.iter().zip(items) {
+                encode_value(idl, ty, item, out)?;
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Integers may arrive as JSON numbers or as strings (for values past 2^53).
+fn parse_int<T: FromStr>(value: &Value) -> Result<T, String> {
+    let text = match value {
+        Value::Number(n) => n.to_string(),
+        Value::String(s) => s.trim().to_string(),
+        _ => return Err("expected an integer".to_string()),
+    };
+    text.parse::<T>()
+        .map_err(|_| format!("`{}` is out of range or not an integer", text))
+}
+
+fn parse_float(value: &Value) -> Result<f64, String> {
+    match value {
+        Value::Number(n) => n.as_f64().ok_or_else(|| "invalid number".to_string()),
+        Value::String(s) => s.trim().parse().map_err(|_| format!("`{}` is not a number", s)),
+        _ => Err("expected a number".to_string()),
+    }
+}
+
+/// Bytes are given either as an array of numbers or as a base64 string.
+fn parse_bytes(value: &Value) -> Result<Vec<u8>, String> {
+    match value {
+        Value::Array(items) => items.iter().map(parse_int::<u8>).collect(),
+        Value::String(s) => base64::decode(s).map_err(|e| format!("invalid base64: {}", e)),
+        _ => Err("expected a byte array or base64 string".to_string()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::anchor::sighash;
+    use serde_json::json;
+
+    fn encode(ty: &IdlType, value: Value) -> Result<Vec<u8>, String> {
+        let idl = Idl::from_json(r#"{ "instructions": [] }"#).unwrap();
+        let mut out = Vec::new();
+        encode_value(&idl, ty, &value, &mut out).map(|_| out)
+    }
+
+    #[test]
+    fn encodes_scalars_little_endian() {
+        assert_eq!(encode(&IdlType::Bool, json!(true)).unwrap(), [1]);
+        assert_eq!(encode(&IdlType::U16, json!(258)).unwrap(), [2, 1]);
+        assert_eq!(encode(&IdlType::I32, json!(-2)).unwrap(), [254, 255, 255, 255]);
+        assert_eq!(encode(&IdlType::U64, json!("1")).unwrap(), [1, 0, 0, 0, 0, 0, 0, 0]);
+        assert_eq!(encode(&IdlType::U128, json!(u128::MAX.to_string())).unwrap(), [255; 16]);
+        assert_eq!(encode(&IdlType::F32, json!(1.0)).unwrap(), 1.0f32.to_le_bytes());
+        assert!(encode(&IdlType::U8, json!(256)).is_err());
+        assert!(encode(&IdlType::U64, json!(-1)).is_err());
+    }
+
+    #[test]
+    fn prefixes_strings_bytes_and_vecs_with_their_length() {
+        assert_eq!(encode(&IdlType::String, json!("hi")).unwrap(), [2, 0, 0, 0, b'h', b'i']);
+        assert_eq!(encode(&IdlType::Bytes, json!([1, 2])).unwrap(), [2, 0, 0, 0, 1, 2]);
+        assert_eq!(encode(&IdlType::Bytes, json!("AQI=")).unwrap(), [2, 0, 0, 0, 1, 2]);
+        let vec = IdlType::Vec(Box::new(IdlType::U8));
+        assert_eq!(encode(&vec, json!([7, 8, 9])).unwrap(), [3, 0, 0, 0, 7, 8, 9]);
+        let array = IdlType::Array(Box::new(IdlType::U8), 2);
+        assert_eq!(encode(&array, json!([7, 8])).unwrap(), [7, 8]);
+        assert!(encode(&array, json!([7])).is_err());
+    }
+
+    #[test]
+    fn encodes_options_and_pubkeys() {
+        let pubkey = Pubkey::new_unique();
+        let option = IdlType::Option(Box::new(IdlType::Pubkey));
+        assert_eq!(encode(&option, Value::Null).unwrap(), [0]);
+        let mut expected = vec![1];
+        expected.extend(pubkey.to_bytes());
+        assert_eq!(encode(&option, json!(pubkey.to_string())).unwrap(), expected);
+
+        let coption = IdlType::COption(Box::new(IdlType::U8));
+        assert_eq!(encode(&coption, json!(5)).unwrap(), [1, 0, 0, 0, 5]);
+        assert!(encode(&IdlType::Pubkey, json!("not base58")).is_err());
+    }
+
+    #[test]
+    fn optional_scalars_are_parsed_like_the_scalar() {
+        let pubkey = Pubkey::new_unique().to_string();
+        let option = IdlType::Option(Box::new(IdlType::Pubkey));
+        assert_eq!(parse_input(&option, &pubkey).unwrap(), json!(pubkey));
+        assert_eq!(parse_input(&option, "  ").unwrap(), Value::Null);
+
+        let coption = IdlType::COption(Box::new(IdlType::U64));
+        assert_eq!(parse_input(&coption, "42").unwrap(), json!("42"));
+
+        let vec = IdlType::Option(Box::new(IdlType::Vec(Box::new(IdlType::U8))));
+        assert_eq!(parse_input(&vec, "[1, 2]").unwrap(), json!([1, 2]));
+        assert!(parse_input(&vec, "1, 2").is_err());
+    }
+
+    #[test]
+    fn instruction_data_starts_with_the_discriminator() {
+        let idl = Idl::from_json(
+            r#"{
+                "instructions": [{
+                    "name": "setLimit",
+                    "accounts": [],
+                    "args": [{ "name": "limit", "type": "u32" }]
+                }]
+            }"#,
+        )
+        .unwrap();
+        let instruction = idl.instruction("setLimit").unwrap();
+
+        let data = encode_instruction_data(&idl, instruction, &[json!(3)]).unwrap();
+        assert_eq!(data[..8], sighash("global", "set_limit"));
+        assert_eq!(data[8..], [3, 0, 0, 0]);
+        assert!(encode_instruction_data(&idl, instruction, &[]).is_err());
+    }
+}
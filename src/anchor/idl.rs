@@ -0,0 +1,560 @@
+use serde::{Deserialize, Deserializer};
+use serde_json::Value;
+use solana_sdk::{hash::hash, pubkey::Pubkey};
+use std::str::FromStr;
+
+/// Seed used by Anchor to derive the on-chain IDL account of a program.
+pub const IDL_SEED: &str = "anchor:idl";
+
+/// An Anchor IDL. Both the 0.30+ spec and the legacy (pre-0.30) layout are
+/// accepted; legacy field names are handled through serde aliases.
+#[derive(Clone, Debug, PartialEq, Deserialize)]
+pub struct Idl {
+    #[serde(default)]
+    pub address: Option<String>,
+    #[serde(default)]
+    pub name: Option<String>,
+    #[serde(default)]
+    pub metadata: Option<IdlMetadata>,
+    pub instructions: Vec<IdlInstruction>,
+    #[serde(default)]
+    pub accounts: Vec<IdlAccountDef>,
+    #[serde(default)]
+    pub types: Vec<IdlTypeDef>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Deserialize)]
+pub struct IdlMetadata {
+    #[serde(default)]
+    pub name: Option<String>,
+    #[serde(default)]
+    pub version: Option<String>,
+    #[serde(default)]
+    pub address: Option<String>,
+}
+
+#[derive(Clone, Debug, PartialEq, Deserialize)]
+pub struct IdlInstruction {
+    pub name: String,
+    #[serde(default)]
+    pub discriminator: Option<Vec<u8>>,
+    #[serde(default)]
+    pub accounts: Vec<IdlAccountItem>,
+    #[serde(default)]
+    pub args: Vec<IdlField>,
+}
+
+#[derive(Clone, Debug, PartialEq, Deserialize)]
+#[serde(untagged)]
+pub enum IdlAccountItem {
+    Composite(IdlAccounts),
+    Single(IdlAccount),
+}
+
+#[derive(Clone, Debug, PartialEq, Deserialize)]
+pub struct IdlAccounts {
+    pub name: String,
+    pub accounts: Vec<IdlAccountItem>,
+}
+
+#[derive(Clone, Debug, PartialEq, Deserialize)]
+pub struct IdlAccount {
+    pub name: String,
+    #[serde(default, alias = "isMut")]
+    pub writable: bool,
+    #[serde(default, alias = "isSigner")]
+    pub signer: bool,
+    #[serde(default, alias = "isOptional")]
+    pub optional: bool,
+    #[serde(default)]
+    pub address: Option<String>,
+}
+
+#[derive(Clone, Debug, PartialEq, Deserialize)]
+pub struct IdlAccountDef {
+    pub name: String,
+    #[serde(default)]
+    pub discriminator: Option<Vec<u8>>,
+    /// Legacy IDLs inline the account layout; newer ones put it in `types`.
+    #[serde(default, rename = "type")]
+    pub ty: Option<IdlTypeDefTy>,
+}
+
+#[derive(Clone, Debug, PartialEq, Deserialize)]
+pub struct IdlField {
+    pub name: String,
+    #[serde(rename = "type")]
+    pub ty: IdlType,
+}
+
+#[derive(Clone, Debug, PartialEq, Deserialize)]
+pub struct IdlTypeDef {
+    pub name: String,
+    #[serde(rename = "type")]
+    pub ty: IdlTypeDefTy,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum IdlTypeDefTy {
+    Struct(IdlFields),
+    Enum(Vec<IdlEnumVariant>),
+    Alias(IdlType),
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum IdlFields {
+    Named(Vec<IdlField>),
+    Tuple(Vec<IdlType>),
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct IdlEnumVariant {
+    pub name: String,
+    pub fields: IdlFields,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum IdlType {
+    Bool,
+    U8,
+    I8,
+    U16,
+    I16,
+    U32,
+    I32,
+    F32,
+    U64,
+    I64,
+    F64,
+    U128,
+    I128,
+    Bytes,
+    String,
+    Pubkey,
+    Option(Box<IdlType>),
+    COption(Box<IdlType>),
+    Vec(Box<IdlType>),
+    Array(Box<IdlType>, usize),
+    Defined(String),
+}
+
+impl Idl {
+    pub fn from_json(json: &str) -> Result<Self, String> {
+        serde_json::from_str(json).map_err(|e| format!("Invalid IDL: {}", e))
+    }
+
+    pub fn display_name(&self) -> String {
+        self.metadata
+            .as_ref()
+            .and_then(|m| m.name.clone())
+            .or_else(|| self.name.clone())
+            .unwrap_or_else(|| "unnamed".to_string())
+    }
+
+    /// Program address declared by the IDL, if any.
+    pub fn program_id(&self) -> Option<Pubkey> {
+        self.address
+            .as_ref()
+            .or_else(|| self.metadata.as_ref().and_then(|m| m.address.as_ref()))
+            .and_then(|address| Pubkey::from_str(address).ok())
+    }
+
+    pub fn instruction(&self, name: &str) -> Option<&IdlInstruction> {
+        self.instructions.iter().find(|ix| ix.name == name)
+    }
+
+    pub fn type_def(&self, name: &str) -> Option<&IdlTypeDef> {
+        self.types.iter().find(|ty| ty.name == name)
+    }
+
+    /// Layout of an account type, whether inlined in `accounts` or in `types`.
+    pub fn account_layout(&self, name: &str) -> Option<&IdlTypeDefTy> {
+        self.accounts
+            .iter()
+            .find(|acc| acc.name == name)
+            .and_then(|acc| acc.ty.as_ref())
+            .or_else(|| self.type_def(name).map(|def| &def.ty))
+    }
+}
+
+impl IdlInstruction {
+    pub fn discriminator(&self) -> [u8; 8] {
+        match &self.discriminator {
+            Some(bytes) if bytes.len() == 8 => {
+                let mut disc = [0u8; 8];
+                disc.copy_from_slice(bytes);
+                disc
+            }
+            _ => sighash("global", &to_snake_case(&self.name)),
+        }
+    }
+
+    /// Flattens composite account groups into `(path, account)` pairs in the
+    /// order the program expects them.
+    pub fn flat_accounts(&self) -> Vec<(String, IdlAccount)> {
+        fn walk(items: &[IdlAccountItem], prefix: &str, out: &mut Vec<(String, IdlAccount)>) {
+            for item in items {
+                match item {
+                    IdlAccountItem::Single(acc) => {
+                        out.push((format!("{}{}", prefix, acc.name), acc.clone()));
+                    }
+                    IdlAccountItem::Composite(group) => {
+                        walk(&group.accounts, &format!("{}{}.", prefix, group.name), out);
+                    }
+                }
+            }
+        }
+
+        let mut out = Vec::new();
+        walk(&self.accounts, "", &mut out);
+        out
+    }
+}
+
+impl IdlAccountDef {
+    pub fn discriminator(&self) -> [u8; 8] {
+        match &self.discriminator {
+            Some(bytes) if bytes.len() == 8 => {
+                let mut disc = [0u8; 8];
+                disc.copy_from_slice(bytes);
+                disc
+            }
+            _ => sighash("account", &self.name),
+        }
+    }
+}
+
+impl IdlType {
+    /// Short name shown next to form inputs.
+    pub fn label(&self) -> String {
+        match self {
+            IdlType::Bool => "bool".into(),
+            IdlType::U8 => "u8".into(),
+            IdlType::I8 => "i8".into(),
+            IdlType::U16 => "u16".into(),
+            IdlType::I16 => "i16".into(),
+            IdlType::U32 => "u32".into(),
+            IdlType::I32 => "i32".into(),
+            IdlType::F32 => "f32".into(),
+            IdlType::U64 => "u64".into(),
+            IdlType::I64 => "i64".into(),
+            IdlType::F64 => "f64".into(),
+            IdlType::U128 => "u128".into(),
+            IdlType::I128 => "i128".into(),
+            IdlType::Bytes => "bytes".into(),
+            IdlType::String => "string".into(),
+            IdlType::Pubkey => "pubkey".into(),
+            IdlType::Option(inner) => format!("Option<{}>", inner.label()),
+            IdlType::COption(inner) => format!("COption<{}>", inner.label()),
+            IdlType::Vec(inner) => format!("Vec<{}>", inner.label()),
+            IdlType::Array(inner, len) => format!("[{}; {}]", inner.label(), len),
+            IdlType::Defined(name) => name.clone(),
+        }
+    }
+
+    /// Types entered as a single text value rather than as JSON.
+    pub fn is_scalar(&self) -> bool {
+        self.is_integer() || matches!(self, IdlType::F32 | IdlType::F64 | IdlType::String | IdlType::Pubkey)
+    }
+
+    pub fn is_integer(&self) -> bool {
+        matches!(
+            self,
+            IdlType::U8
+                | IdlType::I8
+                | IdlType::U16
+                | IdlType::I16
+                | IdlType::U32
+                | IdlType::I32
+                | IdlType::U64
+                | IdlType::I64
+                | IdlType::U128
+                | IdlType::I128
+        )
+    }
+
+    fn from_value(value: &Value) -> Result<Self, String> {
+        match value {
+            Value::String(name) => match name.as_str() {
+                "bool" => Ok(IdlType::Bool),
+                "u8" => Ok(IdlType::U8),
+                "i8" => Ok(IdlType::I8),
+                "u16" => Ok(IdlType::U16),
+                "i16" => Ok(IdlType::I16),
+                "u32" => Ok(IdlType::U32),
+                "i32" => Ok(IdlType::I32),
+                "f32" => Ok(IdlType::F32),
+                "u64" => Ok(IdlType::U64),
+                "i64" => Ok(IdlType::I64),
+                "f64" => Ok(IdlType::F64),
+                "u128" => Ok(IdlType::U128),
+                "i128" => Ok(IdlType::I128),
+                "bytes" => Ok(IdlType::Bytes),
+                "string" => Ok(IdlType::String),
+                "pubkey" | "publicKey" => Ok(IdlType::Pubkey),
+                other => Err(format!("unsupported type `{}`", other)),
+            },
+            Value::Object(map) => {
+                if let Some(inner) = map.get("option") {
+                    Ok(IdlType::Option(Box::new(Self::from_value(inner)?)))
+                } else if let Some(inner) = map.get("coption") {
+                    Ok(IdlType::COption(Box::new(Self::from_value(inner)?)))
+                } else if let Some(inner) = map.get("vec") {
+                    Ok(IdlType::Vec(Box::new(Self::from_value(inner)?)))
+                } else if let Some(Value::Array(parts)) = map.get("array") {
+                    match parts.as_slice() {
+                        [inner, Value::Number(len)] => {
+                            let len = len
+                                .as_u64()
+                                .ok_or_else(|| "invalid array length".to_string())?;
+                            Ok(IdlType::Array(Box::new(Self::from_value(inner)?), len as usize))
+                        }
+                        _ => Err("generic array lengths are not supported".to_string()),
+                    }
+                } else if let Some(defined) = map.get("defined") {
+                    match defined {
+                        Value::String(name) => Ok(IdlType::Defined(name.clone())),
+                        Value::Object(inner) => inner
+                            .get("name")
+                            .and_then(Value::as_str)
+                            .map(|name| IdlType::Defined(name.to_string()))
+                            .ok_or_else(|| "defined type without a name".to_string()),
+                        _ => Err("invalid defined type".to_string()),
+                    }
+                } else {
+                    Err(format!("unsupported type {}", value))
+                }
+            }
+            _ => Err(format!("unsupported type {}", value)),
+        }
+    }
+}
+
+impl IdlFields {
+    fn from_value(value: Option<&Value>) -> Result<Self, String> {
+        let items = match value {
+            None | Some(Value::Null) => return Ok(IdlFields::Named(Vec::new())),
+            Some(Value::Array(items)) => items,
+            Some(other) => return Err(format!("invalid fields {}", other)),
+        };
+
+        let named = items
+            .first()
+            .map(|first| first.get("name").is_some())
+            .unwrap_or(true);
+
+        if named {
+            items
+                .iter()
+                .map(|item| IdlField::deserialize(item).map_err(|e| e.to_string()))
+                .collect::<Result<_, _>>()
+                .map(IdlFields::Named)
+        } else {
+            items
+                .iter()
+                .map(IdlType::from_value)
+                .collect::<Result<_, _>>()
+                .map(IdlFields::Tuple)
+        }
+    }
+}
+
+impl IdlTypeDefTy {
+    fn from_value(value: &Value) -> Result<Self, String> {
+        match value.get("kind").and_then(Value::as_str) {
+            Some("struct") => Ok(IdlTypeDefTy::Struct(IdlFields::from_value(value.get("fields"))?)),
+            Some("enum") => {
+                let variants = value
+                    .get("variants")
+                    .and_then(Value::as_array)
+                    .ok_or_else(|| "enum without variants".to_string())?;
+                variants
+                    .iter()
+                    .map(|variant| {
+                        let name = variant
+                            .get("name")
+                            .and_then(Value::as_str)
+                            .ok_or_else(|| "enum variant without a name".to_string())?;
+                        Ok(IdlEnumVariant {
+                            name: name.to_string(),
+                            fields: IdlFields::from_value(variant.get("fields"))?,
+                        })
+                    })
+                    .collect::<Result<_, String>>()
+                    .map(IdlTypeDefTy::Enum)
+            }
+            Some("type") => {
+                let alias = value
+                    .get("alias")
+                    .ok_or_else(|| "type alias without a target".to_string())?;
+                Ok(IdlTypeDefTy::Alias(IdlType::from_value(alias)?))
+            }
+            _ => Err(format!("unsupported type definition {}", value)),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for IdlType {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = Value::deserialize(deserializer)?;
+        IdlType::from_value(&value).map_err(serde::de::Error::custom)
+    }
+}
+
+impl<'de> Deserialize<'de> for IdlTypeDefTy {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = Value::deserialize(deserializer)?;
+        IdlTypeDefTy::from_value(&value).map_err(serde::de::Error::custom)
+    }
+}
+
+/// Anchor's 8-byte discriminator: `sha256("<namespace>:<name>")[..8]`.
+pub fn sighash(namespace: &str, name: &str) -> [u8; 8] {
+    let preimage = format!("{}:{}", namespace, name);
+    let mut disc = [0u8; 8];
+    disc.copy_from_slice(&hash(preimage.as_bytes()).to_bytes()[..8]);
+    disc
+}
+
+/// Legacy IDLs use camelCase instruction names while the discriminator is
+/// computed from the Rust snake_case name.
+pub fn to_snake_case(name: &str) -> String {
+    let mut out = String::with_capacity(name.len() + 4);
+    for (i, c) in name.chars().enumerate() {
+        if c.is_uppercase() {
+            if i != 0 {
+                out.push('_');
+            }
+            out.extend(c.to_lowercase());
+        } else {
+            out.push(c);
+        }
+    }
+    out
+}
+
+/// Address of the account where `anchor idl init` stores a program's IDL.
+pub fn idl_address(program_id: &Pubkey) -> Result<Pubkey, String> {
+    let (base, _) = Pubkey::find_program_address(&[], program_id);
+    Pubkey::create_with_seed(&base, IDL_SEED, program_id).map_err(|e| e.to_string())
+}
+
+/// Parses the raw data of an IDL account: 8-byte discriminator, 32-byte
+/// authority, u32 length, then zlib-compressed JSON.
+pub fn parse_idl_account(data: &[u8]) -> Result<Idl, String> {
+    use flate2::read::ZlibDecoder;
+    use std::io::Read;
+
+    if data.len() < 44 {
+        return Err("IDL account is too small".to_string());
+    }
+    let len = u32::from_le_bytes(data[40..44].try_into().unwrap()) as usize;
+    let end = 44usize
+        .checked_add(len)
+        .ok_or_else(|| "IDL account data is truncated".to_string())?;
+    let compressed = data
+        .get(44..end)
+        .ok_or_else(|| "IDL account data is truncated".to_string())?;
+
+    let mut json = String::new();
+    ZlibDecoder::new(compressed)
+        .read_to_string(&mut json)
+        .map_err(|e| format!("Failed to decompress IDL: {}", e))?;
+
+    Idl::from_json(&json)
+}
+
+/// Fetches and parses the IDL a program published on chain.
+#[cfg(feature = "web")]
+pub async fn fetch_idl(
+    client: &solana_client_wasm::WasmClient,
+    program_id: &Pubkey,
+) -> Result<Idl, String> {
+    use crate::solana::rpc_call;
+
+    let address = idl_address(program_id)?;
+    let account = rpc_call("getAccountInfo", client.get_account(&address))
+        .await
+        .map_err(|e| format!("Failed to fetch IDL account {}: {:?}", address, e))?;
+    parse_idl_account(&account.data)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use flate2::{write::ZlibEncoder, Compression};
+    use std::io::Write;
+
+    const IDL_JSON: &str = r#"{
+        "address": "whirLbMiicVdio4qvUfM5KAg6Ct8VwpYzGff3uctyCc",
+        "metadata": { "name": "whirlpool", "version": "0.1.0" },
+        "instructions": [{ "name": "initialize", "accounts": [], "args": [] }],
+        "accounts": [{ "name": "Pool", "discriminator": [1, 2, 3, 4, 5, 6, 7, 8] }]
+    }"#;
+
+    fn idl_account_data(json: &str) -> Vec<u8> {
+        let mut encoder = ZlibEncoder::new(Vec::new(), Compression::default());
+        encoder.write_all(json.as_bytes()).unwrap();
+        let compressed = encoder.finish().unwrap();
+
+        let mut data = sighash("account", "IdlAccount").to_vec();
+        data.extend(Pubkey::new_unique().to_bytes());
+        data.extend((compressed.len() as u32).to_le_bytes());
+        data.extend(compressed);
+        // IDL accounts are allocated larger than their content.
+        data.extend([0; 64]);
+        data
+    }
+
+    #[test]
+    fn sighash_matches_anchor() {
+        assert_eq!(sighash("global", "initialize"), [175, 175, 109, 31, 13, 152, 155, 237]);
+        assert_eq!(to_snake_case("initializePool"), "initialize_pool");
+        assert_eq!(to_snake_case("swap"), "swap");
+    }
+
+    #[test]
+    fn discriminators_prefer_the_idl_value() {
+        let idl = Idl::from_json(IDL_JSON).unwrap();
+        assert_eq!(idl.accounts[0].discriminator(), [1, 2, 3, 4, 5, 6, 7, 8]);
+        assert_eq!(idl.instructions[0].discriminator(), sighash("global", "initialize"));
+    }
+
+    #[test]
+    fn idl_address_is_derived_from_the_program() {
+        let program_id = Pubkey::from_str("whirLbMiicVdio4qvUfM5KAg6Ct8VwpYzGff3uctyCc").unwrap();
+        assert_eq!(
+            idl_address(&program_id).unwrap().to_string(),
+            "2KFqE4RWoPVbvodo8vbggCFeHPS8TDvgpwp79ALMrcyn"
+        );
+    }
+
+    #[test]
+    fn parses_an_idl_account() {
+        let idl = parse_idl_account(&idl_account_data(IDL_JSON)).unwrap();
+        assert_eq!(idl.display_name(), "whirlpool");
+        assert_eq!(idl.instructions.len(), 1);
+    }
+
+    #[test]
+    fn rejects_broken_idl_accounts() {
+        assert_eq!(parse_idl_account(&[0; 43]).unwrap_err(), "IDL account is too small");
+
+        let data = idl_account_data(IDL_JSON);
+        let len = u32::from_le_bytes(data[40..44].try_into().unwrap()) as usize;
+        assert_eq!(
+            parse_idl_account(&data[..44 + len - 1]).unwrap_err(),
+            "IDL account data is truncated"
+        );
+
+        let mut huge = data.clone();
+        huge[40..44].copy_from_slice(&u32::MAX.to_le_bytes());
+        assert_eq!(parse_idl_account(&huge).unwrap_err(), "IDL account data is truncated");
+
+        let mut data = data;
+        data[44..48].copy_from_slice(b"junk");
+        assert!(parse_idl_account(&data).unwrap_err().starts_with("Failed to decompress IDL"));
+
+        assert!(parse_idl_account(&idl_account_data("{}")).unwrap_err().starts_with("Invalid IDL"));
+    }
+}
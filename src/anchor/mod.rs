@@ -0,0 +1,7 @@
+mod decode;
+mod encode;
+mod idl;
+
+pub use decode::*;
+pub use encode::*;
+pub use idl::*;
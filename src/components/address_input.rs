@@ -0,0 +1,97 @@
+use dioxus::prelude::*;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use crate::hooks::{use_address_book, use_i18n, use_sns_owner, AddressBookEntry};
+
+/// Numbers the autocomplete lists of inputs without an `id`.
+static NEXT_LIST: AtomicUsize = AtomicUsize::new(0);
+
+const INPUT_CLASS: &str = "w-full bg-surface-sunken/50 border border-line-strong rounded-lg px-4 py-3 text-fg placeholder-fg-faint focus:outline-none focus:border-purple-500 focus:ring-2 focus:ring-purple-500/50 transition-all";
+
+/// Address text input that autocompletes from the address book, shows the
+/// saved label of a known address and resolves `.sol` names. Lookup results
+/// are announced to screen readers.
+#[component]
+pub fn AddressInput(
+    value: String,
+    oninput: EventHandler<String>,
+    #[props(default)] disabled: bool,
+    /// Defaults to the translated address placeholder.
+    #[props(default)]
+    placeholder: Option<String>,
+    /// Id of the autocomplete list. Defaults to one derived from `id`, or a
+    /// fresh one per input, so several inputs on a page don't share a list.
+    #[props(default)]
+    list_id: Option<String>,
+    /// Id for the input, so a `label` can point at it.
+    #[props(default, into)]
+    id: String,
+    /// Id of the error message describing the input, if one is shown. Marks
+    /// the input invalid.
+    #[props(default)]
+    error_id: String,
+) -> Element {
+    let i18n = use_i18n();
+    let book = use_address_book();
+    let placeholder = placeholder.unwrap_or_else(|| i18n.t("form.address_placeholder"));
+    let instance = use_hook(|| NEXT_LIST.fetch_add(1, Ordering::Relaxed));
+    let list_id = match list_id {
+        Some(list_id) => list_id,
+        None if !id.is_empty() => format!("{}-address-book", id),
+        None => format!("address-book-{}", instance),
+    };
+    let known = book.read().find(&value).cloned();
+    let owner = use_sns_owner(value.clone());
+
+    rsx! {
+        input {
+            id: (!id.is_empty()).then(|| id.clone()),
+            class: INPUT_CLASS,
+            r#type: "text",
+            list: "{list_id}",
+            aria_invalid: !error_id.is_empty(),
+            aria_describedby: (!error_id.is_empty()).then(|| error_id.clone()),
+            autocomplete: "off",
+            placeholder: "{placeholder}",
+            value: "{value}",
+            disabled,
+            oninput: move |evt| oninput.call(evt.value())
+        }
+        datalist {
+            id: "{list_id}",
+            for entry in book.read().entries.iter() {
+                option {
+                    key: "{entry.address}",
+                    value: "{entry.address}",
+                    {entry_description(entry)}
+                }
+            }
+        }
+        div {
+            aria_live: "polite",
+            {match owner {
+                Some(Ok(owner)) => rsx! {
+                    p { class: "mt-2 text-sm text-purple-300 font-mono break-all", "→ {owner}" }
+                },
+                Some(Err(e)) => rsx! {
+                    p { class: "mt-2 text-sm text-danger-fg", "{e}" }
+                },
+                None => rsx! {},
+            }}
+            if let Some(entry) = known {
+                p {
+                    class: "mt-2 text-sm text-purple-300",
+                    {i18n.t_with("form.address_book_entry", &[("label", &entry.label)])}
+                }
+            }
+        }
+    }
+}
+
+/// Label shown next to an autocomplete suggestion.
+fn entry_description(entry: &AddressBookEntry) -> String {
+    if entry.tags.is_empty() {
+        entry.label.clone()
+    } else {
+        format!("{} ({})", entry.label, entry.tags.join(", "))
+    }
+}
@@ -0,0 +1,29 @@
+use dioxus::prelude::*;
+use crate::hooks::{use_address_book, use_sns_name};
+use solana_sdk::pubkey::Pubkey;
+use std::str::FromStr;
+
+/// Short display name for an address: its address book label, else its
+/// primary `.sol` name, else the address shortened to its ends.
+#[component]
+pub fn AddressLabel(address: String) -> Element {
+    let book = use_address_book();
+    let name = use_sns_name(Pubkey::from_str(&address).ok());
+
+    let label = match (book.read().find(&address), name) {
+        (Some(entry), _) => entry.label.clone(),
+        (None, Some(name)) => name,
+        (None, None) => shorten_address(&address),
+    };
+
+    rsx! { "{label}" }
+}
+
+/// `7xKX…AsU` style abbreviation.
+pub fn shorten_address(address: &str) -> String {
+    if address.len() > 8 {
+        format!("{}…{}", &address[..4], &address[address.len() - 4..])
+    } else {
+        address.to_string()
+    }
+}
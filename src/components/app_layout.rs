@@ -1,16 +1,17 @@
 use dioxus::prelude::*;
-use crate::components::Navbar;
+use crate::components::{Navbar, ToastStack};
 
 #[component]
 pub fn AppLayout() -> Element {
     rsx! {
         div {
-            class: "min-h-screen bg-gradient-to-br from-gray-900 via-purple-900 to-gray-900",
+            class: "min-h-screen bg-gradient-to-br from-page-from via-page-via to-page-to",
             Navbar {}
             div {
                 class: "container mx-auto px-4 py-8",
                 Outlet::<Route> {}
             }
+            ToastStack {}
         }
     }
 }
@@ -0,0 +1,81 @@
+use dioxus::prelude::*;
+use crate::diagnostics::diagnostics;
+use crate::hooks::use_i18n;
+
+/// Shown by the app's error boundary in place of a page that failed to
+/// render. Sits outside the router, so links are plain anchors.
+#[component]
+pub fn ErrorScreen(errors: ErrorContext) -> Element {
+    let i18n = use_i18n();
+    let mut copied = use_signal(|| false);
+    let details = errors
+        .errors()
+        .iter()
+        .map(|error| format!("Error: {}", error))
+        .collect::<Vec<_>>()
+        .join("\n");
+    let report = diagnostics(&details);
+
+    let copy_report = report.clone();
+    let handle_copy = move |_| {
+        let report = copy_report.clone();
+        spawn(async move {
+            let mut eval = document::eval(
+                r#"
+                    const report = await dioxus.recv();
+                    await navigator.clipboard.writeText(report + "\nUser agent: " + navigator.userAgent);
+                    return true;
+                "#,
+            );
+            let _ = eval.send(report);
+            match eval.await {
+                Ok(_) => copied.set(true),
+                Err(e) => tracing::warn!("Failed to copy diagnostics: {:?}", e),
+            }
+        });
+    };
+
+    rsx! {
+        div {
+            class: "min-h-screen bg-gradient-to-br from-page-from via-page-via to-page-to flex items-center justify-center px-4",
+            role: "alert",
+            div {
+                class: "max-w-2xl w-full bg-surface/50 backdrop-blur-md rounded-2xl p-8 border border-line shadow-2xl space-y-4",
+                h1 {
+                    class: "text-3xl font-bold text-fg",
+                    {i18n.t("error.title")}
+                }
+                p {
+                    class: "text-fg-muted",
+                    {i18n.t("error.intro")}
+                }
+                pre {
+                    class: "bg-surface-sunken/50 border border-line-strong rounded-lg p-4 text-fg-muted text-xs font-mono whitespace-pre-wrap break-all",
+                    "{report}"
+                }
+                div {
+                    class: "flex flex-wrap gap-3",
+                    button {
+                        class: "bg-purple-600 hover:bg-purple-700 text-white font-medium px-4 py-2 rounded-lg transition-colors",
+                        onclick: move |_| errors.clear_errors(),
+                        {i18n.t("error.retry")}
+                    }
+                    a {
+                        class: "bg-surface-raised hover:bg-surface-strong text-fg font-medium px-4 py-2 rounded-lg transition-colors",
+                        href: "/",
+                        {i18n.t("error.home")}
+                    }
+                    button {
+                        class: "bg-surface-raised hover:bg-surface-strong text-fg font-medium px-4 py-2 rounded-lg transition-colors",
+                        onclick: handle_copy,
+                        if copied() {
+                            {i18n.t("error.copied")}
+                        } else {
+                            {i18n.t("error.copy")}
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,27 @@
+use dioxus::prelude::*;
+use crate::hooks::use_settings;
+use crate::solana::{Cluster, ExplorerTarget};
+
+/// Link to `target` on the explorer picked in settings, for the app's
+/// cluster. Opens in a new tab.
+#[component]
+pub fn ExplorerLink(
+    target: ExplorerTarget,
+    #[props(default = "text-purple-400 hover:text-purple-300 underline".to_string())] class: String,
+    children: Element,
+) -> Element {
+    let settings = use_settings().cloned();
+    let href = settings.explorer.url(&Cluster::from_rpc_url(settings.rpc_url()), &target);
+    let explorer_label = settings.explorer.label();
+
+    rsx! {
+        a {
+            class: "{class}",
+            href: "{href}",
+            target: "_blank",
+            rel: "noopener noreferrer",
+            title: "Open in {explorer_label}",
+            {children}
+        }
+    }
+}
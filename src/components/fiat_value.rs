@@ -0,0 +1,42 @@
+use dioxus::prelude::*;
+use crate::hooks::{use_i18n, use_settings, DisplayCurrency};
+
+/// `sol` converted to the display currency picked in settings. Renders
+/// nothing when that is SOL or the price can't be fetched.
+#[component]
+pub fn FiatValue(sol: f64) -> Element {
+    let currency = use_settings().read().currency;
+    let i18n = use_i18n();
+    let price = use_resource(use_reactive!(|currency| async move {
+        if currency == DisplayCurrency::Sol {
+            return None;
+        }
+
+        #[cfg(feature = "web")]
+        {
+            use crate::solana::fetch_sol_price;
+
+            match fetch_sol_price(currency).await {
+                Ok(price) => Some(price),
+                Err(e) => {
+                    tracing::warn!("{}", e);
+                    None
+                }
+            }
+        }
+
+        #[cfg(not(feature = "web"))]
+        {
+            None
+        }
+    }));
+
+    let Some(Some(price)) = price.cloned() else {
+        return rsx! {};
+    };
+    let value = format!("≈ {}", i18n.currency(sol * price, currency.symbol()));
+
+    rsx! {
+        p { class: "mt-2 text-sm text-fg-subtle", "{value}" }
+    }
+}
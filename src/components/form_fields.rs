@@ -0,0 +1,156 @@
+use dioxus::prelude::*;
+use crate::components::AddressInput;
+use crate::form::{FieldState, KNOWN_TOKENS};
+use crate::hooks::use_i18n;
+
+const INPUT_CLASS: &str = "w-full bg-surface-sunken/50 border border-line-strong rounded-lg px-4 py-3 text-fg placeholder-fg-faint focus:outline-none focus:border-purple-500 focus:ring-2 focus:ring-purple-500/50 transition-all";
+const LABEL_CLASS: &str = "block text-fg-muted font-medium mb-2";
+
+/// Custom-mint option of [`TokenSelect`].
+const CUSTOM_TOKEN: &str = "custom";
+
+/// Id of the [`FieldError`] for the control `id`, for `aria-describedby`
+/// while an error is shown.
+fn error_id(field: FieldState, id: &str) -> Option<String> {
+    field.visible_error().map(|_| format!("{}-error", id))
+}
+
+/// Inline error under a field, shown once the field has been touched.
+/// Announced as it appears. `id` is the control's id; the message gets
+/// `{id}-error`. Validator errors are catalog keys and shown translated.
+#[component]
+pub fn FieldError(field: FieldState, #[props(into)] id: String) -> Element {
+    let i18n = use_i18n();
+
+    rsx! {
+        if let Some(error) = field.visible_error() {
+            p { id: "{id}-error", class: "mt-2 text-sm text-danger-fg", role: "alert", {i18n.t(&error)} }
+        }
+    }
+}
+
+/// Address field with address book autocomplete and `.sol` name lookup.
+#[component]
+pub fn PubkeyField(
+    field: FieldState,
+    #[props(into)] id: String,
+    #[props(into)] label: String,
+    #[props(default)] disabled: bool,
+    /// Defaults to the translated address placeholder.
+    #[props(default)]
+    placeholder: Option<String>,
+) -> Element {
+    let i18n = use_i18n();
+    let placeholder = placeholder.unwrap_or_else(|| i18n.t("form.address_placeholder"));
+
+    rsx! {
+        div {
+            onfocusout: move |_| field.touch(),
+            label { class: LABEL_CLASS, r#for: "{id}", "{label}" }
+            AddressInput {
+                id: id.clone(),
+                error_id: error_id(field, &id).unwrap_or_default(),
+                value: field.raw(),
+                disabled,
+                placeholder,
+                oninput: move |value: String| field.set(value)
+            }
+            FieldError { field, id: id.clone() }
+        }
+    }
+}
+
+#[component]
+pub fn AmountField(
+    field: FieldState,
+    #[props(into)] id: String,
+    #[props(into)] label: String,
+    #[props(default)] disabled: bool,
+) -> Element {
+    rsx! {
+        div {
+            label { class: LABEL_CLASS, r#for: "{id}", "{label}" }
+            input {
+                id: "{id}",
+                class: INPUT_CLASS,
+                r#type: "text",
+                inputmode: "decimal",
+                placeholder: "0.0",
+                value: field.raw(),
+                disabled,
+                aria_invalid: field.visible_error().is_some(),
+                aria_describedby: error_id(field, &id),
+                oninput: move |evt| field.set(evt.value()),
+                onblur: move |_| field.touch()
+            }
+            FieldError { field, id: id.clone() }
+        }
+    }
+}
+
+/// SOL, one of the [`KNOWN_TOKENS`] or a custom mint. The field holds the
+/// mint address, empty for SOL.
+#[component]
+pub fn TokenSelect(
+    field: FieldState,
+    #[props(into)] id: String,
+    #[props(into)] label: String,
+    #[props(default)] disabled: bool,
+) -> Element {
+    let i18n = use_i18n();
+    let raw = field.raw();
+    let known = KNOWN_TOKENS
+        .iter()
+        .find(|(_, mint)| mint.to_string() == raw.trim())
+        .map(|(symbol, _)| *symbol);
+    let mut custom = use_signal(|| false);
+    let show_custom = custom.cloned() || (known.is_none() && !raw.trim().is_empty());
+    let selected = match known {
+        Some(symbol) => symbol,
+        None if show_custom => CUSTOM_TOKEN,
+        None => "",
+    };
+
+    rsx! {
+        div {
+            label { class: LABEL_CLASS, r#for: "{id}", "{label}" }
+            select {
+                id: "{id}",
+                class: INPUT_CLASS,
+                disabled,
+                onchange: move |evt| {
+                    let value = evt.value();
+                    custom.set(value == CUSTOM_TOKEN);
+                    let mint = KNOWN_TOKENS
+                        .iter()
+                        .find(|(symbol, _)| *symbol == value)
+                        .map(|(_, mint)| mint.to_string())
+                        .unwrap_or_default();
+                    field.set(mint);
+                    field.touch();
+                },
+                option { value: "", selected: selected.is_empty(), "SOL" }
+                for (symbol, _) in KNOWN_TOKENS {
+                    option { value: symbol, selected: selected == symbol, "{symbol}" }
+                }
+                option { value: CUSTOM_TOKEN, selected: selected == CUSTOM_TOKEN, {i18n.t("form.other_token")} }
+            }
+            if show_custom {
+                input {
+                    id: "{id}-mint",
+                    class: "{INPUT_CLASS} mt-2 font-mono",
+                    r#type: "text",
+                    placeholder: i18n.t("form.token_mint"),
+                    aria_label: i18n.t("form.token_mint"),
+                    value: "{raw}",
+                    disabled,
+                    aria_invalid: field.visible_error().is_some(),
+                    aria_describedby: error_id(field, &id),
+                    oninput: move |evt| field.set(evt.value()),
+                    onblur: move |_| field.touch()
+                }
+            }
+            FieldError { field, id: id.clone() }
+        }
+    }
+}
@@ -0,0 +1,30 @@
+use dioxus::prelude::*;
+use crate::hooks::{update_settings, use_i18n, use_settings, Language};
+
+/// Compact language picker for the navbar.
+#[component]
+pub fn LanguageSelect() -> Element {
+    let settings = use_settings();
+    let i18n = use_i18n();
+    let current = i18n.language();
+
+    rsx! {
+        select {
+            class: "bg-transparent text-fg-muted hover:text-fg text-sm font-medium focus:outline-none cursor-pointer",
+            title: i18n.t("nav.language"),
+            aria_label: i18n.t("nav.language"),
+            onchange: move |evt| {
+                if let Some(selected) = Language::from_key(&evt.value()) {
+                    update_settings(settings, |settings| settings.language = selected);
+                }
+            },
+            for language in Language::ALL {
+                option {
+                    value: language.key(),
+                    selected: current == language,
+                    {language.label()}
+                }
+            }
+        }
+    }
+}
@@ -1,7 +1,33 @@
+mod address_input;
+mod address_label;
 mod app_layout;
+mod error_screen;
+mod explorer_link;
+mod fiat_value;
+mod form_fields;
+mod language_select;
 mod navbar;
+mod offline_transaction;
+mod qr_code;
+mod theme_toggle;
+mod toast_stack;
+mod tx_queue_badge;
+mod value_tree;
 mod wallet_adapter;
 
+pub use address_input::*;
+pub use address_label::*;
 pub use app_layout::*;
+pub use error_screen::*;
+pub use explorer_link::*;
+pub use fiat_value::*;
+pub use form_fields::*;
+pub use language_select::*;
 pub use navbar::*;
+pub use offline_transaction::*;
+pub use qr_code::*;
+pub use theme_toggle::*;
+pub use toast_stack::*;
+pub use tx_queue_badge::*;
+pub use value_tree::*;
 pub use wallet_adapter::*;
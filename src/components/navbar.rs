@@ -1,12 +1,15 @@
 use dioxus::prelude::*;
 use crate::route::Route;
-use crate::components::WalletAdapter;
+use crate::components::{LanguageSelect, ThemeToggle, TxQueueBadge, WalletAdapter};
+use crate::hooks::use_i18n;
 
 #[component]
 pub fn Navbar() -> Element {
+    let i18n = use_i18n();
+
     rsx! {
         nav {
-            class: "bg-gray-800/50 backdrop-blur-md border-b border-gray-700",
+            class: "bg-surface/50 backdrop-blur-md border-b border-line",
             div {
                 class: "container mx-auto px-4",
                 div {
@@ -17,8 +20,8 @@ pub fn Navbar() -> Element {
                         class: "flex items-center space-x-4",
                         Link {
                             to: Route::Home {},
-                            class: "text-2xl font-bold text-white hover:text-purple-400 transition-colors",
-                            "Solana App"
+                            class: "text-2xl font-bold text-fg hover:text-purple-400 transition-colors",
+                            {i18n.t("nav.brand")}
                         }
                     }
 
@@ -27,14 +30,57 @@ pub fn Navbar() -> Element {
                         class: "flex items-center space-x-6",
                         Link {
                             to: Route::Home {},
-                            class: "text-gray-300 hover:text-white transition-colors font-medium",
-                            "Home"
+                            class: "text-fg-muted hover:text-fg transition-colors font-medium",
+                            {i18n.t("nav.home")}
                         }
                         Link {
                             to: Route::About {},
-                            class: "text-gray-300 hover:text-white transition-colors font-medium",
-                            "About"
+                            class: "text-fg-muted hover:text-fg transition-colors font-medium",
+                            {i18n.t("nav.about")}
+                        }
+                        Link {
+                            to: Route::Anchor {},
+                            class: "text-fg-muted hover:text-fg transition-colors font-medium",
+                            {i18n.t("nav.anchor")}
+                        }
+                        Link {
+                            to: Route::Stake {},
+                            class: "text-fg-muted hover:text-fg transition-colors font-medium",
+                            {i18n.t("nav.stake")}
+                        }
+                        Link {
+                            to: Route::Receive {},
+                            class: "text-fg-muted hover:text-fg transition-colors font-medium",
+                            {i18n.t("nav.receive")}
+                        }
+                        Link {
+                            to: Route::Batch {},
+                            class: "text-fg-muted hover:text-fg transition-colors font-medium",
+                            {i18n.t("nav.batch")}
+                        }
+                        Link {
+                            to: Route::Addresses {},
+                            class: "text-fg-muted hover:text-fg transition-colors font-medium",
+                            {i18n.t("nav.addresses")}
+                        }
+                        Link {
+                            to: Route::Nonce {},
+                            class: "text-fg-muted hover:text-fg transition-colors font-medium",
+                            {i18n.t("nav.nonce")}
+                        }
+                        Link {
+                            to: Route::Multisig {},
+                            class: "text-fg-muted hover:text-fg transition-colors font-medium",
+                            {i18n.t("nav.multisig")}
+                        }
+                        Link {
+                            to: Route::Settings {},
+                            class: "text-fg-muted hover:text-fg transition-colors font-medium",
+                            {i18n.t("nav.settings")}
                         }
+                        TxQueueBadge {}
+                        ThemeToggle {}
+                        LanguageSelect {}
                         
                         // Wallet adapter button
                         WalletAdapter {}
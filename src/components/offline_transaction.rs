@@ -0,0 +1,204 @@
+use dioxus::prelude::*;
+use crate::components::QrCode;
+use crate::hooks::{use_toasts, use_wallet, Wallet};
+use crate::solana::{import_signatures, missing_signers, TransactionEncoding};
+use solana_sdk::transaction::Transaction;
+
+const INPUT_CLASS: &str = "w-full bg-surface-sunken/50 border border-line-strong rounded-lg px-4 py-3 text-fg placeholder-fg-faint focus:outline-none focus:border-purple-500 focus:ring-2 focus:ring-purple-500/50 transition-all";
+const BUTTON_CLASS: &str = "bg-purple-600 hover:bg-purple-700 disabled:bg-surface-strong disabled:text-fg-subtle disabled:cursor-not-allowed text-white font-medium px-4 py-2 rounded-lg transition-colors";
+
+/// Largest export still offered as a QR code; bigger codes don't scan reliably.
+const MAX_QR_LEN: usize = 1_800;
+
+/// Exports a transaction for offline or multisig signing and assembles the
+/// signatures that come back. Once every required signer has signed, the
+/// transaction can be sent from here. Give it a `key` so a new transaction
+/// starts from a clean state.
+#[component]
+pub fn OfflineTransaction(transaction: Transaction) -> Element {
+    let wallet = use_wallet();
+    let mut current = use_signal(|| transaction.clone());
+    let mut encoding = use_signal(TransactionEncoding::default);
+    let mut import_text = use_signal(|| String::new());
+    let toasts = use_toasts();
+    let mut is_processing = use_signal(|| false);
+
+    let exported = encoding.cloned().encode(&current.read());
+    let missing = missing_signers(&current.read());
+    let wallet_can_sign = match wallet.cloned() {
+        Wallet::Connected(pubkey) => missing.contains(&pubkey),
+        Wallet::Disconnected => false,
+    };
+
+    let handle_import = move |_| {
+        let mut updated = current.cloned();
+        match import_signatures(&mut updated, &import_text.cloned()) {
+            Ok(0) => toasts.error("No new signatures for this transaction"),
+            Ok(count) => {
+                current.set(updated);
+                import_text.set(String::new());
+                toasts.success(format!("Added {} signatures", count));
+            }
+            Err(e) => toasts.error(e),
+        }
+    };
+
+    let handle_wallet_sign = move |_| {
+        let unsigned = current.cloned();
+
+        spawn(async move {
+            #[cfg(feature = "web")]
+            {
+                use crate::solana::sign_with_wallet;
+
+                is_processing.set(true);
+                match sign_with_wallet(&unsigned).await {
+                    Ok(signed) => {
+                        current.set(signed);
+                        toasts.success("Added your wallet's signature");
+                    }
+                    Err(e) => toasts.error(e),
+                }
+                is_processing.set(false);
+            }
+
+            #[cfg(not(feature = "web"))]
+            {
+                let _ = unsigned;
+                toasts.info("Signing is only available in web build");
+            }
+        });
+    };
+
+    let handle_send = move |_| {
+        let signed = current.cloned();
+
+        spawn(async move {
+            #[cfg(feature = "web")]
+            {
+                use crate::solana::{rpc_client, send_signed_transaction};
+
+                is_processing.set(true);
+                let client = rpc_client();
+                match send_signed_transaction(&client, &signed).await {
+                    Ok(signature) => toasts.success(format!("Transaction signature: {}", signature)),
+                    Err(e) => toasts.error(e),
+                }
+                is_processing.set(false);
+            }
+
+            #[cfg(not(feature = "web"))]
+            {
+                let _ = signed;
+                toasts.info("Sending is only available in web build");
+            }
+        });
+    };
+
+    rsx! {
+        div {
+            class: "space-y-4 p-4 rounded-lg bg-surface-sunken/50 border border-line",
+
+            div {
+                class: "flex items-center justify-between",
+                h4 { class: "text-fg font-semibold", "Offline signing" }
+                label {
+                    class: "flex items-center space-x-2 text-fg-subtle text-sm",
+                    span { "Encoding" }
+                    select {
+                        class: "bg-surface-sunken/50 border border-line-strong rounded-lg px-3 py-1 text-fg text-sm",
+                        onchange: move |evt| {
+                            let selected = TransactionEncoding::ALL
+                                .into_iter()
+                                .find(|encoding| encoding.label() == evt.value());
+                            if let Some(selected) = selected {
+                                encoding.set(selected);
+                            }
+                        },
+                        for option_encoding in TransactionEncoding::ALL {
+                            option {
+                                value: option_encoding.label(),
+                                selected: encoding.cloned() == option_encoding,
+                                {option_encoding.label()}
+                            }
+                        }
+                    }
+                }
+            }
+
+            {match exported {
+                Ok(exported) => rsx! {
+                    textarea {
+                        class: "{INPUT_CLASS} font-mono text-sm",
+                        aria_label: "Encoded transaction",
+                        rows: "4",
+                        readonly: true,
+                        value: "{exported}"
+                    }
+                    if exported.len() <= MAX_QR_LEN {
+                        div {
+                            class: "text-center",
+                            QrCode { data: exported.clone(), size: 320 }
+                        }
+                    } else {
+                        p { class: "text-fg-subtle text-sm", "Too large for a QR code; copy the text instead." }
+                    }
+                },
+                Err(e) => rsx! {
+                    p { class: "text-danger-fg text-sm", "Error: {e}" }
+                },
+            }}
+
+            if missing.is_empty() {
+                p { class: "text-success-fg text-sm", "✓ All required signatures present" }
+            } else {
+                div {
+                    p { class: "text-fg-muted text-sm", "Waiting for signatures from:" }
+                    for signer in missing.iter() {
+                        p { key: "{signer}", class: "font-mono text-fg-subtle text-sm break-all", "{signer}" }
+                    }
+                }
+            }
+
+            if wallet_can_sign {
+                button {
+                    class: BUTTON_CLASS,
+                    disabled: is_processing.cloned(),
+                    onclick: handle_wallet_sign,
+                    "Sign with connected wallet"
+                }
+            }
+
+            if !missing.is_empty() {
+                div {
+                    class: "space-y-2",
+                    label {
+                        class: "block space-y-2",
+                        span { class: "block text-fg-muted text-sm", "Signatures to import" }
+                        textarea {
+                            class: "{INPUT_CLASS} font-mono text-sm",
+                            rows: "3",
+                            placeholder: "Paste the signed transaction, or one pubkey=signature per line",
+                            value: "{import_text}",
+                            disabled: is_processing.cloned(),
+                            oninput: move |evt| import_text.set(evt.value())
+                        }
+                    }
+                    button {
+                        class: BUTTON_CLASS,
+                        disabled: is_processing.cloned() || import_text.read().trim().is_empty(),
+                        onclick: handle_import,
+                        "Import Signatures"
+                    }
+                }
+            } else {
+                button {
+                    class: BUTTON_CLASS,
+                    disabled: is_processing.cloned(),
+                    onclick: handle_send,
+                    if is_processing.cloned() { "Sending..." } else { "Send Transaction" }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,27 @@
+use dioxus::prelude::*;
+use qrcode::{render::svg, QrCode as Code};
+
+/// Renders `data` as an inline SVG QR code.
+#[component]
+pub fn QrCode(data: String, #[props(default = 256)] size: u32) -> Element {
+    let svg = match Code::new(data.as_bytes()) {
+        Ok(code) => code
+            .render::<svg::Color>()
+            .min_dimensions(size, size)
+            .dark_color(svg::Color("#000000"))
+            .light_color(svg::Color("#ffffff"))
+            .build(),
+        Err(e) => {
+            return rsx! {
+                p { class: "text-danger-fg text-sm", "Error: Could not render QR code: {e}" }
+            }
+        }
+    };
+
+    rsx! {
+        div {
+            class: "inline-block bg-white p-3 rounded-lg",
+            dangerous_inner_html: "{svg}"
+        }
+    }
+}
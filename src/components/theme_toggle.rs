@@ -0,0 +1,32 @@
+use dioxus::prelude::*;
+use crate::hooks::{update_settings, use_i18n, use_settings, use_theme, Theme};
+
+/// Navbar button cycling through dark, light and system themes.
+#[component]
+pub fn ThemeToggle() -> Element {
+    let settings = use_settings();
+    let i18n = use_i18n();
+    let theme = use_theme().cloned();
+    let next = theme.next();
+    let icon = match theme {
+        Theme::Dark => "🌙",
+        Theme::Light => "☀️",
+        Theme::System => "🖥️",
+    };
+    let title = i18n.t_with(
+        "theme.toggle",
+        &[
+            ("current", &i18n.t(&format!("theme.{}", theme.key()))),
+            ("next", &i18n.t(&format!("theme.{}", next.key()))),
+        ],
+    );
+
+    rsx! {
+        button {
+            class: "text-fg-muted hover:text-fg transition-colors",
+            title: "{title}",
+            onclick: move |_| update_settings(settings, |settings| settings.theme = next),
+            "{icon}"
+        }
+    }
+}
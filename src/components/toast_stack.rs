@@ -0,0 +1,84 @@
+use dioxus::prelude::*;
+use crate::hooks::{use_toasts, Toast, ToastLevel};
+
+/// Stacked notifications in the bottom-right corner. Rendered once by the
+/// layout; pages add toasts through [`use_toasts`]. The stack is a polite
+/// live region, so new toasts and status updates are announced; errors
+/// interrupt as alerts.
+#[component]
+pub fn ToastStack() -> Element {
+    let toasts = use_toasts();
+
+    rsx! {
+        div {
+            class: "fixed bottom-4 right-4 z-50 flex flex-col space-y-2 w-96 max-w-[calc(100vw-2rem)]",
+            aria_live: "polite",
+            aria_label: "Notifications",
+            for toast in toasts.read() {
+                ToastItem { key: "{toast.id}", toast: toast.clone() }
+            }
+        }
+    }
+}
+
+#[component]
+fn ToastItem(toast: Toast) -> Element {
+    let toasts = use_toasts();
+    let id = toast.id;
+    let level = toast.level;
+
+    // Restart the timer whenever the level changes, e.g. pending -> confirmed.
+    let _auto_dismiss = use_resource(use_reactive!(|(id, level)| async move {
+        let Some(timeout) = level.timeout_ms() else {
+            return;
+        };
+
+        #[cfg(feature = "web")]
+        {
+            gloo::timers::future::sleep(std::time::Duration::from_millis(timeout)).await;
+            toasts.dismiss(id);
+        }
+
+        #[cfg(not(feature = "web"))]
+        {
+            let _ = (id, timeout);
+        }
+    }));
+
+    let class = match toast.level {
+        ToastLevel::Success => "bg-success-surface/90 border-success text-success-fg",
+        ToastLevel::Error => "bg-danger-surface/90 border-danger text-danger-fg",
+        ToastLevel::Warning => "bg-warning-surface/90 border-warning text-warning-fg",
+        ToastLevel::Info | ToastLevel::Pending => "bg-info-surface/90 border-info text-info-fg",
+    };
+
+    rsx! {
+        div {
+            class: "p-4 rounded-lg border shadow-2xl backdrop-blur-md flex items-start space-x-3 {class}",
+            role: if toast.level == ToastLevel::Error { "alert" },
+            if toast.level == ToastLevel::Pending {
+                span { class: "animate-spin", aria_hidden: "true", "⟳" }
+            }
+            div {
+                class: "flex-1 min-w-0",
+                p { class: "text-sm break-words", "{toast.message}" }
+                if let Some(action) = &toast.action {
+                    a {
+                        class: "text-sm underline hover:text-fg",
+                        href: "{action.href}",
+                        target: "_blank",
+                        rel: "noopener noreferrer",
+                        "{action.label}"
+                    }
+                }
+            }
+            button {
+                class: "text-fg-subtle hover:text-fg",
+                title: "Dismiss",
+                aria_label: "Dismiss notification",
+                onclick: move |_| toasts.dismiss(id),
+                "×"
+            }
+        }
+    }
+}
@@ -0,0 +1,130 @@
+use dioxus::prelude::*;
+use std::rc::Rc;
+use crate::components::ExplorerLink;
+use crate::hooks::{use_i18n, use_tx_queue, TxStatus};
+use crate::solana::ExplorerTarget;
+
+/// Navbar button counting in-flight transactions, with a dropdown listing
+/// every queued transaction. Opening moves focus into the dropdown; Escape
+/// closes it and returns focus to the button.
+#[component]
+pub fn TxQueueBadge() -> Element {
+    let queue = use_tx_queue();
+    let i18n = use_i18n();
+    let mut open = use_signal(|| false);
+    let mut toggle_button = use_signal(|| None::<Rc<MountedData>>);
+
+    let transactions = queue.transactions();
+    let pending = queue.pending_count();
+    let has_finished = transactions.len() > pending;
+    let pending_title = i18n.t_count("activity.pending", pending as u64, &[]);
+    let button_label = format!("{} ({})", i18n.t("activity.button"), pending_title);
+
+    let mut close = move || {
+        open.set(false);
+        if let Some(button) = toggle_button.cloned() {
+            spawn(async move {
+                let _ = button.set_focus(true).await;
+            });
+        }
+    };
+
+    rsx! {
+        div {
+            class: "relative",
+            onkeydown: move |evt| {
+                if evt.key() == Key::Escape && open.cloned() {
+                    close();
+                }
+            },
+            button {
+                class: "relative text-fg-muted hover:text-fg transition-colors font-medium",
+                title: "{pending_title}",
+                aria_label: "{button_label}",
+                aria_expanded: open.cloned(),
+                aria_controls: "activity-panel",
+                onmounted: move |evt| toggle_button.set(Some(evt.data())),
+                onclick: move |_| open.toggle(),
+                {i18n.t("activity.button")}
+                if pending > 0 {
+                    span {
+                        class: "absolute -top-2 -right-4 bg-purple-600 text-white text-xs font-bold rounded-full px-1.5 py-0.5 animate-pulse",
+                        aria_hidden: "true",
+                        "{pending}"
+                    }
+                }
+            }
+
+            if open.cloned() {
+                div {
+                    id: "activity-panel",
+                    class: "absolute right-0 mt-3 w-96 bg-surface border border-line rounded-lg shadow-2xl z-50 p-4 space-y-3",
+                    role: "region",
+                    aria_label: i18n.t("activity.title"),
+                    tabindex: "-1",
+                    onmounted: move |evt| async move {
+                        let _ = evt.data().set_focus(true).await;
+                    },
+                    div {
+                        class: "flex items-center justify-between",
+                        h4 { class: "text-fg font-semibold", {i18n.t("activity.title")} }
+                        if has_finished {
+                            button {
+                                class: "text-fg-subtle hover:text-fg text-sm",
+                                onclick: move |_| queue.clear_finished(),
+                                {i18n.t("activity.clear_finished")}
+                            }
+                        }
+                    }
+                    if transactions.is_empty() {
+                        p { class: "text-fg-subtle text-sm", {i18n.t("activity.empty")} }
+                    }
+                    div {
+                        class: "max-h-96 overflow-y-auto space-y-2",
+                        for transaction in transactions.iter().rev() {
+                            div {
+                                key: "{transaction.id}",
+                                class: "bg-surface-sunken/50 rounded-lg p-3",
+                                div {
+                                    class: "flex items-center justify-between",
+                                    p { class: "text-fg text-sm", "{transaction.label}" }
+                                    span {
+                                        class: match transaction.status {
+                                            TxStatus::Confirmed => "text-success-fg text-xs whitespace-nowrap ml-2",
+                                            TxStatus::Failed { .. } => "text-danger-fg text-xs whitespace-nowrap ml-2",
+                                            _ => "text-info-fg text-xs whitespace-nowrap ml-2",
+                                        },
+                                        {i18n.t(transaction.status.message_key())}
+                                    }
+                                }
+                                if let Some(created_at) = transaction.created_at {
+                                    p { class: "text-fg-faint text-xs", {i18n.date(created_at)} }
+                                }
+                                if let TxStatus::Failed { error } = &transaction.status {
+                                    p { class: "text-danger-fg text-xs break-words mt-1", "{error}" }
+                                }
+                                div {
+                                    class: "flex space-x-3 mt-1",
+                                    if let Some(signature) = &transaction.signature {
+                                        ExplorerLink {
+                                            target: ExplorerTarget::Transaction(signature.clone()),
+                                            class: "text-purple-400 hover:text-purple-300 text-xs underline",
+                                            {i18n.t("activity.view_transaction")}
+                                        }
+                                    }
+                                    if let Some(slot) = transaction.slot {
+                                        ExplorerLink {
+                                            target: ExplorerTarget::Block(slot),
+                                            class: "text-purple-400 hover:text-purple-300 text-xs underline",
+                                            {i18n.t_with("activity.block", &[("slot", &slot.to_string())])}
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,58 @@
+use dioxus::prelude::*;
+use serde_json::Value;
+
+/// Renders a decoded value tree as nested key/value rows.
+#[component]
+pub fn ValueTree(value: Value) -> Element {
+    match value {
+        Value::Object(map) => rsx! {
+            div {
+                class: "space-y-1",
+                for (key, child) in map.into_iter() {
+                    div {
+                        key: "{key}",
+                        class: if is_leaf(&child) { "flex flex-wrap gap-x-2" } else { "" },
+                        span { class: "text-purple-400 font-medium", "{key}:" }
+                        if is_leaf(&child) {
+                            ValueTree { value: child }
+                        } else {
+                            div {
+                                class: "ml-4 pl-3 border-l border-line",
+                                ValueTree { value: child }
+                            }
+                        }
+                    }
+                }
+            }
+        },
+        Value::Array(items) if items.is_empty() => rsx! {
+            span { class: "text-fg-faint", "[]" }
+        },
+        Value::Array(items) => rsx! {
+            div {
+                class: "space-y-1",
+                for (i, child) in items.into_iter().enumerate() {
+                    div {
+                        key: "{i}",
+                        class: "flex gap-x-2",
+                        span { class: "text-fg-faint", "[{i}]" }
+                        div { ValueTree { value: child } }
+                    }
+                }
+            }
+        },
+        Value::Null => rsx! {
+            span { class: "text-fg-faint italic", "null" }
+        },
+        Value::String(s) => rsx! {
+            span { class: "text-success-fg break-all", "{s}" }
+        },
+        other => rsx! {
+            span { class: "text-info-fg", "{other}" }
+        },
+    }
+}
+
+fn is_leaf(value: &Value) -> bool {
+    !matches!(value, Value::Object(_) | Value::Array(_)) || value.as_array().is_some_and(|a| a.is_empty())
+}
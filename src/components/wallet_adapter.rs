@@ -1,6 +1,6 @@
 use dioxus::prelude::*;
 use dioxus::document::eval;
-use crate::hooks::{use_wallet, Wallet, disconnect_wallet};
+use crate::hooks::{use_sns_name, use_wallet, Wallet, disconnect_wallet};
 
 #[component]
 pub fn WalletAdapter() -> Element {
@@ -25,6 +25,12 @@ pub fn WalletAdapter() -> Element {
         }
     });
 
+    // Show the wallet's primary .sol name when it has one
+    let name = use_sns_name(match wallet.cloned() {
+        Wallet::Connected(address) => Some(address),
+        Wallet::Disconnected => None,
+    });
+
     match wallet.cloned() {
         Wallet::Connected(address) => {
             let len = address.to_string().len();
@@ -36,15 +42,24 @@ pub fn WalletAdapter() -> Element {
                     class: "flex items-center space-x-2",
                     div {
                         class: "bg-purple-600 text-white px-4 py-2 rounded-lg font-medium",
-                        "{first_four}...{last_four}"
+                        title: "{address}",
+                        span { class: "sr-only", "Connected wallet: " }
+                        span { aria_hidden: "true", "✓ " }
+                        if let Some(name) = name {
+                            "{name}"
+                        } else {
+                            "{first_four}...{last_four}"
+                        }
                     }
                     button {
                         class: "bg-red-600 hover:bg-red-700 text-white px-4 py-2 rounded-lg font-medium transition-colors",
+                        aria_label: "Disconnect wallet",
                         onclick: move |_| {
                             spawn(async move {
                                 disconnect_wallet().await;
                             });
                         },
+                        span { aria_hidden: "true", "⏏ " }
                         "Disconnect"
                     }
                 }
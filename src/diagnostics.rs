@@ -0,0 +1,113 @@
+//! Crash reporting. Render errors are caught by the error boundary in `App`;
+//! panics abort the wasm instance, so the panic hook draws a static error
+//! screen straight into the DOM before that happens.
+
+use crate::telemetry::recent_events;
+
+/// Message and source location of a panic.
+#[derive(Clone, Debug, PartialEq)]
+pub struct PanicReport {
+    pub message: String,
+    pub location: Option<String>,
+}
+
+impl PanicReport {
+    pub fn from_info(info: &std::panic::PanicHookInfo) -> Self {
+        let payload = info.payload();
+        let message = payload
+            .downcast_ref::<&str>()
+            .map(|message| message.to_string())
+            .or_else(|| payload.downcast_ref::<String>().cloned())
+            .unwrap_or_else(|| "Unknown panic".to_string());
+        let location = info
+            .location()
+            .map(|location| format!("{}:{}:{}", location.file(), location.line(), location.column()));
+        Self { message, location }
+    }
+
+    fn summary(&self) -> String {
+        match &self.location {
+            Some(location) => format!("Panic: {}\nAt: {}", self.message, location),
+            None => format!("Panic: {}", self.message),
+        }
+    }
+}
+
+/// How many of the latest telemetry events a report includes.
+const REPORT_EVENTS: usize = 20;
+
+/// Text to paste into a bug report: app version, current page, `details`
+/// and the latest telemetry events.
+pub fn diagnostics(details: &str) -> String {
+    #[cfg(feature = "web")]
+    let page = gloo::utils::window().location().href().unwrap_or_default();
+    #[cfg(not(feature = "web"))]
+    let page = String::new();
+
+    let events = recent_events();
+    let recent = events[events.len().saturating_sub(REPORT_EVENTS)..]
+        .iter()
+        .map(|event| event.summary())
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    format!(
+        "{} {}\nPage: {}\n\n{}\n\nRecent events:\n{}",
+        env!("CARGO_PKG_NAME"),
+        env!("CARGO_PKG_VERSION"),
+        page,
+        details,
+        recent
+    )
+}
+
+/// Reports panics on screen, after the previously installed hook (which logs
+/// to the console) has run. Install it after launch so the renderer's own
+/// hook is kept.
+pub fn install_panic_hook() {
+    let previous = std::panic::take_hook();
+    std::panic::set_hook(Box::new(move |info| {
+        previous(info);
+        let report = PanicReport::from_info(info);
+        show_panic_screen(&report);
+    }));
+}
+
+/// Replaces the page with a static error screen. Plain HTML and inline
+/// handlers, since no Rust runs after the panic.
+fn show_panic_screen(report: &PanicReport) {
+    #[cfg(feature = "web")]
+    {
+        let diagnostics = escape_html(&diagnostics(&report.summary()));
+        let html = format!(
+            r#"<div class="min-h-screen bg-gradient-to-br from-page-from via-page-via to-page-to flex items-center justify-center px-4" role="alert">
+  <div class="max-w-2xl w-full bg-surface/50 rounded-2xl p-8 border border-line shadow-2xl space-y-4">
+    <h1 class="text-3xl font-bold text-fg">Something went wrong</h1>
+    <p class="text-fg-muted">The app hit an unexpected error and has to be reloaded. Unsent form input is lost; transactions already submitted are not affected.</p>
+    <pre id="panic-diagnostics" class="bg-surface-sunken/50 border border-line-strong rounded-lg p-4 text-fg-muted text-xs font-mono whitespace-pre-wrap break-all">{}</pre>
+    <div class="flex space-x-3">
+      <button class="bg-purple-600 hover:bg-purple-700 text-white font-medium px-4 py-2 rounded-lg" onclick="location.reload()">Reload</button>
+      <button class="bg-surface-raised hover:bg-surface-strong text-fg font-medium px-4 py-2 rounded-lg" onclick="navigator.clipboard.writeText(document.getElementById('panic-diagnostics').textContent + '\nUser agent: ' + navigator.userAgent); this.textContent = 'Copied'">Copy diagnostics</button>
+    </div>
+  </div>
+</div>"#,
+            diagnostics
+        );
+        if let Some(body) = gloo::utils::document().body() {
+            body.set_inner_html(&html);
+        }
+    }
+
+    #[cfg(not(feature = "web"))]
+    {
+        let _ = report;
+    }
+}
+
+#[cfg(feature = "web")]
+fn escape_html(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
@@ -0,0 +1,131 @@
+use dioxus::prelude::*;
+
+/// Untyped state of a form field: what the user typed, whether they have
+/// left the field yet, and the current validation error. Field components
+/// only need this part.
+#[derive(Clone, Copy, PartialEq)]
+pub struct FieldState {
+    raw: Signal<String>,
+    initial: Signal<String>,
+    touched: Signal<bool>,
+    error: Memo<Option<String>>,
+}
+
+impl FieldState {
+    pub fn raw(&self) -> String {
+        self.raw.cloned()
+    }
+
+    pub fn set(&self, value: impl Into<String>) {
+        let mut raw = self.raw;
+        raw.set(value.into());
+    }
+
+    /// Marks the field as visited, which makes its error visible.
+    pub fn touch(&self) {
+        let mut touched = self.touched;
+        touched.set(true);
+    }
+
+    pub fn is_touched(&self) -> bool {
+        self.touched.cloned()
+    }
+
+    /// Changed from the value the field started with.
+    pub fn is_dirty(&self) -> bool {
+        *self.raw.read() != *self.initial.read()
+    }
+
+    pub fn is_valid(&self) -> bool {
+        self.error.read().is_none()
+    }
+
+    /// Validation error, whether or not it is shown yet.
+    pub fn error(&self) -> Option<String> {
+        self.error.cloned()
+    }
+
+    /// Error to show inline: only once the field has been touched.
+    pub fn visible_error(&self) -> Option<String> {
+        self.error().filter(|_| self.is_touched())
+    }
+
+    /// Back to the initial value, untouched.
+    pub fn reset(&self) {
+        let (mut raw, mut touched) = (self.raw, self.touched);
+        raw.set(self.initial.cloned());
+        touched.set(false);
+    }
+}
+
+/// A form field whose text parses into a `T`.
+pub struct Field<T: 'static> {
+    state: FieldState,
+    parse: fn(&str) -> Result<T, String>,
+}
+
+impl<T: 'static> Clone for Field<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T: 'static> Copy for Field<T> {}
+
+impl<T: 'static> PartialEq for Field<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.state == other.state
+    }
+}
+
+impl<T: 'static> Field<T> {
+    pub fn state(&self) -> FieldState {
+        self.state
+    }
+
+    pub fn raw(&self) -> String {
+        self.state.raw()
+    }
+
+    pub fn set(&self, value: impl Into<String>) {
+        self.state.set(value)
+    }
+
+    /// The parsed value, or the validation error.
+    pub fn value(&self) -> Result<T, String> {
+        (self.parse)(&self.state.raw.read())
+    }
+}
+
+/// A field starting at `initial` and validated by `parse` on every change.
+pub fn use_field<T: 'static>(initial: &str, parse: fn(&str) -> Result<T, String>) -> Field<T> {
+    let initial = use_signal(|| initial.to_string());
+    let raw = use_signal(|| initial.cloned());
+    let touched = use_signal(|| false);
+    let error = use_memo(move || parse(&raw.read()).err());
+
+    Field {
+        state: FieldState {
+            raw,
+            initial,
+            touched,
+            error,
+        },
+        parse,
+    }
+}
+
+/// Submit gate: touches every field so their errors show, and returns
+/// whether all of them are valid.
+pub fn validate_all(fields: &[FieldState]) -> bool {
+    fields.iter().for_each(FieldState::touch);
+    fields.iter().all(FieldState::is_valid)
+}
+
+pub fn any_dirty(fields: &[FieldState]) -> bool {
+    fields.iter().any(FieldState::is_dirty)
+}
+
+pub fn reset_all(fields: &[FieldState]) {
+    fields.iter().for_each(FieldState::reset);
+}
@@ -0,0 +1,5 @@
+mod field;
+mod validators;
+
+pub use field::*;
+pub use validators::*;
@@ -0,0 +1,58 @@
+use solana_sdk::{pubkey, pubkey::Pubkey};
+use std::str::FromStr;
+
+use crate::solana::is_sns_name;
+
+/// Tokens offered by the token picker before falling back to a custom mint.
+pub const KNOWN_TOKENS: [(&str, Pubkey); 2] = [
+    ("USDC", pubkey!("EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v")),
+    ("USDT", pubkey!("Es9vMFrzaCERmJfrF4H2FYD4KCoNkY11McCE8BenwNYB")),
+];
+
+// Errors are catalog keys; `FieldError` shows them translated.
+
+pub fn parse_pubkey(input: &str) -> Result<Pubkey, String> {
+    match input.trim() {
+        "" => Err("form.address_required".to_string()),
+        value => Pubkey::from_str(value).map_err(|_| "form.invalid_address".to_string()),
+    }
+}
+
+pub fn parse_optional_pubkey(input: &str) -> Result<Option<Pubkey>, String> {
+    match input.trim() {
+        "" => Ok(None),
+        value => parse_pubkey(value).map(Some),
+    }
+}
+
+/// An address or a `.sol` name; names are resolved when the form is
+/// submitted.
+pub fn parse_address_or_name(input: &str) -> Result<String, String> {
+    let input = input.trim();
+    if is_sns_name(input) {
+        return Ok(input.to_string());
+    }
+    parse_pubkey(input).map(|address| address.to_string())
+}
+
+/// A positive decimal amount, kept as text so it can be converted to base
+/// units once the mint decimals are known.
+pub fn parse_amount(input: &str) -> Result<String, String> {
+    let amount = input.trim();
+    if amount.is_empty() {
+        return Err("form.amount_required".to_string());
+    }
+    let (whole, fraction) = amount.split_once('.').unwrap_or((amount, ""));
+    if !whole.chars().all(|c| c.is_ascii_digit()) || !fraction.chars().all(|c| c.is_ascii_digit()) {
+        return Err("form.invalid_amount".to_string());
+    }
+    if !amount.chars().any(|c| c.is_ascii_digit() && c != '0') {
+        return Err("form.amount_zero".to_string());
+    }
+    Ok(amount.to_string())
+}
+
+/// Token mint, or `None` for SOL.
+pub fn parse_token(input: &str) -> Result<Option<Pubkey>, String> {
+    parse_optional_pubkey(input).map_err(|_| "form.invalid_token".to_string())
+}
@@ -1,3 +1,17 @@
+mod use_address_book;
+mod use_i18n;
+mod use_settings;
+mod use_sns;
+mod use_theme;
+mod use_toasts;
+mod use_tx_queue;
 mod use_wallet;
 
+pub use use_address_book::*;
+pub use use_i18n::*;
+pub use use_settings::*;
+pub use use_sns::*;
+pub use use_theme::*;
+pub use use_toasts::*;
+pub use use_tx_queue::*;
 pub use use_wallet::*;
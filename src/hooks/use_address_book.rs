@@ -0,0 +1,162 @@
+use dioxus::prelude::*;
+use serde::{Deserialize, Serialize};
+use solana_sdk::pubkey::Pubkey;
+use std::str::FromStr;
+
+#[cfg(feature = "web")]
+const ADDRESS_BOOK_KEY: &str = "address_book";
+
+/// How many recently paid addresses are remembered.
+const MAX_RECENT: usize = 10;
+
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct AddressBookEntry {
+    /// Base58 address, kept as a string so exported files stay readable.
+    pub address: String,
+    pub label: String,
+    #[serde(default)]
+    pub note: String,
+    #[serde(default)]
+    pub tags: Vec<String>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct AddressBook {
+    #[serde(default)]
+    pub entries: Vec<AddressBookEntry>,
+    /// Addresses of confirmed transfers, most recent first.
+    #[serde(default)]
+    pub recent: Vec<String>,
+}
+
+impl AddressBookEntry {
+    /// Trims the fields, drops empty and duplicate tags and checks the address.
+    pub fn normalized(mut self) -> Result<Self, String> {
+        self.address = self.address.trim().to_string();
+        Pubkey::from_str(&self.address).map_err(|_| format!("Invalid address: {}", self.address))?;
+        self.label = self.label.trim().to_string();
+        if self.label.is_empty() {
+            return Err(format!("Missing label for {}", self.address));
+        }
+        self.note = self.note.trim().to_string();
+        let mut tags: Vec<String> = Vec::new();
+        for tag in self.tags.iter().map(|tag| tag.trim().to_lowercase()) {
+            if !tag.is_empty() && !tags.contains(&tag) {
+                tags.push(tag);
+            }
+        }
+        self.tags = tags;
+        Ok(self)
+    }
+
+    /// Whether the entry matches a search across label, address, note and tags.
+    pub fn matches(&self, query: &str) -> bool {
+        let query = query.trim().to_lowercase();
+        query.is_empty()
+            || self.label.to_lowercase().contains(&query)
+            || self.address.to_lowercase().contains(&query)
+            || self.note.to_lowercase().contains(&query)
+            || self.tags.iter().any(|tag| tag.contains(&query))
+    }
+}
+
+/// Splits a comma separated tag list as typed into a form.
+pub fn parse_tags(text: &str) -> Vec<String> {
+    text.split(',').map(|tag| tag.trim().to_string()).filter(|tag| !tag.is_empty()).collect()
+}
+
+impl AddressBook {
+    pub fn load() -> Self {
+        #[cfg(feature = "web")]
+        {
+            use gloo_storage::{LocalStorage, Storage};
+            LocalStorage::get(ADDRESS_BOOK_KEY).unwrap_or_default()
+        }
+
+        #[cfg(not(feature = "web"))]
+        {
+            AddressBook::default()
+        }
+    }
+
+    pub fn save(&self) {
+        #[cfg(feature = "web")]
+        {
+            use gloo_storage::{LocalStorage, Storage};
+            if let Err(e) = LocalStorage::set(ADDRESS_BOOK_KEY, self) {
+                tracing::warn!("Failed to save address book: {:?}", e);
+            }
+        }
+    }
+
+    pub fn find(&self, address: &str) -> Option<&AddressBookEntry> {
+        let address = address.trim();
+        self.entries.iter().find(|entry| entry.address == address)
+    }
+
+    /// Adds an entry, or replaces the one with the same address.
+    pub fn upsert(&mut self, entry: AddressBookEntry) -> Result<(), String> {
+        let entry = entry.normalized()?;
+        match self.entries.iter_mut().find(|existing| existing.address == entry.address) {
+            Some(existing) => *existing = entry,
+            None => self.entries.push(entry),
+        }
+        self.entries.sort_by_key(|entry| entry.label.to_lowercase());
+        Ok(())
+    }
+
+    pub fn remove(&mut self, address: &str) {
+        self.entries.retain(|entry| entry.address != address);
+    }
+
+    /// Moves `address` to the front of the recent recipients.
+    pub fn record_recent(&mut self, address: &Pubkey) {
+        let address = address.to_string();
+        self.recent.retain(|recent| *recent != address);
+        self.recent.insert(0, address);
+        self.recent.truncate(MAX_RECENT);
+    }
+
+    /// Entries as a pretty-printed JSON array for sharing.
+    pub fn export_json(&self) -> String {
+        serde_json::to_string_pretty(&self.entries).unwrap_or_else(|_| "[]".to_string())
+    }
+
+    /// Merges a JSON array of entries, as produced by [`Self::export_json`].
+    /// Imported labels and notes win; tags are combined. Returns how many
+    /// entries were imported.
+    pub fn import_json(&mut self, json: &str) -> Result<usize, String> {
+        let imported: Vec<AddressBookEntry> =
+            serde_json::from_str(json).map_err(|e| format!("Invalid address book file: {}", e))?;
+        let imported = imported
+            .into_iter()
+            .map(AddressBookEntry::normalized)
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let count = imported.len();
+        for mut entry in imported {
+            if let Some(existing) = self.find(&entry.address) {
+                let mut tags = existing.tags.clone();
+                tags.extend(entry.tags);
+                entry.tags = tags;
+            }
+            self.upsert(entry)?;
+        }
+        Ok(count)
+    }
+}
+
+pub fn use_address_book_provider() {
+    use_context_provider(|| Signal::new(AddressBook::load()));
+}
+
+pub fn use_address_book() -> Signal<AddressBook> {
+    use_context::<Signal<AddressBook>>()
+}
+
+/// Applies `update` to the shared address book and saves it.
+pub fn update_address_book(mut book: Signal<AddressBook>, update: impl FnOnce(&mut AddressBook)) {
+    let mut book = book.write();
+    update(&mut book);
+    book.save();
+}
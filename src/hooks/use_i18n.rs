@@ -0,0 +1,100 @@
+use dioxus::prelude::*;
+
+use crate::hooks::{use_settings, Language};
+use crate::i18n::{format_currency, format_date, format_decimal, lookup, plural_category, Message};
+
+/// Translates messages and formats numbers and dates for the language picked
+/// in settings. Reading it in a component re-renders on language changes.
+#[derive(Clone, Copy, PartialEq)]
+pub struct I18n {
+    language: Memo<Language>,
+}
+
+impl I18n {
+    pub fn language(&self) -> Language {
+        self.language.cloned()
+    }
+
+    /// The message for `key`. Missing keys render as the key itself so they
+    /// stand out.
+    pub fn t(&self, key: &str) -> String {
+        self.t_with(key, &[])
+    }
+
+    /// The message for `key` with its `{name}` placeholders filled from `args`.
+    pub fn t_with(&self, key: &str, args: &[(&str, &str)]) -> String {
+        let text = match lookup(self.language(), key) {
+            Some(Message::Text(text)) => text.as_str(),
+            Some(Message::Plural(forms)) => forms.get("other").map(String::as_str).unwrap_or(key),
+            None => key,
+        };
+        fill(text, args)
+    }
+
+    /// The plural form of `key` matching `count`, with `{count}` filled in
+    /// as a formatted number alongside `args`.
+    pub fn t_count(&self, key: &str, count: u64, args: &[(&str, &str)]) -> String {
+        let language = self.language();
+        let text = match lookup(language, key) {
+            Some(Message::Plural(forms)) => forms
+                .get(plural_category(language, count))
+                .or_else(|| forms.get("other"))
+                .map(String::as_str)
+                .unwrap_or(key),
+            Some(Message::Text(text)) => text.as_str(),
+            None => key,
+        };
+        let count = format_decimal(language, &count.to_string());
+        let mut all_args = vec![("count", count.as_str())];
+        all_args.extend_from_slice(args);
+        fill(text, &all_args)
+    }
+
+    /// A decimal amount string, such as a SOL or token amount, with the
+    /// language's separators.
+    pub fn amount(&self, amount: &str) -> String {
+        format_decimal(self.language(), amount)
+    }
+
+    /// `value` as a price in the currency written `symbol`.
+    pub fn currency(&self, value: f64, symbol: &str) -> String {
+        format_currency(self.language(), value, symbol)
+    }
+
+    pub fn date(&self, unix_seconds: i64) -> String {
+        format_date(self.language(), unix_seconds)
+    }
+}
+
+fn fill(text: &str, args: &[(&str, &str)]) -> String {
+    args.iter()
+        .fold(text.to_string(), |text, (name, value)| text.replace(&format!("{{{}}}", name), value))
+}
+
+/// Provides [`I18n`] and keeps the root element's `lang` in sync. Must run
+/// after the settings provider.
+pub fn use_i18n_provider() {
+    let settings = use_settings();
+    let language = use_memo(move || settings.read().language);
+    use_context_provider(|| I18n { language });
+
+    use_effect(move || {
+        let language = language();
+
+        #[cfg(feature = "web")]
+        {
+            if let Err(e) = gloo::utils::document_element().set_attribute("lang", language.key()) {
+                tracing::warn!("Failed to set document language: {:?}", e);
+            }
+        }
+
+        #[cfg(not(feature = "web"))]
+        {
+            let _ = language;
+        }
+    });
+}
+
+pub fn use_i18n() -> I18n {
+    use_context::<I18n>()
+}
@@ -0,0 +1,346 @@
+use dioxus::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::solana::{Explorer, DEFAULT_RPC_URL};
+
+#[cfg(feature = "web")]
+const SETTINGS_KEY: &str = "settings";
+
+/// Bumped whenever a saved field is renamed, removed or changes meaning, with
+/// a matching upgrade step in [`read_saved`]. Added fields only need a serde
+/// default.
+pub const SETTINGS_VERSION: u32 = 1;
+
+/// Commitment level reads use and transactions are confirmed at.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Commitment {
+    Processed,
+    #[default]
+    Confirmed,
+    Finalized,
+}
+
+impl Commitment {
+    pub const ALL: [Commitment; 3] = [Commitment::Processed, Commitment::Confirmed, Commitment::Finalized];
+
+    pub fn key(&self) -> &'static str {
+        match self {
+            Commitment::Processed => "processed",
+            Commitment::Confirmed => "confirmed",
+            Commitment::Finalized => "finalized",
+        }
+    }
+
+    pub fn from_key(key: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|commitment| commitment.key() == key)
+    }
+}
+
+/// Currency SOL amounts are also shown in. [`DisplayCurrency::Sol`] shows
+/// no conversion.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum DisplayCurrency {
+    #[default]
+    Sol,
+    Usd,
+    Eur,
+}
+
+impl DisplayCurrency {
+    pub const ALL: [DisplayCurrency; 3] = [DisplayCurrency::Sol, DisplayCurrency::Usd, DisplayCurrency::Eur];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            DisplayCurrency::Sol => "SOL only",
+            DisplayCurrency::Usd => "US dollar",
+            DisplayCurrency::Eur => "Euro",
+        }
+    }
+
+    pub fn key(&self) -> &'static str {
+        match self {
+            DisplayCurrency::Sol => "sol",
+            DisplayCurrency::Usd => "usd",
+            DisplayCurrency::Eur => "eur",
+        }
+    }
+
+    pub fn from_key(key: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|currency| currency.key() == key)
+    }
+
+    pub fn symbol(&self) -> &'static str {
+        match self {
+            DisplayCurrency::Sol => "◎",
+            DisplayCurrency::Usd => "$",
+            DisplayCurrency::Eur => "€",
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Theme {
+    #[default]
+    Dark,
+    Light,
+    /// Follow the operating system's preference.
+    System,
+}
+
+impl Theme {
+    pub const ALL: [Theme; 3] = [Theme::Dark, Theme::Light, Theme::System];
+
+    pub fn key(&self) -> &'static str {
+        match self {
+            Theme::Dark => "dark",
+            Theme::Light => "light",
+            Theme::System => "system",
+        }
+    }
+
+    pub fn from_key(key: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|theme| theme.key() == key)
+    }
+
+    /// The theme after this one in [`Theme::ALL`], wrapping around.
+    pub fn next(&self) -> Self {
+        let index = Self::ALL.iter().position(|theme| theme == self).unwrap_or(0);
+        Self::ALL[(index + 1) % Self::ALL.len()]
+    }
+}
+
+/// How signed transactions reach the network.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum SendStrategy {
+    /// Let the wallet submit through its native `signAndSendTransaction`,
+    /// falling back to [`SendStrategy::Rpc`] if the wallet has none.
+    #[default]
+    Wallet,
+    /// Have the wallet only sign, then submit through the app's RPC.
+    Rpc,
+}
+
+impl SendStrategy {
+    pub const ALL: [SendStrategy; 2] = [SendStrategy::Wallet, SendStrategy::Rpc];
+
+    pub fn key(&self) -> &'static str {
+        match self {
+            SendStrategy::Wallet => "wallet",
+            SendStrategy::Rpc => "rpc",
+        }
+    }
+
+    pub fn from_key(key: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|strategy| strategy.key() == key)
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Language {
+    #[default]
+    English,
+    Spanish,
+}
+
+impl Language {
+    pub const ALL: [Language; 2] = [Language::English, Language::Spanish];
+
+    /// Name of the language in itself.
+    pub fn label(&self) -> &'static str {
+        match self {
+            Language::English => "English",
+            Language::Spanish => "Español",
+        }
+    }
+
+    pub fn key(&self) -> &'static str {
+        match self {
+            Language::English => "en",
+            Language::Spanish => "es",
+        }
+    }
+
+    pub fn from_key(key: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|language| language.key() == key)
+    }
+}
+
+/// App-wide preferences, saved in local storage as one versioned object.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Settings {
+    pub version: u32,
+    pub rpc_url: String,
+    pub commitment: Commitment,
+    pub explorer: Explorer,
+    /// Compute unit price added to sent transactions, in micro-lamports.
+    /// Zero adds no compute budget instruction.
+    pub priority_fee: u64,
+    pub send_strategy: SendStrategy,
+    pub currency: DisplayCurrency,
+    pub theme: Theme,
+    pub language: Language,
+    /// Collector telemetry batches are posted to. Empty keeps telemetry in
+    /// the browser.
+    pub telemetry_url: String,
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Self {
+            version: SETTINGS_VERSION,
+            rpc_url: DEFAULT_RPC_URL.to_string(),
+            commitment: Commitment::default(),
+            explorer: Explorer::default(),
+            priority_fee: 0,
+            send_strategy: SendStrategy::default(),
+            currency: DisplayCurrency::default(),
+            theme: Theme::default(),
+            language: Language::default(),
+            telemetry_url: String::new(),
+        }
+    }
+}
+
+impl Settings {
+    /// Reads the saved settings. Unreadable data falls back to the defaults.
+    pub fn load() -> Self {
+        #[cfg(feature = "web")]
+        {
+            use gloo_storage::{LocalStorage, Storage};
+
+            match LocalStorage::get::<serde_json::Value>(SETTINGS_KEY) {
+                Ok(saved) => read_saved(saved),
+                Err(_) => Settings::default(),
+            }
+        }
+
+        #[cfg(not(feature = "web"))]
+        {
+            Settings::default()
+        }
+    }
+
+    pub fn save(&self) {
+        #[cfg(feature = "web")]
+        {
+            use gloo_storage::{LocalStorage, Storage};
+            if let Err(e) = LocalStorage::set(SETTINGS_KEY, self) {
+                tracing::warn!("Failed to save settings: {:?}", e);
+            }
+        }
+    }
+
+    /// The RPC endpoint, or the default if none is set.
+    pub fn rpc_url(&self) -> &str {
+        match self.rpc_url.trim() {
+            "" => DEFAULT_RPC_URL,
+            url => url,
+        }
+    }
+}
+
+/// Reads settings saved by this or an older version. A field that doesn't
+/// parse, e.g. an option a later build renamed, keeps its default while the
+/// other fields are kept. Settings saved by a newer version are ignored.
+fn read_saved(saved: serde_json::Value) -> Settings {
+    let Some(fields) = saved.as_object() else {
+        tracing::warn!("Ignoring malformed saved settings");
+        return Settings::default();
+    };
+    let version = fields.get("version").and_then(|version| version.as_u64()).unwrap_or(0);
+    if version > SETTINGS_VERSION as u64 {
+        // Saved by a newer build; leave it for that build to read.
+        tracing::warn!("Ignoring settings from newer version {}", version);
+        return Settings::default();
+    }
+
+    let mut merged = serde_json::to_value(Settings::default()).unwrap_or_default();
+    for (name, value) in fields {
+        let mut candidate = merged.clone();
+        candidate[name] = value.clone();
+        if serde_json::from_value::<Settings>(candidate.clone()).is_ok() {
+            merged = candidate;
+        } else {
+            tracing::warn!("Ignoring unreadable saved setting `{}`", name);
+        }
+    }
+    let mut settings: Settings = serde_json::from_value(merged).unwrap_or_default();
+    settings.version = SETTINGS_VERSION;
+    settings
+}
+
+pub fn use_settings_provider() {
+    use_context_provider(|| Signal::new(Settings::load()));
+}
+
+/// The app's settings. Change them through [`update_settings`] so they are
+/// saved.
+pub fn use_settings() -> Signal<Settings> {
+    use_context::<Signal<Settings>>()
+}
+
+/// The app's settings for code outside components, such as RPC helpers:
+/// read from the settings signal, without subscribing to it, or loaded from
+/// storage when called outside the app.
+pub fn current_settings() -> Settings {
+    match try_consume_context::<Signal<Settings>>() {
+        Some(settings) => settings.peek().clone(),
+        None => Settings::load(),
+    }
+}
+
+pub fn update_settings(mut signal: Signal<Settings>, update: impl FnOnce(&mut Settings)) {
+    let mut settings = signal.cloned();
+    update(&mut settings);
+    settings.save();
+    signal.set(settings);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn reads_saved_settings() {
+        let saved = Settings {
+            priority_fee: 10,
+            theme: Theme::Light,
+            explorer: Explorer::Solscan,
+            ..Settings::default()
+        };
+        assert_eq!(read_saved(serde_json::to_value(&saved).unwrap()), saved);
+    }
+
+    #[test]
+    fn missing_fields_take_their_defaults() {
+        let settings = read_saved(json!({ "version": 1, "send_strategy": "Rpc" }));
+        assert_eq!(settings.send_strategy, SendStrategy::Rpc);
+        assert_eq!(settings.rpc_url, DEFAULT_RPC_URL);
+        assert_eq!(settings.version, SETTINGS_VERSION);
+    }
+
+    #[test]
+    fn keeps_the_fields_that_still_parse() {
+        let saved = json!({
+            "version": 1,
+            "theme": "Sepia",
+            "priority_fee": -1,
+            "explorer": "Xray",
+            "language": "Spanish"
+        });
+        let settings = read_saved(saved);
+        assert_eq!(settings.theme, Theme::default());
+        assert_eq!(settings.priority_fee, 0);
+        assert_eq!(settings.explorer, Explorer::Xray);
+        assert_eq!(settings.language, Language::Spanish);
+    }
+
+    #[test]
+    fn ignores_malformed_or_newer_settings() {
+        assert_eq!(read_saved(json!("dark")), Settings::default());
+        let newer = json!({ "version": SETTINGS_VERSION + 1, "priority_fee": 10 });
+        assert_eq!(read_saved(newer), Settings::default());
+    }
+}
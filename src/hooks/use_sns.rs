@@ -0,0 +1,52 @@
+use dioxus::prelude::*;
+use solana_sdk::pubkey::Pubkey;
+
+/// Primary `.sol` name of `address`, once looked up.
+pub fn use_sns_name(address: Option<Pubkey>) -> Option<String> {
+    let name = use_resource(use_reactive!(|address| async move {
+        let address = address?;
+
+        #[cfg(feature = "web")]
+        {
+            use crate::solana::{lookup_name, rpc_client};
+
+            let client = rpc_client();
+            lookup_name(&client, &address).await.ok().flatten()
+        }
+
+        #[cfg(not(feature = "web"))]
+        {
+            let _ = address;
+            None
+        }
+    }));
+
+    name.cloned().flatten()
+}
+
+/// Owner of `input` if it is a `.sol` name. `None` while loading or when the
+/// input is not a name.
+pub fn use_sns_owner(input: String) -> Option<Result<Pubkey, String>> {
+    let owner = use_resource(use_reactive!(|input| async move {
+        use crate::solana::is_sns_name;
+
+        if !is_sns_name(&input) {
+            return None;
+        }
+
+        #[cfg(feature = "web")]
+        {
+            use crate::solana::{resolve_name, rpc_client};
+
+            let client = rpc_client();
+            Some(resolve_name(&client, &input).await)
+        }
+
+        #[cfg(not(feature = "web"))]
+        {
+            Some(Err("Name resolution is only available in web build".to_string()))
+        }
+    }));
+
+    owner.cloned().flatten()
+}
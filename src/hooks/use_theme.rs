@@ -0,0 +1,33 @@
+use dioxus::prelude::*;
+
+use crate::hooks::{use_settings, Theme};
+
+/// Provides the theme from settings and mirrors it onto the root element's
+/// `data-theme`, which selects the color tokens in `input.css`. Must run
+/// after the settings provider.
+pub fn use_theme_provider() {
+    let settings = use_settings();
+    let theme = use_memo(move || settings.read().theme);
+    use_context_provider(|| theme);
+
+    use_effect(move || {
+        let theme = theme();
+
+        #[cfg(feature = "web")]
+        {
+            if let Err(e) = gloo::utils::document_element().set_attribute("data-theme", theme.key()) {
+                tracing::warn!("Failed to apply theme: {:?}", e);
+            }
+        }
+
+        #[cfg(not(feature = "web"))]
+        {
+            let _ = theme;
+        }
+    });
+}
+
+/// The selected theme. Change it through the settings.
+pub fn use_theme() -> Memo<Theme> {
+    use_context::<Memo<Theme>>()
+}
@@ -0,0 +1,111 @@
+use dioxus::prelude::*;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ToastLevel {
+    Info,
+    Success,
+    Warning,
+    Error,
+    /// A transaction waiting for confirmation; stays until it resolves.
+    Pending,
+}
+
+impl ToastLevel {
+    /// How long the toast stays up, or `None` to keep it until dismissed.
+    pub fn timeout_ms(&self) -> Option<u64> {
+        match self {
+            ToastLevel::Info | ToastLevel::Success => Some(5_000),
+            ToastLevel::Warning | ToastLevel::Error => Some(10_000),
+            ToastLevel::Pending => None,
+        }
+    }
+}
+
+/// Link shown on a toast, e.g. to the transaction on an explorer.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ToastAction {
+    pub label: String,
+    pub href: String,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Toast {
+    pub id: u64,
+    pub level: ToastLevel,
+    pub message: String,
+    pub action: Option<ToastAction>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+struct ToastList {
+    toasts: Vec<Toast>,
+    next_id: u64,
+}
+
+/// App-wide notifications. The list lives in the root scope, so toasts
+/// survive route changes.
+#[derive(Clone, Copy, PartialEq)]
+pub struct Toasts {
+    list: Signal<ToastList>,
+}
+
+impl Toasts {
+    pub fn read(&self) -> Vec<Toast> {
+        self.list.read().toasts.clone()
+    }
+
+    /// Adds a toast and returns its id for later updates.
+    pub fn push(&self, level: ToastLevel, message: impl Into<String>, action: Option<ToastAction>) -> u64 {
+        let mut list = self.list;
+        let mut list = list.write();
+        list.next_id += 1;
+        let id = list.next_id;
+        list.toasts.push(Toast {
+            id,
+            level,
+            message: message.into(),
+            action,
+        });
+        id
+    }
+
+    pub fn info(&self, message: impl Into<String>) {
+        self.push(ToastLevel::Info, message, None);
+    }
+
+    pub fn success(&self, message: impl Into<String>) {
+        self.push(ToastLevel::Success, message, None);
+    }
+
+    pub fn warning(&self, message: impl Into<String>) {
+        self.push(ToastLevel::Warning, message, None);
+    }
+
+    pub fn error(&self, message: impl Into<String>) {
+        self.push(ToastLevel::Error, message, None);
+    }
+
+    /// Changes the level and message of toast `id`, keeping its action.
+    pub fn update(&self, id: u64, level: ToastLevel, message: impl Into<String>) {
+        let mut list = self.list;
+        if let Some(toast) = list.write().toasts.iter_mut().find(|toast| toast.id == id) {
+            toast.level = level;
+            toast.message = message.into();
+        }
+    }
+
+    pub fn dismiss(&self, id: u64) {
+        let mut list = self.list;
+        list.write().toasts.retain(|toast| toast.id != id);
+    }
+}
+
+pub fn use_toasts_provider() {
+    use_context_provider(|| Toasts {
+        list: Signal::new(ToastList::default()),
+    });
+}
+
+pub fn use_toasts() -> Toasts {
+    use_context::<Toasts>()
+}
@@ -0,0 +1,312 @@
+use dioxus::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::hooks::{use_i18n, use_settings, use_toasts, I18n, Settings, ToastAction, ToastLevel, Toasts};
+use crate::solana::{Cluster, ExplorerTarget};
+
+#[cfg(feature = "web")]
+const TX_QUEUE_KEY: &str = "tx_queue";
+
+/// How many finished transactions are kept for reference.
+const MAX_FINISHED: usize = 20;
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub enum TxStatus {
+    Built,
+    AwaitingSignature,
+    Sent,
+    Confirming,
+    Confirmed,
+    Failed { error: String },
+}
+
+impl TxStatus {
+    /// Catalog key of the status label.
+    pub fn message_key(&self) -> &'static str {
+        match self {
+            TxStatus::Built => "activity.status.built",
+            TxStatus::AwaitingSignature => "activity.status.awaiting_signature",
+            TxStatus::Sent => "activity.status.sent",
+            TxStatus::Confirming => "activity.status.confirming",
+            TxStatus::Confirmed => "activity.status.confirmed",
+            TxStatus::Failed { .. } => "activity.status.failed",
+        }
+    }
+
+    pub fn is_pending(&self) -> bool {
+        !matches!(self, TxStatus::Confirmed | TxStatus::Failed { .. })
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct QueuedTransaction {
+    pub id: u64,
+    /// What the transaction does, e.g. "Send 1 SOL to 7xKX…AsU".
+    pub label: String,
+    pub signature: Option<String>,
+    pub status: TxStatus,
+    /// Slot the transaction landed in, once confirmed.
+    #[serde(default)]
+    pub slot: Option<u64>,
+    /// When the transaction was queued, as a Unix timestamp.
+    #[serde(default)]
+    pub created_at: Option<i64>,
+}
+
+/// Current Unix timestamp, if the clock is available.
+fn now() -> Option<i64> {
+    #[cfg(feature = "web")]
+    {
+        Some((js_sys::Date::now() / 1000.0) as i64)
+    }
+
+    #[cfg(not(feature = "web"))]
+    {
+        use std::time::{SystemTime, UNIX_EPOCH};
+        SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .ok()
+            .map(|elapsed| elapsed.as_secs() as i64)
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+struct TxQueueState {
+    #[serde(default)]
+    transactions: Vec<QueuedTransaction>,
+    #[serde(default)]
+    next_id: u64,
+}
+
+impl TxQueueState {
+    fn load() -> Self {
+        #[cfg(feature = "web")]
+        {
+            use gloo_storage::{LocalStorage, Storage};
+            LocalStorage::get(TX_QUEUE_KEY).unwrap_or_default()
+        }
+
+        #[cfg(not(feature = "web"))]
+        {
+            TxQueueState::default()
+        }
+    }
+
+    fn save(&self) {
+        #[cfg(feature = "web")]
+        {
+            use gloo_storage::{LocalStorage, Storage};
+            if let Err(e) = LocalStorage::set(TX_QUEUE_KEY, self) {
+                tracing::warn!("Failed to save transaction queue: {:?}", e);
+            }
+        }
+    }
+
+    /// Drops the oldest finished transactions beyond [`MAX_FINISHED`].
+    fn prune(&mut self) {
+        let finished = self
+            .transactions
+            .iter()
+            .filter(|transaction| !transaction.status.is_pending())
+            .count();
+        let mut excess = finished.saturating_sub(MAX_FINISHED);
+        self.transactions.retain(|transaction| {
+            if excess > 0 && !transaction.status.is_pending() {
+                excess -= 1;
+                false
+            } else {
+                true
+            }
+        });
+    }
+}
+
+/// Every transaction the app is working on, from built to confirmed. Kept in
+/// the root scope and in local storage, so progress survives route changes
+/// and reloads.
+#[derive(Clone, Copy, PartialEq)]
+pub struct TxQueue {
+    state: Signal<TxQueueState>,
+    toasts: Toasts,
+    settings: Signal<Settings>,
+    i18n: I18n,
+}
+
+impl TxQueue {
+    /// Oldest first.
+    pub fn transactions(&self) -> Vec<QueuedTransaction> {
+        self.state.read().transactions.clone()
+    }
+
+    pub fn pending_count(&self) -> usize {
+        self.state
+            .read()
+            .transactions
+            .iter()
+            .filter(|transaction| transaction.status.is_pending())
+            .count()
+    }
+
+    fn update(&self, update: impl FnOnce(&mut TxQueueState)) {
+        let mut state = self.state;
+        let mut state = state.write();
+        update(&mut state);
+        state.prune();
+        state.save();
+    }
+
+    fn set_status(&self, id: u64, status: TxStatus) {
+        self.update(|state| {
+            if let Some(transaction) = state.transactions.iter_mut().find(|transaction| transaction.id == id) {
+                transaction.status = status;
+            }
+        });
+    }
+
+    /// Adds a transaction that has been built but not yet signed.
+    pub fn add(&self, label: impl Into<String>) -> u64 {
+        let mut id = 0;
+        self.update(|state| {
+            state.next_id += 1;
+            id = state.next_id;
+            state.transactions.push(QueuedTransaction {
+                id,
+                label: label.into(),
+                signature: None,
+                status: TxStatus::Built,
+                slot: None,
+                created_at: now(),
+            });
+        });
+        id
+    }
+
+    pub fn awaiting_signature(&self, id: u64) {
+        self.set_status(id, TxStatus::AwaitingSignature);
+    }
+
+    pub fn fail(&self, id: u64, error: impl Into<String>) {
+        self.set_status(id, TxStatus::Failed { error: error.into() });
+    }
+
+    pub fn clear_finished(&self) {
+        self.update(|state| state.transactions.retain(|transaction| transaction.status.is_pending()));
+    }
+
+    /// Records the signature of a submitted transaction, shows a toast for it
+    /// and polls until it confirms or fails. `on_confirmed` runs after
+    /// confirmation.
+    pub fn sent(&self, id: u64, signature: String, on_confirmed: impl FnOnce() + 'static) {
+        self.sent_and_then(id, signature, move |result| {
+            if result.is_ok() {
+                on_confirmed();
+            }
+        });
+    }
+
+    /// Like [`TxQueue::sent`], but `on_settled` runs either way, with the
+    /// slot the transaction landed in or why it didn't.
+    pub fn sent_and_then(&self, id: u64, signature: String, on_settled: impl FnOnce(Result<u64, String>) + 'static) {
+        self.update(|state| {
+            if let Some(transaction) = state.transactions.iter_mut().find(|transaction| transaction.id == id) {
+                transaction.signature = Some(signature.clone());
+                transaction.status = TxStatus::Sent;
+            }
+        });
+        let settings = self.settings.cloned();
+        let action = ToastAction {
+            label: self.i18n.t_with("activity.view_on", &[("explorer", settings.explorer.label())]),
+            href: settings.explorer.url(
+                &Cluster::from_rpc_url(settings.rpc_url()),
+                &ExplorerTarget::Transaction(signature.clone()),
+            ),
+        };
+        let toast = self
+            .toasts
+            .push(ToastLevel::Pending, self.i18n.t("activity.submitted"), Some(action));
+        self.watch(id, signature, Some(toast), false, on_settled);
+    }
+
+    /// Polls `signature` in the root scope so leaving the page doesn't
+    /// cancel it. `search_history` is for signatures from an earlier session.
+    fn watch(
+        &self,
+        id: u64,
+        signature: String,
+        toast: Option<u64>,
+        search_history: bool,
+        on_settled: impl FnOnce(Result<u64, String>) + 'static,
+    ) {
+        let queue = *self;
+        queue.set_status(id, TxStatus::Confirming);
+
+        #[cfg(feature = "web")]
+        spawn_forever(async move {
+            use crate::solana::{rpc_client, wait_for_confirmation};
+
+            let client = rpc_client();
+            match wait_for_confirmation(&client, &signature, search_history).await {
+                Ok(slot) => {
+                    queue.update(|state| {
+                        if let Some(transaction) = state.transactions.iter_mut().find(|transaction| transaction.id == id) {
+                            transaction.status = TxStatus::Confirmed;
+                            transaction.slot = Some(slot);
+                        }
+                    });
+                    if let Some(toast) = toast {
+                        queue.toasts.update(toast, ToastLevel::Success, queue.i18n.t("activity.confirmed"));
+                    }
+                    on_settled(Ok(slot));
+                }
+                Err(e) => {
+                    if let Some(toast) = toast {
+                        let message = queue.i18n.t_with("activity.not_confirmed", &[("error", &e)]);
+                        queue.toasts.update(toast, ToastLevel::Error, message);
+                    }
+                    queue.fail(id, e.clone());
+                    on_settled(Err(e));
+                }
+            }
+        });
+
+        #[cfg(not(feature = "web"))]
+        {
+            let _ = (signature, search_history, on_settled);
+            if let Some(toast) = toast {
+                queue.toasts.update(toast, ToastLevel::Info, queue.i18n.t("activity.web_only"));
+            }
+        }
+    }
+
+    /// Picks up where a previous session stopped: sent transactions are
+    /// polled again, and ones that never got sent are marked failed.
+    fn resume(&self) {
+        for transaction in self.transactions() {
+            match (&transaction.status, transaction.signature) {
+                (TxStatus::Sent | TxStatus::Confirming, Some(signature)) => {
+                    self.watch(transaction.id, signature, None, true, |_| {})
+                }
+                (status, _) if status.is_pending() => self.fail(transaction.id, self.i18n.t("activity.interrupted")),
+                _ => {}
+            }
+        }
+    }
+}
+
+/// Must run after the toast, settings and i18n providers.
+pub fn use_tx_queue_provider() {
+    let toasts = use_toasts();
+    let settings = use_settings();
+    let i18n = use_i18n();
+    let queue = use_context_provider(|| TxQueue {
+        state: Signal::new(TxQueueState::load()),
+        toasts,
+        settings,
+        i18n,
+    });
+    use_hook(|| queue.resume());
+}
+
+pub fn use_tx_queue() -> TxQueue {
+    use_context::<TxQueue>()
+}
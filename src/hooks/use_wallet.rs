@@ -39,6 +39,7 @@ pub fn use_wallet_provider() {
                 loop {
                     if let Ok(result) = eval.recv::<String>().await {
                         if result == "null" {
+                            tracing::info!("Wallet disconnected");
                             wallet.set(Wallet::Disconnected);
                         } else {
                             if let Ok(pubkey_bytes) = serde_json::from_str::<Vec<u8>>(&result) {
@@ -46,6 +47,7 @@ pub fn use_wallet_provider() {
                                     let mut array = [0u8; 32];
                                     array.copy_from_slice(&pubkey_bytes);
                                     let pubkey = Pubkey::from(array);
+                                    tracing::info!("Wallet connected");
                                     wallet.set(Wallet::Connected(pubkey));
                                 }
                             }
@@ -63,6 +65,7 @@ pub fn use_wallet() -> Signal<Wallet> {
 
 #[cfg(feature = "web")]
 pub async fn disconnect_wallet() {
+    tracing::info!("Wallet disconnect requested");
     let eval = dioxus::document::eval(
         r#"
         if (window.OreWalletDisconnecter) {
@@ -73,8 +76,10 @@ pub async fn disconnect_wallet() {
     let _ = eval.await;
 }
 
+/// Has the wallet sign a base64-encoded transaction without submitting it.
+/// Returns the signed transaction, base64-encoded.
 #[cfg(feature = "web")]
-pub async fn sign_and_send_transaction(transaction_base64: String) -> Result<String, String> {
+pub async fn sign_transaction(transaction_base64: String) -> Result<String, String> {
     use dioxus::document::eval;
     
     let script = format!(
@@ -109,3 +114,134 @@ pub async fn sign_and_send_transaction(transaction_base64: String) -> Result<Str
         Err(e) => Err(format!("Eval error: {:?}", e)),
     }
 }
+
+/// Signs and submits a base64-encoded transaction according to the
+/// [`SendStrategy`](super::SendStrategy) in settings. With [`SendStrategy::Wallet`] the wallet's native
+/// `signAndSendTransaction` is used when it has one; otherwise the wallet only
+/// signs and the transaction is submitted through `client`. Returns the
+/// transaction signature.
+#[cfg(feature = "web")]
+pub async fn sign_and_send_transaction(
+    client: &solana_client_wasm::WasmClient,
+    transaction_base64: String,
+) -> Result<String, String> {
+    use super::{current_settings, SendStrategy};
+    use crate::solana::rpc_call;
+    use solana_sdk::transaction::Transaction;
+
+    let settings = current_settings();
+    if settings.send_strategy == SendStrategy::Wallet {
+        let sent = send_with_native_wallet(&transaction_base64, &settings)
+            .await
+            .map_err(|e| format!("Failed to send transaction: {}", e))?;
+        if let Some(signature) = sent {
+            tracing::info!(submitted_by = "wallet", "Transaction submitted");
+            return Ok(signature);
+        }
+    }
+
+    let signed_tx_base64 = sign_transaction(transaction_base64)
+        .await
+        .map_err(|e| format!("Failed to sign transaction: {}", e))?;
+    let signed_tx_bytes = base64::decode(&signed_tx_base64)
+        .map_err(|e| format!("Failed to decode signed transaction: {}", e))?;
+    let signed_transaction: Transaction = bincode::deserialize(&signed_tx_bytes)
+        .map_err(|e| format!("Failed to deserialize signed transaction: {}", e))?;
+    rpc_call("sendTransaction", client.send_transaction(&signed_transaction))
+        .await
+        .map(|signature| signature.to_string())
+        .map_err(|e| format!("Failed to send transaction: {:?}", e))
+}
+
+/// Asks the wallet to sign and submit the transaction itself, through the
+/// RPC endpoint and commitment in `settings`. Returns `Ok(None)` if the
+/// wallet has no native send.
+#[cfg(feature = "web")]
+async fn send_with_native_wallet(
+    transaction_base64: &str,
+    settings: &super::Settings,
+) -> Result<Option<String>, String> {
+    use dioxus::document::eval;
+
+    let script = format!(
+        r#"
+        try {{
+            if (!window.OreTxSender) {{
+                return "UNSUPPORTED";
+            }}
+            const signature = await window.OreTxSender({{ b64: "{}", rpc: {}, commitment: "{}" }});
+            return signature;
+        }} catch (err) {{
+            return "ERROR: " + err.message;
+        }}
+        "#,
+        transaction_base64,
+        serde_json::to_string(settings.rpc_url()).map_err(|e| e.to_string())?,
+        settings.commitment.key()
+    );
+
+    let eval = eval(&script);
+
+    match eval.await {
+        Ok(result) => {
+            if let Ok(result_str) = result.downcast::<String>() {
+                if result_str == "UNSUPPORTED" {
+                    Ok(None)
+                } else if result_str.starts_with("ERROR:") {
+                    Err(result_str)
+                } else {
+                    Ok(Some(result_str))
+                }
+            } else {
+                Err("Failed to parse result".to_string())
+            }
+        }
+        Err(e) => Err(format!("Eval error: {:?}", e)),
+    }
+}
+
+/// Signs several base64-encoded transactions with a single wallet prompt.
+/// Signed transactions are returned in the same order.
+#[cfg(feature = "web")]
+pub async fn sign_all_transactions(transactions_base64: Vec<String>) -> Result<Vec<String>, String> {
+    use dioxus::document::eval;
+
+    let payload = serde_json::to_string(&transactions_base64)
+        .map_err(|e| format!("Failed to encode transactions: {}", e))?;
+    let script = format!(
+        r#"
+        try {{
+            if (!window.OreTxsSigner) {{
+                return "ERROR: Wallet not connected";
+            }}
+            const signed = await window.OreTxsSigner({{ b64s: {} }});
+            return JSON.stringify(signed);
+        }} catch (err) {{
+            return "ERROR: " + err.message;
+        }}
+        "#,
+        payload
+    );
+
+    let eval = eval(&script);
+
+    match eval.await {
+        Ok(result) => {
+            if let Ok(result_str) = result.downcast::<String>() {
+                if result_str.starts_with("ERROR:") {
+                    Err(result_str)
+                } else {
+                    let signed = serde_json::from_str::<Vec<String>>(&result_str)
+                        .map_err(|_| "Failed to parse result".to_string())?;
+                    if signed.len() != transactions_base64.len() {
+                        return Err("Wallet returned the wrong number of transactions".to_string());
+                    }
+                    Ok(signed)
+                }
+            } else {
+                Err("Failed to parse result".to_string())
+            }
+        }
+        Err(e) => Err(format!("Eval error: {:?}", e)),
+    }
+}
@@ -0,0 +1,48 @@
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::sync::OnceLock;
+
+use crate::hooks::Language;
+
+/// A catalog entry: plain text, or one form per plural category (see
+/// [`plural_category`](super::plural_category)). Text may contain `{name}`
+/// placeholders.
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+pub enum Message {
+    Text(String),
+    Plural(HashMap<String, String>),
+}
+
+type Catalog = HashMap<String, Message>;
+
+/// Catalog source, one JSON file per language under `locales/`.
+fn source(language: Language) -> &'static str {
+    match language {
+        Language::English => include_str!("../../locales/en.json"),
+        Language::Spanish => include_str!("../../locales/es.json"),
+    }
+}
+
+fn catalog(language: Language) -> &'static Catalog {
+    static ENGLISH: OnceLock<Catalog> = OnceLock::new();
+    static SPANISH: OnceLock<Catalog> = OnceLock::new();
+
+    let cell = match language {
+        Language::English => &ENGLISH,
+        Language::Spanish => &SPANISH,
+    };
+    cell.get_or_init(|| {
+        serde_json::from_str(source(language)).unwrap_or_else(|e| {
+            tracing::error!("Failed to parse {} catalog: {}", language.key(), e);
+            HashMap::new()
+        })
+    })
+}
+
+/// `key` in `language`'s catalog, falling back to English for keys it lacks.
+pub fn lookup(language: Language, key: &str) -> Option<&'static Message> {
+    catalog(language)
+        .get(key)
+        .or_else(|| catalog(Language::English).get(key))
+}
@@ -0,0 +1,127 @@
+use crate::hooks::Language;
+
+/// CLDR plural category of `count` in `language`. English and Spanish only
+/// distinguish one from everything else.
+pub fn plural_category(language: Language, count: u64) -> &'static str {
+    match language {
+        Language::English | Language::Spanish => {
+            if count == 1 {
+                "one"
+            } else {
+                "other"
+            }
+        }
+    }
+}
+
+/// Group and decimal separators, and the fewest integer digits that get
+/// grouped (Spanish leaves four-digit numbers like 1234 ungrouped).
+fn separators(language: Language) -> (char, char, usize) {
+    match language {
+        Language::English => (',', '.', 4),
+        Language::Spanish => ('.', ',', 5),
+    }
+}
+
+/// Formats a decimal string such as `"1234.5"` with `language`'s separators,
+/// keeping every digit. Anything that isn't a plain decimal is returned as is.
+pub fn format_decimal(language: Language, amount: &str) -> String {
+    let amount = amount.trim();
+    let (sign, unsigned) = match amount.strip_prefix('-') {
+        Some(rest) => ("-", rest),
+        None => ("", amount),
+    };
+    let (whole, fraction) = unsigned.split_once('.').unwrap_or((unsigned, ""));
+    if whole.is_empty() || !whole.chars().all(|c| c.is_ascii_digit()) || !fraction.chars().all(|c| c.is_ascii_digit())
+    {
+        return amount.to_string();
+    }
+
+    let (group, decimal, min_grouped) = separators(language);
+    let mut formatted = String::from(sign);
+    for (index, digit) in whole.chars().enumerate() {
+        let remaining = whole.len() - index;
+        if index > 0 && remaining % 3 == 0 && whole.len() >= min_grouped {
+            formatted.push(group);
+        }
+        formatted.push(digit);
+    }
+    if !fraction.is_empty() {
+        formatted.push(decimal);
+        formatted.push_str(fraction);
+    }
+    formatted
+}
+
+/// Formats `value` rounded to `decimals` places.
+pub fn format_number(language: Language, value: f64, decimals: usize) -> String {
+    format_decimal(language, &format!("{:.*}", decimals, value))
+}
+
+/// `value` as a price with two decimals, with `symbol` placed the way
+/// `language` does: `$12.34` in English, `12,34 $` in Spanish.
+pub fn format_currency(language: Language, value: f64, symbol: &str) -> String {
+    let number = format_number(language, value, 2);
+    match language {
+        Language::English => format!("{}{}", symbol, number),
+        Language::Spanish => format!("{} {}", number, symbol),
+    }
+}
+
+/// Date and time of a Unix timestamp, in the browser's time zone and
+/// `language`'s conventions. Outside the browser it falls back to UTC in
+/// ISO 8601.
+pub fn format_date(language: Language, unix_seconds: i64) -> String {
+    #[cfg(feature = "web")]
+    {
+        let date = js_sys::Date::new(&wasm_bindgen::JsValue::from_f64(unix_seconds as f64 * 1000.0));
+        date.to_locale_string(language.key(), &wasm_bindgen::JsValue::UNDEFINED).into()
+    }
+
+    #[cfg(not(feature = "web"))]
+    {
+        let _ = language;
+        let days = unix_seconds.div_euclid(86_400);
+        let seconds = unix_seconds.rem_euclid(86_400);
+        // Civil date from days since the epoch (Howard Hinnant's algorithm).
+        let z = days + 719_468;
+        let era = z.div_euclid(146_097);
+        let day_of_era = z.rem_euclid(146_097);
+        let year_of_era = (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+        let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+        let month_index = (5 * day_of_year + 2) / 153;
+        let day = day_of_year - (153 * month_index + 2) / 5 + 1;
+        let month = if month_index < 10 { month_index + 3 } else { month_index - 9 };
+        let year = year_of_era + era * 400 + if month <= 2 { 1 } else { 0 };
+        format!("{:04}-{:02}-{:02} {:02}:{:02} UTC", year, month, day, seconds / 3600, seconds % 3600 / 60)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn groups_english_thousands() {
+        assert_eq!(format_decimal(Language::English, "0.5"), "0.5");
+        assert_eq!(format_decimal(Language::English, "999"), "999");
+        assert_eq!(format_decimal(Language::English, "1234"), "1,234");
+        assert_eq!(format_decimal(Language::English, "1234567.000000001"), "1,234,567.000000001");
+        assert_eq!(format_decimal(Language::English, "-12345.67"), "-12,345.67");
+    }
+
+    #[test]
+    fn spanish_leaves_four_digits_ungrouped() {
+        assert_eq!(format_decimal(Language::Spanish, "1234.5"), "1234,5");
+        assert_eq!(format_decimal(Language::Spanish, "12345.5"), "12.345,5");
+        assert_eq!(format_decimal(Language::Spanish, "1000000"), "1.000.000");
+    }
+
+    #[test]
+    fn leaves_anything_else_alone() {
+        assert_eq!(format_decimal(Language::English, ""), "");
+        assert_eq!(format_decimal(Language::English, ".5"), ".5");
+        assert_eq!(format_decimal(Language::English, "1e9"), "1e9");
+        assert_eq!(format_decimal(Language::English, " 1,000 "), "1,000");
+    }
+}
@@ -0,0 +1,5 @@
+mod catalog;
+mod format;
+
+pub use catalog::*;
+pub use format::*;
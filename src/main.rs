@@ -1,35 +1,55 @@
 #![allow(non_snake_case)]
 
+mod anchor;
 mod components;
+mod diagnostics;
+mod form;
 mod hooks;
+mod i18n;
 mod pages;
 mod route;
+mod solana;
+mod solana_pay;
+mod squads;
+mod telemetry;
 
 use dioxus::prelude::*;
 use tracing::Level;
 
 use crate::{
-    hooks::{use_wallet_provider},
+    components::ErrorScreen,
+    hooks::{
+        use_address_book_provider, use_i18n_provider, use_settings_provider, use_theme_provider, use_toasts_provider,
+        use_tx_queue_provider, use_wallet_provider,
+    },
     route::Route,
 };
 
 const CSS: &str = include_str!("../public/tailwind.css");
 
 fn main() {
-    #[cfg(feature = "web")]
-    wasm_logger::init(wasm_logger::Config::default());
-    
-    dioxus_logger::init(Level::INFO).expect("failed to init logger");
+    telemetry::init(Level::INFO).expect("failed to init logger");
     dioxus::launch(App)
 }
 
 pub fn App() -> Element {
     use_wallet_provider();
+    use_address_book_provider();
+    use_settings_provider();
+    use_theme_provider();
+    use_i18n_provider();
+    use_toasts_provider();
+    use_tx_queue_provider();
+    // After launch, so the renderer's console hook still runs first.
+    use_hook(diagnostics::install_panic_hook);
 
     rsx! {
         style { "{CSS}" }
         document::Link { rel: "icon", href: asset!("/public/favicon.png") }
         document::Script { src: asset!("/public/wallet.js") }
-        Router::<Route> {}
+        ErrorBoundary {
+            handle_error: |errors: ErrorContext| rsx! { ErrorScreen { errors } },
+            Router::<Route> {}
+        }
     }
 }
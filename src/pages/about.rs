@@ -1,7 +1,10 @@
 use dioxus::prelude::*;
+use crate::hooks::use_i18n;
 
 #[component]
 pub fn About() -> Element {
+    let i18n = use_i18n();
+
     rsx! {
         div {
             class: "max-w-4xl mx-auto",
@@ -10,70 +13,70 @@ pub fn About() -> Element {
             div {
                 class: "text-center mb-12",
                 h1 {
-                    class: "text-5xl font-bold text-white mb-4",
-                    "About"
+                    class: "text-5xl font-bold text-fg mb-4",
+                    {i18n.t("about.title")}
                 }
             }
 
             // Content card
             div {
-                class: "bg-gray-800/50 backdrop-blur-md rounded-2xl p-8 border border-gray-700 shadow-2xl",
+                class: "bg-surface/50 backdrop-blur-md rounded-2xl p-8 border border-line shadow-2xl",
                 
                 div {
                     class: "prose prose-invert max-w-none",
                     
                     p {
-                        class: "text-gray-300 text-lg leading-relaxed mb-6",
-                        "Lorem ipsum dolor sit amet, consectetur adipiscing elit. Sed do eiusmod tempor incididunt ut labore et dolore magna aliqua. Ut enim ad minim veniam, quis nostrud exercitation ullamco laboris nisi ut aliquip ex ea commodo consequat."
+                        class: "text-fg-muted text-lg leading-relaxed mb-6",
+                        {i18n.t("about.intro_1")}
                     }
 
                     p {
-                        class: "text-gray-300 text-lg leading-relaxed mb-6",
-                        "Duis aute irure dolor in reprehenderit in voluptate velit esse cillum dolore eu fugiat nulla pariatur. Excepteur sint occaecat cupidatat non proident, sunt in culpa qui officia deserunt mollit anim id est laborum."
+                        class: "text-fg-muted text-lg leading-relaxed mb-6",
+                        {i18n.t("about.intro_2")}
                     }
 
                     h2 {
-                        class: "text-2xl font-bold text-white mt-8 mb-4",
-                        "Features"
+                        class: "text-2xl font-bold text-fg mt-8 mb-4",
+                        {i18n.t("about.features")}
                     }
 
                     ul {
-                        class: "text-gray-300 space-y-3 mb-6",
+                        class: "text-fg-muted space-y-3 mb-6",
                         li {
                             class: "flex items-start",
                             span { class: "text-purple-400 mr-2", "•" }
-                            span { "Built with Rust and Dioxus for optimal performance" }
+                            span { {i18n.t("about.feature_rust")} }
                         }
                         li {
                             class: "flex items-start",
                             span { class: "text-purple-400 mr-2", "•" }
-                            span { "Integrated Solana wallet adapter for seamless blockchain interactions" }
+                            span { {i18n.t("about.feature_wallet")} }
                         }
                         li {
                             class: "flex items-start",
                             span { class: "text-purple-400 mr-2", "•" }
-                            span { "Simple and intuitive interface for SOL transfers" }
+                            span { {i18n.t("about.feature_transfers")} }
                         }
                         li {
                             class: "flex items-start",
                             span { class: "text-purple-400 mr-2", "•" }
-                            span { "Secure transaction signing with wallet confirmation" }
+                            span { {i18n.t("about.feature_signing")} }
                         }
                     }
 
                     h2 {
-                        class: "text-2xl font-bold text-white mt-8 mb-4",
-                        "Technology Stack"
+                        class: "text-2xl font-bold text-fg mt-8 mb-4",
+                        {i18n.t("about.stack")}
                     }
 
                     p {
-                        class: "text-gray-300 text-lg leading-relaxed mb-6",
-                        "Sed ut perspiciatis unde omnis iste natus error sit voluptatem accusantium doloremque laudantium, totam rem aperiam, eaque ipsa quae ab illo inventore veritatis et quasi architecto beatae vitae dicta sunt explicabo."
+                        class: "text-fg-muted text-lg leading-relaxed mb-6",
+                        {i18n.t("about.stack_1")}
                     }
 
                     p {
-                        class: "text-gray-300 text-lg leading-relaxed",
-                        "Nemo enim ipsam voluptatem quia voluptas sit aspernatur aut odit aut fugit, sed quia consequuntur magni dolores eos qui ratione voluptatem sequi nesciunt. Neque porro quisquam est, qui dolorem ipsum quia dolor sit amet, consectetur, adipisci velit."
+                        class: "text-fg-muted text-lg leading-relaxed",
+                        {i18n.t("about.stack_2")}
                     }
                 }
             }
@@ -82,8 +85,8 @@ pub fn About() -> Element {
             div {
                 class: "mt-8 bg-gradient-to-r from-purple-900/30 to-pink-900/30 rounded-xl p-6 border border-purple-500/50",
                 p {
-                    class: "text-center text-gray-300 italic",
-                    "\"Lorem ipsum dolor sit amet, consectetur adipiscing elit, sed do eiusmod tempor incididunt ut labore et dolore magna aliqua.\""
+                    class: "text-center text-fg-muted italic",
+                    {i18n.t("about.quote")}
                 }
             }
         }
@@ -0,0 +1,300 @@
+use dioxus::prelude::*;
+use crate::hooks::{parse_tags, update_address_book, use_address_book, use_sns_name, use_toasts, AddressBookEntry};
+use solana_sdk::pubkey::Pubkey;
+use std::str::FromStr;
+
+const INPUT_CLASS: &str = "w-full bg-surface-sunken/50 border border-line-strong rounded-lg px-4 py-3 text-fg placeholder-fg-faint focus:outline-none focus:border-purple-500 focus:ring-2 focus:ring-purple-500/50 transition-all";
+
+/// Address book: saved addresses with labels, notes and tags, the recent
+/// recipients of confirmed transfers, and JSON import/export.
+#[component]
+pub fn Addresses() -> Element {
+    let book = use_address_book();
+    let mut address = use_signal(|| String::new());
+    let mut label = use_signal(|| String::new());
+    let mut note = use_signal(|| String::new());
+    let mut tags = use_signal(|| String::new());
+    let mut search = use_signal(|| String::new());
+    let toasts = use_toasts();
+
+    let handle_save = move |_| {
+        let entry = AddressBookEntry {
+            address: address.cloned(),
+            label: label.cloned(),
+            note: note.cloned(),
+            tags: parse_tags(&tags.cloned()),
+        };
+        let mut result = Ok(());
+        update_address_book(book, |book| result = book.upsert(entry));
+        match result {
+            Ok(()) => {
+                toasts.success(format!("Saved {}", label.cloned().trim()));
+                address.set(String::new());
+                label.set(String::new());
+                note.set(String::new());
+                tags.set(String::new());
+            }
+            Err(e) => toasts.error(e),
+        }
+    };
+
+    let handle_import = move |evt: FormEvent| async move {
+        let Some(files) = evt.files() else {
+            return;
+        };
+        let Some(name) = files.files().into_iter().next() else {
+            return;
+        };
+        let Some(text) = files.read_file_to_string(&name).await else {
+            toasts.error(format!("Could not read {}", name));
+            return;
+        };
+
+        let mut result = Ok(0);
+        update_address_book(book, |book| {
+            // Only keep the merge if every entry is valid.
+            let mut merged = book.clone();
+            result = merged.import_json(&text);
+            if result.is_ok() {
+                *book = merged;
+            }
+        });
+        match result {
+            Ok(count) => toasts.success(format!("Imported {} addresses from {}", count, name)),
+            Err(e) => toasts.error(e),
+        }
+    };
+
+    let query = search.cloned();
+    let entries: Vec<AddressBookEntry> = book
+        .read()
+        .entries
+        .iter()
+        .filter(|entry| entry.matches(&query))
+        .cloned()
+        .collect();
+    let recent: Vec<String> = book.read().recent.clone();
+    let export_href = format!(
+        "data:application/json;charset=utf-8,{}",
+        crate::solana_pay::encode_component(&book.read().export_json())
+    );
+
+    rsx! {
+        div {
+            class: "max-w-4xl mx-auto",
+
+            // Header
+            div {
+                class: "text-center mb-12",
+                h1 {
+                    class: "text-5xl font-bold text-fg mb-4",
+                    "Address Book"
+                }
+                p {
+                    class: "text-fg-muted text-lg",
+                    "Save addresses you pay often and share the list with your team"
+                }
+            }
+
+            // Add or edit
+            div {
+                class: "bg-surface/50 backdrop-blur-md rounded-2xl p-8 border border-line shadow-2xl space-y-6",
+                div {
+                    label { class: "block text-fg-muted font-medium mb-2", r#for: "address-book-address", "Address" }
+                    input {
+                        id: "address-book-address",
+                        class: INPUT_CLASS,
+                        r#type: "text",
+                        placeholder: "Enter Solana address (e.g., 7xKX...)",
+                        value: "{address}",
+                        oninput: move |evt| address.set(evt.value())
+                    }
+                }
+                div {
+                    label { class: "block text-fg-muted font-medium mb-2", r#for: "address-book-label", "Label" }
+                    input {
+                        id: "address-book-label",
+                        class: INPUT_CLASS,
+                        r#type: "text",
+                        placeholder: "e.g. Treasury",
+                        value: "{label}",
+                        oninput: move |evt| label.set(evt.value())
+                    }
+                }
+                div {
+                    label { class: "block text-fg-muted font-medium mb-2", r#for: "address-book-note", "Note (optional)" }
+                    input {
+                        id: "address-book-note",
+                        class: INPUT_CLASS,
+                        r#type: "text",
+                        placeholder: "Anything worth remembering about this address",
+                        value: "{note}",
+                        oninput: move |evt| note.set(evt.value())
+                    }
+                }
+                div {
+                    label { class: "block text-fg-muted font-medium mb-2", r#for: "address-book-tags", "Tags (comma separated)" }
+                    input {
+                        id: "address-book-tags",
+                        class: INPUT_CLASS,
+                        r#type: "text",
+                        placeholder: "e.g. payroll, vendor",
+                        value: "{tags}",
+                        oninput: move |evt| tags.set(evt.value())
+                    }
+                }
+                button {
+                    class: "w-full bg-purple-600 hover:bg-purple-700 disabled:bg-surface-strong disabled:text-fg-subtle disabled:cursor-not-allowed text-white font-bold py-4 rounded-lg transition-all duration-200 transform hover:scale-105 disabled:hover:scale-100",
+                    disabled: address.read().trim().is_empty() || label.read().trim().is_empty(),
+                    onclick: handle_save,
+                    "Save Address"
+                }
+            }
+
+            // Saved addresses
+            div {
+                class: "mt-8 bg-surface/50 backdrop-blur-md rounded-2xl p-6 border border-line shadow-2xl space-y-4",
+                div {
+                    class: "flex items-center justify-between",
+                    h3 { class: "text-fg font-semibold text-lg", "Saved Addresses" }
+                    div {
+                        class: "flex items-center space-x-4",
+                        label {
+                            class: "text-purple-400 hover:text-purple-300 text-sm font-medium cursor-pointer",
+                            "Import JSON"
+                            input {
+                                class: "hidden",
+                                r#type: "file",
+                                accept: ".json,application/json",
+                                onchange: handle_import
+                            }
+                        }
+                        a {
+                            class: "text-purple-400 hover:text-purple-300 text-sm font-medium",
+                            href: "{export_href}",
+                            download: "address-book.json",
+                            "Export JSON"
+                        }
+                    }
+                }
+                input {
+                    class: INPUT_CLASS,
+                    r#type: "search",
+                    aria_label: "Search saved addresses",
+                    placeholder: "Search by label, address, note or tag",
+                    value: "{search}",
+                    oninput: move |evt| search.set(evt.value())
+                }
+                if entries.is_empty() {
+                    p { class: "text-fg-subtle text-sm", "No saved addresses" }
+                }
+                for entry in entries.into_iter() {
+                    EntryRow {
+                        key: "{entry.address}",
+                        entry: entry.clone(),
+                        onedit: move |entry: AddressBookEntry| {
+                            address.set(entry.address.clone());
+                            label.set(entry.label.clone());
+                            note.set(entry.note.clone());
+                            tags.set(entry.tags.join(", "));
+                        },
+                    }
+                }
+            }
+
+            // Recent recipients
+            if !recent.is_empty() {
+                div {
+                    class: "mt-8 bg-surface/50 backdrop-blur-md rounded-2xl p-6 border border-line shadow-2xl space-y-3",
+                    h3 { class: "text-fg font-semibold text-lg", "Recent Recipients" }
+                    for recent_address in recent.into_iter() {
+                        RecentRow {
+                            key: "{recent_address}",
+                            address: recent_address.clone(),
+                            onsave: move |recent_address: String| address.set(recent_address),
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// A recent recipient with its saved label or `.sol` name.
+#[component]
+fn RecentRow(address: String, onsave: EventHandler<String>) -> Element {
+    let book = use_address_book();
+    let name = use_sns_name(Pubkey::from_str(&address).ok());
+    let entry = book.read().find(&address).cloned();
+
+    rsx! {
+        div {
+            class: "flex items-center justify-between",
+            div {
+                span { class: "font-mono text-fg-muted text-sm break-all", "{address}" }
+                if let Some(name) = name {
+                    span { class: "text-fg-subtle text-sm ml-2", "({name})" }
+                }
+            }
+            if let Some(entry) = entry {
+                span { class: "text-purple-300 text-sm ml-4", "{entry.label}" }
+            } else {
+                button {
+                    class: "text-purple-400 hover:text-purple-300 text-sm font-medium ml-4",
+                    onclick: move |_| onsave.call(address.clone()),
+                    "Save"
+                }
+            }
+        }
+    }
+}
+
+#[component]
+fn EntryRow(entry: AddressBookEntry, onedit: EventHandler<AddressBookEntry>) -> Element {
+    let book = use_address_book();
+
+    rsx! {
+        div {
+            class: "p-4 rounded-lg bg-surface-sunken/50 border border-line",
+            div {
+                class: "flex items-center justify-between",
+                p { class: "text-fg font-semibold", "{entry.label}" }
+                div {
+                    class: "flex items-center space-x-4",
+                    button {
+                        class: "text-purple-400 hover:text-purple-300 text-sm font-medium",
+                        onclick: {
+                            let entry = entry.clone();
+                            move |_| onedit.call(entry.clone())
+                        },
+                        "Edit"
+                    }
+                    button {
+                        class: "text-danger-fg hover:text-danger text-sm font-medium",
+                        onclick: {
+                            let address = entry.address.clone();
+                            move |_| update_address_book(book, |book| book.remove(&address))
+                        },
+                        "Remove"
+                    }
+                }
+            }
+            p { class: "font-mono text-fg-subtle text-sm break-all", "{entry.address}" }
+            if !entry.note.is_empty() {
+                p { class: "text-fg-muted text-sm mt-1", "{entry.note}" }
+            }
+            if !entry.tags.is_empty() {
+                div {
+                    class: "mt-2 flex flex-wrap gap-2",
+                    for tag in entry.tags.iter() {
+                        span {
+                            key: "{tag}",
+                            class: "bg-purple-900/40 border border-purple-700 text-purple-300 text-xs px-2 py-0.5 rounded-full",
+                            "{tag}"
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,694 @@
+use dioxus::prelude::*;
+use crate::anchor::{build_instruction, parse_input, DecodedAccount, Idl, IdlInstruction, IdlType};
+use crate::components::{shorten_address, ValueTree};
+use crate::hooks::{use_toasts, use_tx_queue, use_wallet, Wallet};
+use solana_sdk::pubkey::Pubkey;
+use std::str::FromStr;
+
+const INPUT_CLASS: &str = "w-full bg-surface-sunken/50 border border-line-strong rounded-lg px-4 py-3 text-fg placeholder-fg-faint focus:outline-none focus:border-purple-500 focus:ring-2 focus:ring-purple-500/50 transition-all";
+
+#[component]
+pub fn Anchor() -> Element {
+    let wallet = use_wallet();
+    let tx_queue = use_tx_queue();
+    let mut program_input = use_signal(|| String::new());
+    let mut idl = use_signal(|| None::<Idl>);
+    let mut selected = use_signal(|| None::<String>);
+    let mut account_inputs = use_signal(Vec::<String>::new);
+    let mut arg_inputs = use_signal(Vec::<String>::new);
+    let toasts = use_toasts();
+    let mut is_processing = use_signal(|| false);
+
+    // Reset the form whenever a different instruction is picked.
+    let mut select_instruction = move |name: String| {
+        let Some(ix) = idl.read().as_ref().and_then(|idl| idl.instruction(&name).cloned()) else {
+            return;
+        };
+        let wallet_pubkey = match wallet.cloned() {
+            Wallet::Connected(pubkey) => Some(pubkey.to_string()),
+            Wallet::Disconnected => None,
+        };
+        account_inputs.set(
+            ix.flat_accounts()
+                .into_iter()
+                .map(|(_, acc)| match (&acc.address, acc.signer) {
+                    (Some(address), _) => address.clone(),
+                    (None, true) => wallet_pubkey.clone().unwrap_or_default(),
+                    (None, false) => String::new(),
+                })
+                .collect(),
+        );
+        arg_inputs.set(
+            ix.args
+                .iter()
+                .map(|arg| if arg.ty == IdlType::Bool { "false".to_string() } else { String::new() })
+                .collect(),
+        );
+        selected.set(Some(name));
+    };
+
+    let mut load_idl = move |loaded: Idl| {
+        if program_input.read().is_empty() {
+            if let Some(program_id) = loaded.program_id() {
+                program_input.set(program_id.to_string());
+            }
+        }
+        let first = loaded.instructions.first().map(|ix| ix.name.clone());
+        toasts.info(format!(
+            "Loaded IDL `{}` with {} instructions",
+            loaded.display_name(),
+            loaded.instructions.len()
+        ));
+        idl.set(Some(loaded));
+        selected.set(None);
+        if let Some(name) = first {
+            select_instruction(name);
+        }
+    };
+
+    let handle_fetch = move |_| {
+        let program_str = program_input.cloned();
+
+        spawn(async move {
+            let program_id = match Pubkey::from_str(program_str.trim()) {
+                Ok(pubkey) => pubkey,
+                Err(_) => {
+                    toasts.error("Invalid program id");
+                    return;
+                }
+            };
+
+            #[cfg(feature = "web")]
+            {
+                use crate::anchor::fetch_idl;
+                use crate::solana::rpc_client;
+
+                is_processing.set(true);
+                let client = rpc_client();
+                match fetch_idl(&client, &program_id).await {
+                    Ok(loaded) => load_idl(loaded),
+                    Err(e) => toasts.error(e),
+                }
+                is_processing.set(false);
+            }
+
+            #[cfg(not(feature = "web"))]
+            {
+                let _ = program_id;
+                toasts.info("Fetching IDLs is only available in web build");
+            }
+        });
+    };
+
+    let handle_upload = move |evt: FormEvent| async move {
+        let Some(files) = evt.files() else {
+            return;
+        };
+        for name in files.files() {
+            match files.read_file_to_string(&name).await {
+                Some(json) => match Idl::from_json(&json) {
+                    Ok(loaded) => load_idl(loaded),
+                    Err(e) => toasts.error(e),
+                },
+                None => toasts.error(format!("Could not read {}", name)),
+            }
+        }
+    };
+
+    let handle_send = move |_| {
+        let wallet_clone = wallet.cloned();
+        let program_str = program_input.cloned();
+        let accounts_raw = account_inputs.cloned();
+        let args_raw = arg_inputs.cloned();
+        let idl_clone = idl.cloned();
+        let ix_name = selected.cloned();
+
+        spawn(async move {
+            is_processing.set(true);
+            let result = prepare_instruction(
+                &wallet_clone,
+                &program_str,
+                idl_clone.as_ref(),
+                ix_name.as_deref(),
+                &accounts_raw,
+                &args_raw,
+            );
+
+            let (sender_pubkey, instruction) = match result {
+                Ok(prepared) => prepared,
+                Err(e) => {
+                    toasts.error(e);
+                    is_processing.set(false);
+                    return;
+                }
+            };
+
+            #[cfg(feature = "web")]
+            {
+                use crate::solana::{rpc_client, send_with_wallet};
+
+                let client = rpc_client();
+                let tx_id = tx_queue.add(format!(
+                    "Call {} on {}",
+                    ix_name.as_deref().unwrap_or("instruction"),
+                    shorten_address(&instruction.program_id.to_string())
+                ));
+                tx_queue.awaiting_signature(tx_id);
+                match send_with_wallet(&client, &sender_pubkey, &[instruction]).await {
+                    Ok(signature) => tx_queue.sent(tx_id, signature, || {}),
+                    Err(e) => {
+                        tx_queue.fail(tx_id, e.clone());
+                        toasts.error(e);
+                    }
+                }
+            }
+
+            #[cfg(not(feature = "web"))]
+            {
+                let _ = (sender_pubkey, instruction, tx_queue);
+                toasts.info("Sending instructions is only available in web build");
+            }
+
+            is_processing.set(false);
+        });
+    };
+
+    let connected = matches!(wallet.cloned(), Wallet::Connected(_));
+    let current_ix: Option<IdlInstruction> = selected
+        .read()
+        .as_ref()
+        .and_then(|name| idl.read().as_ref().and_then(|idl| idl.instruction(name).cloned()));
+
+    rsx! {
+        div {
+            class: "max-w-3xl mx-auto",
+
+            // Header
+            div {
+                class: "text-center mb-12",
+                h1 {
+                    class: "text-5xl font-bold text-fg mb-4",
+                    "Anchor Programs"
+                }
+                p {
+                    class: "text-fg-muted text-lg",
+                    "Load an IDL and call any instruction of an Anchor program"
+                }
+            }
+
+            // IDL source
+            div {
+                class: "bg-surface/50 backdrop-blur-md rounded-2xl p-8 border border-line shadow-2xl space-y-6",
+                div {
+                    label {
+                        class: "block text-fg-muted font-medium mb-2",
+                        r#for: "anchor-program",
+                        "Program ID"
+                    }
+                    div {
+                        class: "flex space-x-2",
+                        input {
+                            id: "anchor-program",
+                            class: INPUT_CLASS,
+                            r#type: "text",
+                            placeholder: "Program address",
+                            value: "{program_input}",
+                            disabled: is_processing.cloned(),
+                            oninput: move |evt| program_input.set(evt.value())
+                        }
+                        button {
+                            class: "bg-purple-600 hover:bg-purple-700 disabled:bg-surface-strong disabled:text-fg-subtle disabled:cursor-not-allowed text-white font-medium px-4 rounded-lg transition-colors whitespace-nowrap",
+                            disabled: is_processing.cloned(),
+                            onclick: handle_fetch,
+                            "Load from chain"
+                        }
+                    }
+                }
+                div {
+                    label {
+                        class: "block text-fg-muted font-medium mb-2",
+                        r#for: "anchor-idl-file",
+                        "Or upload an IDL file"
+                    }
+                    input {
+                        id: "anchor-idl-file",
+                        class: "block w-full text-fg-muted file:mr-4 file:py-2 file:px-4 file:rounded-lg file:border-0 file:bg-purple-600 file:text-white hover:file:bg-purple-700",
+                        r#type: "file",
+                        accept: ".json",
+                        disabled: is_processing.cloned(),
+                        onchange: handle_upload
+                    }
+                }
+            }
+
+            // Instruction form
+            if let Some(loaded) = idl.cloned() {
+                div {
+                    class: "mt-8 bg-surface/50 backdrop-blur-md rounded-2xl p-8 border border-line shadow-2xl space-y-6",
+                    div {
+                        label {
+                            class: "block text-fg-muted font-medium mb-2",
+                            r#for: "anchor-instruction",
+                            "Instruction"
+                        }
+                        select {
+                            id: "anchor-instruction",
+                            class: INPUT_CLASS,
+                            value: selected.cloned().unwrap_or_default(),
+                            onchange: move |evt| select_instruction(evt.value()),
+                            for ix in loaded.instructions.iter() {
+                                option { value: "{ix.name}", "{ix.name}" }
+                            }
+                        }
+                    }
+
+                    if let Some(ix) = current_ix {
+                        if !ix.flat_accounts().is_empty() {
+                            div {
+                                class: "space-y-4",
+                                h3 { class: "text-fg font-semibold text-lg", "Accounts" }
+                                for (i, (path, acc)) in ix.flat_accounts().into_iter().enumerate() {
+                                    div {
+                                        key: "{ix.name}-acc-{i}",
+                                        label {
+                                            class: "block text-fg-muted font-medium mb-2",
+                                            r#for: "anchor-account-{i}",
+                                            "{path}"
+                                            span {
+                                                class: "ml-2 text-xs text-fg-faint",
+                                                {account_flags(acc.writable, acc.signer, acc.optional)}
+                                            }
+                                        }
+                                        input {
+                                            id: "anchor-account-{i}",
+                                            class: INPUT_CLASS,
+                                            r#type: "text",
+                                            placeholder: if acc.optional { "Optional account address" } else { "Account address" },
+                                            value: account_inputs.read().get(i).cloned().unwrap_or_default(),
+                                            disabled: is_processing.cloned() || acc.address.is_some(),
+                                            oninput: move |evt| {
+                                                if let Some(slot) = account_inputs.write().get_mut(i) {
+                                                    *slot = evt.value();
+                                                }
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+
+                        if !ix.args.is_empty() {
+                            div {
+                                class: "space-y-4",
+                                h3 { class: "text-fg font-semibold text-lg", "Arguments" }
+                                for (i, arg) in ix.args.iter().cloned().enumerate() {
+                                    div {
+                                        key: "{ix.name}-arg-{i}",
+                                        label {
+                                            class: "block text-fg-muted font-medium mb-2",
+                                            r#for: "anchor-arg-{i}",
+                                            "{arg.name}"
+                                            span { class: "ml-2 text-xs text-fg-faint", "{arg.ty.label()}" }
+                                        }
+                                        ArgInput {
+                                            id: "anchor-arg-{i}",
+                                            ty: arg.ty.clone(),
+                                            value: arg_inputs.read().get(i).cloned().unwrap_or_default(),
+                                            disabled: is_processing.cloned(),
+                                            oninput: move |value: String| {
+                                                if let Some(slot) = arg_inputs.write().get_mut(i) {
+                                                    *slot = value;
+                                                }
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+
+                    button {
+                        class: "w-full bg-purple-600 hover:bg-purple-700 disabled:bg-surface-strong disabled:text-fg-subtle disabled:cursor-not-allowed text-white font-bold py-4 rounded-lg transition-all duration-200 transform hover:scale-105 disabled:hover:scale-100",
+                        disabled: is_processing.cloned() || !connected || selected.read().is_none(),
+                        onclick: handle_send,
+                        if is_processing.cloned() {
+                            "Processing..."
+                        } else {
+                            "Send Instruction"
+                        }
+                    }
+                }
+            }
+
+            // Account inspector
+            if let Some(loaded) = idl.cloned() {
+                if !loaded.accounts.is_empty() {
+                    // A different IDL starts the inspector over.
+                    AccountInspector {
+                        key: "{inspector_key(&loaded)}",
+                        idl: loaded,
+                        program: program_input.cloned(),
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Decodes a single account, or lists all accounts of one type, using the IDL.
+#[component]
+fn AccountInspector(idl: Idl, program: String) -> Element {
+    let mut address = use_signal(|| String::new());
+    let mut account_type = use_signal(|| idl.accounts.first().map(|acc| acc.name.clone()).unwrap_or_default());
+    let mut results = use_signal(Vec::<(String, DecodedAccount)>::new);
+    // Listed accounts that didn't decode, with the reason.
+    let mut undecoded = use_signal(Vec::<(String, String)>::new);
+    let mut error = use_signal(|| None::<String>);
+    let mut is_loading = use_signal(|| false);
+
+    let idl_decode = idl.clone();
+    let handle_decode = move |_| {
+        let idl = idl_decode.clone();
+        let address_str = address.cloned();
+
+        spawn(async move {
+            error.set(None);
+            results.set(Vec::new());
+            undecoded.set(Vec::new());
+            let pubkey = match Pubkey::from_str(address_str.trim()) {
+                Ok(pubkey) => pubkey,
+                Err(_) => {
+                    error.set(Some("Invalid account address".to_string()));
+                    return;
+                }
+            };
+
+            #[cfg(feature = "web")]
+            {
+                use crate::anchor::decode_account;
+                use crate::solana::{rpc_call, rpc_client};
+
+                is_loading.set(true);
+                let client = rpc_client();
+                match rpc_call("getAccountInfo", client.get_account(&pubkey)).await {
+                    Ok(account) => match decode_account(&idl, &account.data) {
+                        Ok(decoded) => results.set(vec![(pubkey.to_string(), decoded)]),
+                        Err(e) => error.set(Some(e)),
+                    },
+                    Err(e) => error.set(Some(format!("Failed to fetch account: {:?}", e))),
+                }
+                is_loading.set(false);
+            }
+
+            #[cfg(not(feature = "web"))]
+            {
+                let _ = (idl, pubkey);
+                error.set(Some("Fetching accounts is only available in web build".to_string()));
+            }
+        });
+    };
+
+    let idl_list = idl.clone();
+    let handle_list = move |_| {
+        let idl = idl_list.clone();
+        let program_str = program.clone();
+        let name = account_type.cloned();
+
+        spawn(async move {
+            error.set(None);
+            results.set(Vec::new());
+            undecoded.set(Vec::new());
+            let program_id = match Pubkey::from_str(program_str.trim()).ok().or_else(|| idl.program_id()) {
+                Some(pubkey) => pubkey,
+                None => {
+                    error.set(Some("Invalid program id".to_string()));
+                    return;
+                }
+            };
+
+            #[cfg(feature = "web")]
+            {
+                use crate::anchor::fetch_program_accounts;
+                use crate::solana::rpc_client;
+
+                is_loading.set(true);
+                let client = rpc_client();
+                match fetch_program_accounts(&client, &program_id, &idl, &name).await {
+                    Ok(listed) => {
+                        results.set(
+                            listed
+                                .decoded
+                                .into_iter()
+                                .map(|(pubkey, decoded)| (pubkey.to_string(), decoded))
+                                .collect(),
+                        );
+                        undecoded.set(
+                            listed
+                                .failed
+                                .into_iter()
+                                .map(|(pubkey, e)| (pubkey.to_string(), e))
+                                .collect(),
+                        );
+                    }
+                    Err(e) => error.set(Some(e)),
+                }
+                is_loading.set(false);
+            }
+
+            #[cfg(not(feature = "web"))]
+            {
+                let _ = (idl, program_id, name);
+                error.set(Some("Fetching accounts is only available in web build".to_string()));
+            }
+        });
+    };
+
+    rsx! {
+        div {
+            class: "mt-8 bg-surface/50 backdrop-blur-md rounded-2xl p-8 border border-line shadow-2xl space-y-6",
+            h3 { class: "text-fg font-semibold text-lg", "Program Accounts" }
+
+            div {
+                label {
+                    class: "block text-fg-muted font-medium mb-2",
+                    r#for: "anchor-decode-address",
+                    "Decode an account"
+                }
+                div {
+                    class: "flex space-x-2",
+                    input {
+                        id: "anchor-decode-address",
+                        class: INPUT_CLASS,
+                        r#type: "text",
+                        placeholder: "Account address",
+                        value: "{address}",
+                        disabled: is_loading.cloned(),
+                        oninput: move |evt| address.set(evt.value())
+                    }
+                    button {
+                        class: "bg-purple-600 hover:bg-purple-700 disabled:bg-surface-strong disabled:text-fg-subtle disabled:cursor-not-allowed text-white font-medium px-4 rounded-lg transition-colors",
+                        disabled: is_loading.cloned(),
+                        onclick: handle_decode,
+                        "Decode"
+                    }
+                }
+            }
+
+            div {
+                label {
+                    class: "block text-fg-muted font-medium mb-2",
+                    r#for: "anchor-account-type",
+                    "List accounts by type"
+                }
+                div {
+                    class: "flex space-x-2",
+                    select {
+                        id: "anchor-account-type",
+                        class: INPUT_CLASS,
+                        value: "{account_type}",
+                        disabled: is_loading.cloned(),
+                        onchange: move |evt| account_type.set(evt.value()),
+                        for acc in idl.accounts.iter() {
+                            option { value: "{acc.name}", "{acc.name}" }
+                        }
+                    }
+                    button {
+                        class: "bg-purple-600 hover:bg-purple-700 disabled:bg-surface-strong disabled:text-fg-subtle disabled:cursor-not-allowed text-white font-medium px-4 rounded-lg transition-colors",
+                        disabled: is_loading.cloned(),
+                        onclick: handle_list,
+                        "List"
+                    }
+                }
+            }
+
+            if is_loading.cloned() {
+                p { class: "text-info-fg text-sm", "Loading accounts..." }
+            }
+
+            if let Some(e) = error.cloned() {
+                p { class: "text-danger-fg text-sm break-words", "Error: {e}" }
+            }
+
+            if !undecoded.read().is_empty() {
+                details {
+                    class: "text-warning-fg text-sm",
+                    summary { "{undecoded.read().len()} accounts could not be decoded and are not shown" }
+                    for (pubkey, e) in undecoded.cloned().into_iter() {
+                        p { key: "{pubkey}", class: "mt-1 font-mono break-all", "{pubkey}: {e}" }
+                    }
+                }
+            }
+
+            for (pubkey, decoded) in results.cloned().into_iter() {
+                div {
+                    key: "{pubkey}",
+                    class: "bg-surface-sunken/50 rounded-lg p-4 border border-line text-sm font-mono",
+                    div {
+                        class: "flex justify-between mb-2",
+                        span { class: "text-fg break-all", "{pubkey}" }
+                        span { class: "text-fg-faint ml-2", "{decoded.name}" }
+                    }
+                    ValueTree { value: decoded.value }
+                }
+            }
+        }
+    }
+}
+
+/// Input matching the shape of an IDL argument type.
+#[component]
+fn ArgInput(
+    #[props(into)] id: String,
+    ty: IdlType,
+    value: String,
+    disabled: bool,
+    oninput: EventHandler<String>,
+) -> Element {
+    // Optional scalars are entered like the scalar; empty means none.
+    let (ty, optional) = match ty {
+        IdlType::Option(inner) | IdlType::COption(inner) if inner.is_scalar() => (*inner, true),
+        ty => (ty, false),
+    };
+    let placeholder = |hint: &str| match (optional, hint.is_empty()) {
+        (false, _) => hint.to_string(),
+        (true, true) => "Empty for none".to_string(),
+        (true, false) => format!("{}, or empty for none", hint),
+    };
+
+    match ty {
+        IdlType::Bool => rsx! {
+            input {
+                id: "{id}",
+                class: "h-5 w-5 accent-purple-600",
+                r#type: "checkbox",
+                checked: value == "true",
+                disabled,
+                onchange: move |evt: FormEvent| oninput.call(evt.checked().to_string())
+            }
+        },
+        ty if ty.is_integer() || matches!(ty, IdlType::F32 | IdlType::F64) => rsx! {
+            input {
+                id: "{id}",
+                class: INPUT_CLASS,
+                r#type: "text",
+                inputmode: if ty.is_integer() { "numeric" } else { "decimal" },
+                placeholder: placeholder("0"),
+                value,
+                disabled,
+                oninput: move |evt| oninput.call(evt.value())
+            }
+        },
+        IdlType::String | IdlType::Pubkey => rsx! {
+            input {
+                id: "{id}",
+                class: INPUT_CLASS,
+                r#type: "text",
+                placeholder: placeholder(if ty == IdlType::Pubkey { "Base58 address" } else { "" }),
+                value,
+                disabled,
+                oninput: move |evt| oninput.call(evt.value())
+            }
+        },
+        _ => rsx! {
+            textarea {
+                id: "{id}",
+                class: "{INPUT_CLASS} font-mono text-sm",
+                rows: 3,
+                placeholder: "JSON value, e.g. [1, 2, 3] or {{\"field\": 1}}",
+                value,
+                disabled,
+                oninput: move |evt| oninput.call(evt.value())
+            }
+        },
+    }
+}
+
+/// Identifies an IDL by its program address, or by name if it has none.
+fn inspector_key(idl: &Idl) -> String {
+    idl.program_id()
+        .map(|program_id| program_id.to_string())
+        .unwrap_or_else(|| idl.display_name())
+}
+
+fn account_flags(writable: bool, signer: bool, optional: bool) -> String {
+    let mut flags = Vec::new();
+    if writable {
+        flags.push("mut");
+    }
+    if signer {
+        flags.push("signer");
+    }
+    if optional {
+        flags.push("optional");
+    }
+    flags.join(", ")
+}
+
+/// Validates the form and turns it into an instruction signed by the wallet.
+fn prepare_instruction(
+    wallet: &Wallet,
+    program_str: &str,
+    idl: Option<&Idl>,
+    ix_name: Option<&str>,
+    accounts_raw: &[String],
+    args_raw: &[String],
+) -> Result<(Pubkey, solana_sdk::instruction::Instruction), String> {
+    let sender_pubkey = match wallet {
+        Wallet::Connected(pubkey) => *pubkey,
+        Wallet::Disconnected => return Err("Please connect your wallet first".to_string()),
+    };
+    let idl = idl.ok_or("Load an IDL first")?;
+    let ix = ix_name
+        .and_then(|name| idl.instruction(name))
+        .ok_or("Select an instruction")?;
+    let program_id = Pubkey::from_str(program_str.trim())
+        .ok()
+        .or_else(|| idl.program_id())
+        .ok_or("Invalid program id")?;
+
+    let flat = ix.flat_accounts();
+    let mut accounts = Vec::with_capacity(flat.len());
+    for ((path, acc), raw) in flat.iter().zip(accounts_raw) {
+        let raw = raw.trim();
+        if raw.is_empty() {
+            accounts.push(None);
+            continue;
+        }
+        let pubkey = Pubkey::from_str(raw).map_err(|_| format!("Invalid address for `{}`", path))?;
+        // The wallet is the only signer this page can provide.
+        if acc.signer && pubkey != sender_pubkey {
+            return Err(format!("`{}` must be signed by the connected wallet", path));
+        }
+        accounts.push(Some(pubkey));
+    }
+
+    let args = ix
+        .args
+        .iter()
+        .zip(args_raw)
+        .map(|(arg, raw)| parse_input(&arg.ty, raw).map_err(|e| format!("{}: {}", arg.name, e)))
+        .collect::<Result<Vec<_>, String>>()?;
+
+    let instruction = build_instruction(idl, program_id, ix, &accounts, &args)?;
+    Ok((sender_pubkey, instruction))
+}
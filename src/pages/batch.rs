@@ -0,0 +1,628 @@
+use dioxus::prelude::*;
+use crate::components::OfflineTransaction;
+use crate::hooks::{use_toasts, use_tx_queue, use_wallet, Wallet};
+use crate::solana::{format_ui_amount, parse_payouts_csv, PayoutBatch, PayoutRow, PayoutRowError};
+use crate::solana_pay::MintInfo;
+use solana_sdk::{pubkey::Pubkey, transaction::Transaction};
+use std::collections::BTreeMap;
+
+#[derive(Clone, Debug, PartialEq)]
+enum RowStatus {
+    Pending,
+    Signing,
+    /// Sent, waiting for the commitment level picked in settings.
+    Confirming(String),
+    Confirmed(String),
+    /// `signature` is kept when the transaction was sent but not confirmed,
+    /// so a retry can check whether it landed after all.
+    Failed { error: String, signature: Option<String> },
+}
+
+impl RowStatus {
+    fn label(&self) -> &'static str {
+        match self {
+            RowStatus::Pending => "pending",
+            RowStatus::Signing => "signing",
+            RowStatus::Confirming(_) => "confirming",
+            RowStatus::Confirmed(_) => "confirmed",
+            RowStatus::Failed { .. } => "failed",
+        }
+    }
+}
+
+/// Validated payouts, ready to sign.
+#[derive(Clone, Debug, PartialEq)]
+struct PayoutPlan {
+    batches: Vec<PayoutBatch>,
+    /// Base units paid by each row.
+    amounts: Vec<u64>,
+    mints: BTreeMap<Pubkey, MintInfo>,
+}
+
+impl PayoutPlan {
+    /// Total per token, formatted; SOL is listed under `None`.
+    fn totals(&self, rows: &[PayoutRow]) -> Vec<(Option<Pubkey>, String)> {
+        let mut totals: BTreeMap<Option<Pubkey>, u128> = BTreeMap::new();
+        for (row, amount) in rows.iter().zip(&self.amounts) {
+            *totals.entry(row.token).or_default() += *amount as u128;
+        }
+        totals
+            .into_iter()
+            .map(|(token, total)| {
+                let decimals = token
+                    .and_then(|mint| self.mints.get(&mint))
+                    .map(|mint| mint.decimals)
+                    .unwrap_or(crate::solana::LAMPORTS_DECIMALS);
+                let total = format_ui_amount(total.min(u64::MAX as u128) as u64, decimals)
+                    .unwrap_or_else(|e| format!("Error: {}", e));
+                (token, total)
+            })
+            .collect()
+    }
+}
+
+#[component]
+pub fn Batch() -> Element {
+    let wallet = use_wallet();
+    let tx_queue = use_tx_queue();
+    let mut rows = use_signal(Vec::<PayoutRow>::new);
+    // Lines that didn't parse need a new upload; validation errors can be
+    // retried with Review.
+    let mut row_errors = use_signal(Vec::<PayoutRowError>::new);
+    let mut plan_errors = use_signal(Vec::<PayoutRowError>::new);
+    let mut plan = use_signal(|| None::<PayoutPlan>);
+    let mut statuses = use_signal(Vec::<RowStatus>::new);
+    let toasts = use_toasts();
+    let mut is_processing = use_signal(|| false);
+    let mut offline_transactions = use_signal(Vec::<Transaction>::new);
+
+    let handle_upload = move |evt: FormEvent| async move {
+        let Some(files) = evt.files() else {
+            return;
+        };
+        let Some(name) = files.files().into_iter().next() else {
+            return;
+        };
+        let Some(text) = files.read_file_to_string(&name).await else {
+            toasts.error(format!("Could not read {}", name));
+            return;
+        };
+
+        let (parsed, errors) = parse_payouts_csv(&text);
+        toasts.info(format!("Loaded {} payouts from {}", parsed.len(), name));
+        statuses.set(vec![RowStatus::Pending; parsed.len()]);
+        rows.set(parsed);
+        row_errors.set(errors);
+        plan_errors.set(Vec::new());
+        plan.set(None);
+    };
+
+    let handle_review = move |_| {
+        let wallet_clone = wallet.cloned();
+        let rows_clone = rows.cloned();
+
+        spawn(async move {
+            let payer = match wallet_clone {
+                Wallet::Connected(pubkey) => pubkey,
+                Wallet::Disconnected => {
+                    toasts.error("Please connect your wallet first");
+                    return;
+                }
+            };
+
+            #[cfg(feature = "web")]
+            {
+                is_processing.set(true);
+                plan_errors.set(Vec::new());
+                match build_plan(&payer, &rows_clone).await {
+                    Ok(built) => {
+                        toasts.info(format!(
+                            "{} payouts packed into {} transactions",
+                            rows_clone.len(),
+                            built.batches.len()
+                        ));
+                        plan.set(Some(built));
+                    }
+                    Err(errors) => {
+                        toasts.error(format!("{} rows failed validation", errors.len()));
+                        plan_errors.set(errors);
+                    }
+                }
+                is_processing.set(false);
+            }
+
+            #[cfg(not(feature = "web"))]
+            {
+                let _ = (payer, rows_clone);
+                toasts.info("Batch payouts are only available in web build");
+            }
+        });
+    };
+
+    let handle_pay = move |_| {
+        let wallet_clone = wallet.cloned();
+        let Some(plan_clone) = plan.cloned() else {
+            return;
+        };
+
+        spawn(async move {
+            let payer = match wallet_clone {
+                Wallet::Connected(pubkey) => pubkey,
+                Wallet::Disconnected => {
+                    toasts.error("Please connect your wallet first");
+                    return;
+                }
+            };
+
+            #[cfg(feature = "web")]
+            {
+                use crate::solana::{rpc_client, send_all_with_wallet, signature_status};
+                use futures::channel::oneshot;
+
+                is_processing.set(true);
+                let client = rpc_client();
+
+                // A batch that didn't confirm may still have landed; check
+                // before paying it again. One the cluster doesn't know by now
+                // has an expired blockhash and can't land anymore.
+                for batch in &plan_clone.batches {
+                    let Some(RowStatus::Failed { signature: Some(signature), .. }) =
+                        batch.rows.first().map(|&i| statuses.read()[i].clone())
+                    else {
+                        continue;
+                    };
+                    let status = match signature_status(&client, &signature, true).await {
+                        Ok(Some(Ok(_))) => RowStatus::Confirmed(signature),
+                        Ok(Some(Err(error))) => RowStatus::Failed { error, signature: None },
+                        Ok(None) => continue,
+                        Err(e) => {
+                            toasts.error(e);
+                            is_processing.set(false);
+                            return;
+                        }
+                    };
+                    for &i in &batch.rows {
+                        statuses.write()[i] = status.clone();
+                    }
+                }
+
+                // Rows already paid in an earlier attempt are not paid twice.
+                let pending: Vec<&PayoutBatch> = plan_clone
+                    .batches
+                    .iter()
+                    .filter(|batch| {
+                        !batch.rows.iter().all(|&i| matches!(statuses.read()[i], RowStatus::Confirmed(_)))
+                    })
+                    .collect();
+                let total = pending.len();
+                let mut failed = 0;
+
+                toasts.info(format!("Approve {} transactions in your wallet...", total));
+                let tx_ids: Vec<u64> = pending
+                    .iter()
+                    .enumerate()
+                    .map(|(n, batch)| {
+                        let tx_id =
+                            tx_queue.add(format!("Batch payout {}/{} ({} payouts)", n + 1, total, batch.rows.len()));
+                        tx_queue.awaiting_signature(tx_id);
+                        tx_id
+                    })
+                    .collect();
+                for batch in &pending {
+                    for &i in &batch.rows {
+                        statuses.write()[i] = RowStatus::Signing;
+                    }
+                }
+
+                let instruction_lists: Vec<_> = pending.iter().map(|batch| batch.instructions.clone()).collect();
+                let mut confirmations = Vec::new();
+                match send_all_with_wallet(&client, &payer, &instruction_lists).await {
+                    Ok(results) => {
+                        for ((batch, result), &tx_id) in pending.iter().zip(results).zip(&tx_ids) {
+                            let status = match result {
+                                Ok(signature) => {
+                                    let (settled, confirmation) = oneshot::channel();
+                                    tx_queue.sent_and_then(tx_id, signature.clone(), move |result| {
+                                        let _ = settled.send(result);
+                                    });
+                                    confirmations.push((batch, signature.clone(), confirmation));
+                                    RowStatus::Confirming(signature)
+                                }
+                                Err(error) => {
+                                    tx_queue.fail(tx_id, error.clone());
+                                    failed += 1;
+                                    RowStatus::Failed { error, signature: None }
+                                }
+                            };
+                            for &i in &batch.rows {
+                                statuses.write()[i] = status.clone();
+                            }
+                        }
+                    }
+                    Err(e) => {
+                        failed = total;
+                        for &tx_id in &tx_ids {
+                            tx_queue.fail(tx_id, e.clone());
+                        }
+                        for batch in &pending {
+                            for &i in &batch.rows {
+                                statuses.write()[i] = RowStatus::Failed {
+                                    error: e.clone(),
+                                    signature: None,
+                                };
+                            }
+                        }
+                    }
+                }
+
+                // Rows only count as paid once their transaction confirms.
+                if !confirmations.is_empty() {
+                    toasts.info(format!("Waiting for {} transactions to confirm...", confirmations.len()));
+                }
+                for (batch, signature, confirmation) in confirmations {
+                    let status = match confirmation.await {
+                        Ok(Ok(_)) => RowStatus::Confirmed(signature),
+                        Ok(Err(error)) => {
+                            failed += 1;
+                            RowStatus::Failed {
+                                error,
+                                signature: Some(signature),
+                            }
+                        }
+                        Err(_) => {
+                            failed += 1;
+                            RowStatus::Failed {
+                                error: "Stopped waiting for confirmation".to_string(),
+                                signature: Some(signature),
+                            }
+                        }
+                    };
+                    for &i in &batch.rows {
+                        statuses.write()[i] = status.clone();
+                    }
+                }
+
+                if failed == 0 {
+                    toasts.success(format!("Confirmed {} transactions", total));
+                } else {
+                    toasts.error(format!("{} of {} transactions failed", failed, total));
+                }
+                is_processing.set(false);
+            }
+
+            #[cfg(not(feature = "web"))]
+            {
+                let _ = (payer, plan_clone, tx_queue);
+                toasts.info("Batch payouts are only available in web build");
+            }
+        });
+    };
+
+    let handle_export = move |_| {
+        let wallet_clone = wallet.cloned();
+        let Some(plan_clone) = plan.cloned() else {
+            return;
+        };
+
+        spawn(async move {
+            let payer = match wallet_clone {
+                Wallet::Connected(pubkey) => pubkey,
+                Wallet::Disconnected => {
+                    toasts.error("Please connect your wallet first");
+                    return;
+                }
+            };
+
+            #[cfg(feature = "web")]
+            {
+                use crate::hooks::current_settings;
+                use crate::solana::{build_transaction, rpc_call, rpc_client};
+
+                is_processing.set(true);
+                let priority_fee = current_settings().priority_fee;
+                let client = rpc_client();
+                match rpc_call("getLatestBlockhash", client.get_latest_blockhash()).await {
+                    Ok(blockhash) => {
+                        let transactions: Vec<Transaction> = plan_clone
+                            .batches
+                            .iter()
+                            .filter(|batch| {
+                                !batch.rows.iter().all(|&i| matches!(statuses.read()[i], RowStatus::Confirmed(_)))
+                            })
+                            .map(|batch| {
+                                let mut transaction = build_transaction(&batch.instructions, &payer, priority_fee);
+                                transaction.message.recent_blockhash = blockhash;
+                                transaction
+                            })
+                            .collect();
+                        toasts.info(format!(
+                            "Exported {} transactions for offline signing. They expire in about a minute unless sent",
+                            transactions.len()
+                        ));
+                        offline_transactions.set(transactions);
+                    }
+                    Err(e) => toasts.error(format!("Failed to get blockhash: {:?}", e)),
+                }
+                is_processing.set(false);
+            }
+
+            #[cfg(not(feature = "web"))]
+            {
+                let _ = (payer, plan_clone);
+                toasts.info("Batch payouts are only available in web build");
+            }
+        });
+    };
+
+    let connected = matches!(wallet.cloned(), Wallet::Connected(_));
+    let has_results = statuses.read().iter().any(|status| !matches!(status, RowStatus::Pending));
+    let totals = plan.read().as_ref().map(|plan| plan.totals(&rows.read()));
+
+    rsx! {
+        div {
+            class: "max-w-5xl mx-auto",
+
+            // Header
+            div {
+                class: "text-center mb-12",
+                h1 {
+                    class: "text-5xl font-bold text-fg mb-4",
+                    "Batch Payouts"
+                }
+                p {
+                    class: "text-fg-muted text-lg",
+                    "Pay many recipients at once from a CSV file"
+                }
+            }
+
+            div {
+                class: "bg-surface/50 backdrop-blur-md rounded-2xl p-8 border border-line shadow-2xl space-y-6",
+
+                div {
+                    label {
+                        class: "block text-fg-muted font-medium mb-2",
+                        r#for: "batch-csv",
+                        "Payout CSV"
+                    }
+                    input {
+                        id: "batch-csv",
+                        class: "block w-full text-fg-muted file:mr-4 file:py-2 file:px-4 file:rounded-lg file:border-0 file:bg-purple-600 file:text-white hover:file:bg-purple-700",
+                        r#type: "file",
+                        accept: ".csv,text/csv",
+                        disabled: is_processing.cloned(),
+                        onchange: handle_upload
+                    }
+                    p {
+                        class: "mt-2 text-fg-faint text-sm",
+                        "One payout per line: address,amount,token — leave token empty for SOL."
+                    }
+                }
+
+                if !row_errors.read().is_empty() || !plan_errors.read().is_empty() {
+                    div {
+                        class: "p-4 rounded-lg bg-danger-surface border border-danger text-danger-fg text-sm space-y-1",
+                        for error in row_errors.read().iter().chain(plan_errors.read().iter()) {
+                            p { key: "{error.line}", "Line {error.line}: {error.message}" }
+                        }
+                    }
+                }
+
+                if !rows.read().is_empty() {
+                    div {
+                        class: "flex space-x-4",
+                        button {
+                            class: "flex-1 bg-surface-raised hover:bg-surface-strong disabled:bg-surface-strong disabled:cursor-not-allowed text-fg font-bold py-4 rounded-lg transition-colors",
+                            disabled: is_processing.cloned() || !connected || !row_errors.read().is_empty(),
+                            onclick: handle_review,
+                            "Review"
+                        }
+                        button {
+                            class: "flex-1 bg-purple-600 hover:bg-purple-700 disabled:bg-surface-strong disabled:text-fg-subtle disabled:cursor-not-allowed text-white font-bold py-4 rounded-lg transition-colors",
+                            disabled: is_processing.cloned() || !connected || plan.read().is_none(),
+                            onclick: handle_pay,
+                            if is_processing.cloned() { "Processing..." } else { "Sign and Send All" }
+                        }
+                    }
+                    button {
+                        class: "w-full bg-surface-raised hover:bg-surface-strong disabled:bg-surface-strong disabled:cursor-not-allowed text-fg font-medium py-3 rounded-lg transition-colors",
+                        disabled: is_processing.cloned() || !connected || plan.read().is_none(),
+                        onclick: handle_export,
+                        "Export for Offline Signing"
+                    }
+                }
+            }
+
+            if !offline_transactions.read().is_empty() {
+                div {
+                    class: "mt-8 bg-surface/50 backdrop-blur-md rounded-2xl p-6 border border-line shadow-2xl space-y-4",
+                    h3 { class: "text-fg font-semibold text-lg", "Offline signing" }
+                    p {
+                        class: "text-fg-subtle text-sm",
+                        "Transactions sent from here are not reflected in the payout table below."
+                    }
+                    for (index, transaction) in offline_transactions.read().iter().enumerate() {
+                        div {
+                            key: "{index}-{transaction.message.recent_blockhash}",
+                            p { class: "text-fg-muted text-sm mb-2", {format!("Transaction {}", index + 1)} }
+                            OfflineTransaction { transaction: transaction.clone() }
+                        }
+                    }
+                }
+            }
+
+            if let Some(totals) = totals {
+                div {
+                    class: "mt-8 bg-surface/50 backdrop-blur-md rounded-2xl p-6 border border-line shadow-2xl",
+                    h3 { class: "text-fg font-semibold text-lg mb-3", "Totals" }
+                    for (token, total) in totals.into_iter() {
+                        p {
+                            class: "text-fg-muted font-mono text-sm",
+                            "{total} "
+                            {token.map(|mint| mint.to_string()).unwrap_or_else(|| "SOL".to_string())}
+                        }
+                    }
+                }
+            }
+
+            if !rows.read().is_empty() {
+                div {
+                    class: "mt-8 bg-surface/50 backdrop-blur-md rounded-2xl p-6 border border-line shadow-2xl overflow-x-auto",
+                    div {
+                        class: "flex items-center justify-between mb-3",
+                        h3 { class: "text-fg font-semibold text-lg", "Payouts" }
+                        if has_results {
+                            a {
+                                class: "text-purple-400 hover:text-purple-300 text-sm font-medium",
+                                href: results_csv_href(&rows.read(), &statuses.read()),
+                                download: "payout-results.csv",
+                                "Download results CSV"
+                            }
+                        }
+                    }
+                    table {
+                        class: "w-full text-sm text-left",
+                        thead {
+                            tr {
+                                class: "text-fg-subtle border-b border-line",
+                                th { class: "py-2 pr-4", "Line" }
+                                th { class: "py-2 pr-4", "Recipient" }
+                                th { class: "py-2 pr-4", "Amount" }
+                                th { class: "py-2 pr-4", "Token" }
+                                th { class: "py-2", "Status" }
+                            }
+                        }
+                        tbody {
+                            for (i, row) in rows.read().iter().enumerate() {
+                                tr {
+                                    key: "{row.line}",
+                                    class: "border-b border-line text-fg-muted",
+                                    td { class: "py-2 pr-4", "{row.line}" }
+                                    td { class: "py-2 pr-4 font-mono break-all", "{row.recipient}" }
+                                    td { class: "py-2 pr-4 font-mono", "{row.amount}" }
+                                    td {
+                                        class: "py-2 pr-4 font-mono break-all",
+                                        {row.token.map(|mint| mint.to_string()).unwrap_or_else(|| "SOL".to_string())}
+                                    }
+                                    td {
+                                        class: "py-2",
+                                        {status_cell(statuses.read().get(i).cloned().unwrap_or(RowStatus::Pending))}
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+fn status_cell(status: RowStatus) -> Element {
+    match status {
+        RowStatus::Pending => rsx! { span { class: "text-fg-faint", "Pending" } },
+        RowStatus::Signing => rsx! { span { class: "text-info-fg", "Signing..." } },
+        RowStatus::Confirming(signature) => rsx! {
+            span { class: "text-info-fg", title: "{signature}", "Confirming..." }
+        },
+        RowStatus::Confirmed(signature) => rsx! {
+            span { class: "text-success-fg font-mono break-all", title: "{signature}", "Confirmed" }
+        },
+        RowStatus::Failed { error, .. } => rsx! {
+            span { class: "text-danger-fg", title: "{error}", "Failed" }
+        },
+    }
+}
+
+/// Result CSV as a data URL for the download link.
+fn results_csv_href(rows: &[PayoutRow], statuses: &[RowStatus]) -> String {
+    let mut csv = String::from("address,amount,token,status,signature,error\n");
+    for (row, status) in rows.iter().zip(statuses) {
+        let (signature, error) = match status {
+            RowStatus::Confirming(signature) | RowStatus::Confirmed(signature) => (signature.as_str(), ""),
+            RowStatus::Failed { error, signature } => (signature.as_deref().unwrap_or_default(), error.as_str()),
+            _ => ("", ""),
+        };
+        csv.push_str(&format!(
+            "{},{},{},{},{},\"{}\"\n",
+            row.recipient,
+            row.amount,
+            row.token.map(|mint| mint.to_string()).unwrap_or_default(),
+            status.label(),
+            signature,
+            error.replace('"', "\"\"")
+        ));
+    }
+    format!(
+        "data:text/csv;charset=utf-8,{}",
+        crate::solana_pay::encode_component(&csv)
+    )
+}
+
+/// Fetches mint details, checks every amount and packs the transfers.
+/// Returns every failing row on error.
+#[cfg(feature = "web")]
+async fn build_plan(payer: &Pubkey, rows: &[PayoutRow]) -> Result<PayoutPlan, Vec<PayoutRowError>> {
+    use crate::hooks::current_settings;
+    use crate::solana::{fetch_mint, pack_payouts, rpc_client};
+    use crate::solana_pay::TransferRequest;
+
+    let client = rpc_client();
+    let mut mints = BTreeMap::new();
+    let mut errors = Vec::new();
+
+    for mint in rows.iter().filter_map(|row| row.token) {
+        if mints.contains_key(&mint) {
+            continue;
+        }
+        match fetch_mint(&client, &mint).await {
+            Ok((token_program, decimals)) => {
+                mints.insert(mint, MintInfo { token_program, decimals });
+            }
+            Err(e) => errors.extend(rows.iter().filter(|row| row.token == Some(mint)).map(|row| {
+                PayoutRowError {
+                    line: row.line,
+                    message: e.clone(),
+                }
+            })),
+        }
+    }
+
+    let mut amounts = Vec::with_capacity(rows.len());
+    let mut row_instructions = Vec::with_capacity(rows.len());
+    for row in rows {
+        let request = TransferRequest {
+            recipient: row.recipient,
+            amount: Some(row.amount.clone()),
+            spl_token: row.token,
+            ..Default::default()
+        };
+        let mint = row.token.and_then(|mint| mints.get(&mint).copied());
+        if row.token.is_some() && mint.is_none() {
+            continue;
+        }
+        match request
+            .amount_base_units(mint)
+            .and_then(|amount| request.instructions(payer, mint).map(|ixs| (amount, ixs)))
+        {
+            Ok((amount, instructions)) => {
+                amounts.push(amount);
+                row_instructions.push(instructions);
+            }
+            Err(message) => errors.push(PayoutRowError { line: row.line, message }),
+        }
+    }
+
+    if !errors.is_empty() {
+        errors.sort_by_key(|error| error.line);
+        return Err(errors);
+    }
+
+    let priority_fee = current_settings().priority_fee;
+    let batches = pack_payouts(payer, &row_instructions, priority_fee).map_err(|index| {
+        vec![PayoutRowError {
+            line: rows[index].line,
+            message: "Does not fit in a single transaction".to_string(),
+        }]
+    })?;
+    Ok(PayoutPlan { batches, amounts, mints })
+}
@@ -0,0 +1,294 @@
+use dioxus::prelude::*;
+use crate::hooks::{use_i18n, use_settings, use_tx_queue, use_wallet, TxStatus, Wallet};
+use crate::pages::NotFound;
+use crate::solana::Cluster;
+use crate::telemetry::{recent_events, TelemetryEvent};
+
+const CARD_CLASS: &str = "bg-surface/50 backdrop-blur-md rounded-2xl p-6 border border-line shadow-2xl space-y-3";
+const BUTTON_CLASS: &str = "px-4 py-2 bg-surface-raised hover:bg-surface-strong text-fg rounded-lg font-medium transition-colors";
+const DANGER_BUTTON_CLASS: &str = "px-4 py-2 bg-red-600 hover:bg-red-700 text-white rounded-lg font-medium transition-colors";
+const ROW_CLASS: &str = "bg-surface-sunken/50 border border-line-strong rounded-lg px-3 py-2 text-sm font-mono break-all";
+
+/// How many events each event list shows, newest first.
+const SHOWN_EVENTS: usize = 100;
+
+/// Developer panel at `/debug`. Builds with the `debug-panel` feature always
+/// show it; other builds only with `?enable=1`, and a 404 otherwise.
+#[component]
+pub fn Debug(enable: String) -> Element {
+    let enabled = cfg!(feature = "debug-panel") || matches!(enable.as_str(), "1" | "true");
+    if !enabled {
+        return rsx! { NotFound { _route: vec!["debug".to_string()] } };
+    }
+    rsx! { DebugPanel {} }
+}
+
+#[component]
+fn DebugPanel() -> Element {
+    let mut wallet = use_wallet();
+    let settings = use_settings();
+    let queue = use_tx_queue();
+    let i18n = use_i18n();
+    // Telemetry and storage aren't signals; bumping this re-reads them.
+    let mut refresh = use_signal(|| 0u32);
+    let _ = refresh();
+
+    let mut latency = use_resource(move || async move {
+        // Measure again when the endpoint changes.
+        let _ = settings.read().rpc_url.clone();
+
+        #[cfg(feature = "web")]
+        {
+            use crate::solana::{rpc_call, rpc_client};
+            use crate::telemetry::now_ms;
+
+            let client = rpc_client();
+            let started = now_ms();
+            rpc_call("getLatestBlockhash", client.get_latest_blockhash())
+                .await
+                .map(|_| (now_ms() - started).round() as u64)
+                .map_err(|e| format!("{:?}", e))
+        }
+
+        #[cfg(not(feature = "web"))]
+        {
+            Err::<u64, String>("Latency is only measured in web build".to_string())
+        }
+    });
+
+    let current = settings.cloned();
+    let cluster = Cluster::from_rpc_url(current.rpc_url());
+    let transactions = queue.transactions();
+    let events = recent_events();
+    let rpc_calls: Vec<TelemetryEvent> = events
+        .iter()
+        .rev()
+        .filter(|event| event.spans.iter().any(|span| span == "rpc"))
+        .take(SHOWN_EVENTS)
+        .cloned()
+        .collect();
+    let latest_events: Vec<TelemetryEvent> = events.iter().rev().take(SHOWN_EVENTS).cloned().collect();
+    let storage = storage_entries();
+
+    let handle_disconnect = move |_| {
+        tracing::info!("Wallet disconnect forced from debug panel");
+        #[cfg(feature = "web")]
+        spawn(async move {
+            crate::hooks::disconnect_wallet().await;
+        });
+        wallet.set(Wallet::Disconnected);
+    };
+
+    let handle_clear_storage = move |_| {
+        #[cfg(feature = "web")]
+        {
+            use gloo_storage::{LocalStorage, Storage};
+
+            if gloo::dialogs::confirm("Clear all saved data (settings, address book, activity) and reload?") {
+                LocalStorage::clear();
+                if let Err(e) = gloo::utils::window().location().reload() {
+                    tracing::warn!("Failed to reload after clearing storage: {:?}", e);
+                }
+            }
+        }
+    };
+
+    rsx! {
+        div {
+            class: "max-w-4xl mx-auto space-y-6",
+
+            // Header
+            div {
+                class: "flex items-center justify-between",
+                div {
+                    h1 { class: "text-4xl font-bold text-fg", "Debug" }
+                    p { class: "text-fg-muted", "Live app state for development" }
+                }
+                button {
+                    class: BUTTON_CLASS,
+                    onclick: move |_| refresh += 1,
+                    "Refresh"
+                }
+            }
+
+            // Wallet
+            section {
+                class: CARD_CLASS,
+                h2 { class: "text-xl font-semibold text-fg", "Wallet" }
+                p {
+                    class: ROW_CLASS,
+                    {match wallet.cloned() {
+                        Wallet::Connected(address) => format!("Connected: {}", address),
+                        Wallet::Disconnected => "Disconnected".to_string(),
+                    }}
+                }
+                button {
+                    class: DANGER_BUTTON_CLASS,
+                    onclick: handle_disconnect,
+                    "Force disconnect"
+                }
+            }
+
+            // Cluster
+            section {
+                class: CARD_CLASS,
+                h2 { class: "text-xl font-semibold text-fg", "Cluster" }
+                dl {
+                    class: "grid grid-cols-[max-content_1fr] gap-x-4 gap-y-1 text-sm",
+                    dt { class: "text-fg-subtle", "Cluster" }
+                    dd { class: "text-fg font-mono", {cluster.name()} }
+                    dt { class: "text-fg-subtle", "Endpoint" }
+                    dd { class: "text-fg font-mono break-all", {current.rpc_url().to_string()} }
+                    dt { class: "text-fg-subtle", "Commitment" }
+                    dd { class: "text-fg font-mono", {current.commitment.key()} }
+                    dt { class: "text-fg-subtle", "Latency" }
+                    dd {
+                        class: "text-fg font-mono",
+                        aria_live: "polite",
+                        {match &*latency.read() {
+                            None => "Measuring…".to_string(),
+                            Some(Ok(latency_ms)) => format!("{} ms (getLatestBlockhash)", latency_ms),
+                            Some(Err(e)) => format!("Failed: {}", e),
+                        }}
+                    }
+                }
+                button {
+                    class: BUTTON_CLASS,
+                    onclick: move |_| latency.restart(),
+                    "Measure again"
+                }
+            }
+
+            // Transaction queue
+            section {
+                class: CARD_CLASS,
+                h2 { class: "text-xl font-semibold text-fg", "Transaction queue ({transactions.len()})" }
+                if transactions.is_empty() {
+                    p { class: "text-fg-subtle text-sm", "Empty" }
+                }
+                for transaction in transactions {
+                    div {
+                        key: "{transaction.id}",
+                        class: ROW_CLASS,
+                        p { class: "text-fg", "#{transaction.id} {transaction.label}" }
+                        p {
+                            class: "text-fg-muted",
+                            {i18n.t(transaction.status.message_key())}
+                            if let Some(signature) = &transaction.signature {
+                                " · {signature}"
+                            }
+                            if let Some(slot) = transaction.slot {
+                                " · slot {slot}"
+                            }
+                        }
+                        if let TxStatus::Failed { error } = &transaction.status {
+                            p { class: "text-danger-fg", "{error}" }
+                        }
+                    }
+                }
+            }
+
+            // RPC calls
+            section {
+                class: CARD_CLASS,
+                h2 { class: "text-xl font-semibold text-fg", "Recent RPC calls" }
+                if rpc_calls.is_empty() {
+                    p { class: "text-fg-subtle text-sm", "No RPC calls yet" }
+                }
+                for event in rpc_calls {
+                    div {
+                        class: "{ROW_CLASS} flex justify-between gap-4",
+                        span { class: "text-fg", {field(&event, "operation")} }
+                        span { class: "text-danger-fg", {field(&event, "error_kind")} }
+                        span { class: "text-fg-muted", {format!("{} ms", field(&event, "latency_ms"))} }
+                        span { class: "text-fg-subtle", {i18n.date((event.timestamp_ms / 1000) as i64)} }
+                    }
+                }
+            }
+
+            // Storage
+            section {
+                class: CARD_CLASS,
+                div {
+                    class: "flex items-center justify-between",
+                    h2 { class: "text-xl font-semibold text-fg", "Local storage" }
+                    button {
+                        class: DANGER_BUTTON_CLASS,
+                        onclick: handle_clear_storage,
+                        "Clear storage"
+                    }
+                }
+                if storage.is_empty() {
+                    p { class: "text-fg-subtle text-sm", "Empty" }
+                }
+                for (key, value) in storage {
+                    details {
+                        key: "{key}",
+                        class: ROW_CLASS,
+                        summary { class: "text-fg cursor-pointer", "{key} ({value.len()} bytes)" }
+                        pre { class: "mt-2 text-fg-muted whitespace-pre-wrap", "{value}" }
+                    }
+                }
+            }
+
+            // Tracing
+            section {
+                class: CARD_CLASS,
+                h2 { class: "text-xl font-semibold text-fg", "Tracing events" }
+                if latest_events.is_empty() {
+                    p { class: "text-fg-subtle text-sm", "No events yet" }
+                }
+                div {
+                    class: "space-y-1 max-h-96 overflow-y-auto",
+                    for event in latest_events {
+                        p {
+                            class: format!("{} {}", ROW_CLASS, level_class(&event.level)),
+                            {event.summary()}
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+fn level_class(level: &str) -> &'static str {
+    match level {
+        "ERROR" | "WARN" => "text-danger-fg",
+        _ => "text-fg-muted",
+    }
+}
+
+/// A field of `event` as plain text, or empty if it isn't set.
+fn field(event: &TelemetryEvent, name: &str) -> String {
+    match event.fields.get(name) {
+        Some(serde_json::Value::String(value)) => value.clone(),
+        Some(value) => value.to_string(),
+        None => String::new(),
+    }
+}
+
+/// Every local storage key and its raw value, sorted by key.
+fn storage_entries() -> Vec<(String, String)> {
+    #[cfg(feature = "web")]
+    {
+        use gloo_storage::{LocalStorage, Storage};
+
+        let storage = LocalStorage::raw();
+        let length = storage.length().unwrap_or(0);
+        let mut entries: Vec<(String, String)> = (0..length)
+            .filter_map(|index| storage.key(index).ok().flatten())
+            .map(|key| {
+                let value = storage.get_item(&key).ok().flatten().unwrap_or_default();
+                (key, value)
+            })
+            .collect();
+        entries.sort();
+        entries
+    }
+
+    #[cfg(not(feature = "web"))]
+    {
+        Vec::new()
+    }
+}
@@ -1,150 +1,327 @@
 use dioxus::prelude::*;
-use crate::hooks::{use_wallet, Wallet, sign_and_send_transaction};
-use solana_sdk::{
-    pubkey::Pubkey,
-    system_instruction,
-    transaction::Transaction,
-    message::Message,
+use crate::components::{AddressLabel, AmountField, FiatValue, OfflineTransaction, PubkeyField, TokenSelect};
+use crate::form::{any_dirty, parse_address_or_name, parse_amount, parse_token, reset_all, use_field, validate_all};
+use crate::hooks::{
+    update_address_book, use_address_book, use_i18n, use_settings, use_toasts, use_tx_queue, use_wallet, Wallet,
 };
+#[cfg(feature = "web")]
+use crate::solana::{rpc_client, send_with_wallet};
+use crate::route::Route;
+use crate::solana::RecipientWarning;
+use crate::solana_pay::{SolanaPayRequest, TransferRequest};
+use solana_sdk::{pubkey::Pubkey, transaction::Transaction};
 use std::str::FromStr;
 
 #[component]
 pub fn Home() -> Element {
     let wallet = use_wallet();
-    let mut recipient = use_signal(|| String::new());
-    let mut amount = use_signal(|| String::new());
-    let mut status_message = use_signal(|| String::new());
+    let i18n = use_i18n();
+    let recipient = use_field("", parse_address_or_name);
+    let amount = use_field("", parse_amount);
+    let toasts = use_toasts();
+    let tx_queue = use_tx_queue();
+    let settings = use_settings();
     let mut is_processing = use_signal(|| false);
+    // Announced to screen readers while a transfer is in progress.
+    let mut progress = use_signal(String::new);
+    let token_mint = use_field("", parse_token);
+    let mut memo = use_signal(|| String::new());
+    let mut references = use_signal(Vec::<Pubkey>::new);
+    let mut pay_link = use_signal(|| String::new());
+    let mut pay_note = use_signal(|| None::<String>);
+    let address_book = use_address_book();
+    let mut recipient_warnings = use_signal(Vec::<RecipientWarning>::new);
+    let mut warnings_acknowledged = use_signal(|| false);
+    let mut nonce_account = use_signal(|| String::new());
+    let mut sign_later = use_signal(|| false);
+    let mut signed_output = use_signal(|| None::<String>);
+    let mut offline_transaction = use_signal(|| None::<Transaction>);
 
-    let handle_transfer = move |_| {
+    // Warnings belong to the recipient they were raised for.
+    use_effect(move || {
+        recipient.raw();
+        recipient_warnings.set(Vec::new());
+        warnings_acknowledged.set(false);
+    });
+
+    // Prefill the form from a Solana Pay transfer request link; transaction
+    // requests are handed over to the Pay page.
+    let handle_pay_link = move |_| {
+        match SolanaPayRequest::parse(&pay_link.cloned()) {
+            Ok(SolanaPayRequest::Transaction(_)) => {
+                navigator().push(Route::Pay { link: pay_link.cloned() });
+            }
+            Ok(SolanaPayRequest::Transfer(request)) => {
+                recipient.set(request.recipient.to_string());
+                amount.set(request.amount.clone().unwrap_or_default());
+                token_mint.set(request.spl_token.map(|mint| mint.to_string()).unwrap_or_default());
+                memo.set(request.memo.clone().unwrap_or_default());
+                references.set(request.references.clone());
+                let note = [request.label.clone(), request.message.clone()]
+                    .into_iter()
+                    .flatten()
+                    .collect::<Vec<_>>()
+                    .join(" — ");
+                pay_note.set(Some(note).filter(|note| !note.is_empty()));
+                pay_link.set(String::new());
+            }
+            Err(e) => toasts.error(i18n.t_with("home.error.pay_link", &[("error", &e)])),
+        }
+    };
+
+    let mut run_transfer = move |export: bool| {
+        if !validate_all(&[recipient.state(), amount.state(), token_mint.state()]) {
+            return;
+        }
+        let (Ok(recipient_str), Ok(amount_str), Ok(spl_token)) = (recipient.value(), amount.value(), token_mint.value())
+        else {
+            return;
+        };
         let wallet_clone = wallet.cloned();
-        let recipient_str = recipient.cloned();
-        let amount_str = amount.cloned();
+        let memo_str = memo.cloned();
+        let references_list = references.cloned();
+        let nonce_str = nonce_account.cloned();
+        let sign_only = sign_later.cloned();
 
         spawn(async move {
             is_processing.set(true);
-            status_message.set("Processing transfer...".to_string());
+            signed_output.set(None);
+            offline_transaction.set(None);
 
             // Validate wallet is connected
             let sender_pubkey = match wallet_clone {
                 Wallet::Connected(pubkey) => pubkey,
                 Wallet::Disconnected => {
-                    status_message.set("Error: Please connect your wallet first".to_string());
+                    toasts.error(i18n.t("home.connect_wallet"));
                     is_processing.set(false);
                     return;
                 }
             };
 
-            // Validate recipient address
-            let recipient_pubkey = match Pubkey::from_str(&recipient_str) {
+            // Validate recipient address, resolving .sol names
+            let recipient_pubkey = match parse_recipient(&recipient_str).await {
                 Ok(pubkey) => pubkey,
-                Err(_) => {
-                    status_message.set("Error: Invalid recipient address".to_string());
+                Err(e) => {
+                    toasts.error(i18n.t_with("home.error.recipient", &[("error", &e)]));
                     is_processing.set(false);
                     return;
                 }
             };
 
-            // Parse amount
-            let lamports = match amount_str.parse::<f64>() {
-                Ok(sol) => (sol * 1_000_000_000.0) as u64,
-                Err(_) => {
-                    status_message.set("Error: Invalid amount".to_string());
+            let request = TransferRequest {
+                recipient: recipient_pubkey,
+                amount: Some(amount_str),
+                spl_token,
+                references: references_list,
+                memo: Some(memo_str.trim().to_string()).filter(|memo| !memo.is_empty()),
+                ..Default::default()
+            };
+
+            // Check SOL precision (token amounts are checked once the mint is known)
+            if spl_token.is_none() {
+                if let Err(e) = request.amount_base_units(None) {
+                    toasts.error(i18n.t_with("home.error.amount", &[("error", &e)]));
                     is_processing.set(false);
                     return;
                 }
-            };
-
-            if lamports == 0 {
-                status_message.set("Error: Amount must be greater than 0".to_string());
-                is_processing.set(false);
-                return;
             }
 
             #[cfg(feature = "web")]
             {
-                use solana_client_wasm::WasmClient;
-                use solana_extra_wasm::transaction_status::TransactionConfirmationStatus;
-
-                // Create the transfer instruction
-                let instruction = system_instruction::transfer(
-                    &sender_pubkey,
-                    &recipient_pubkey,
-                    lamports,
-                );
-
-                // Get recent blockhash from Solana
-                let client = WasmClient::new("https://api.mainnet-beta.solana.com");
-                
-                let blockhash = match client.get_latest_blockhash().await {
-                    Ok(hash) => hash,
-                    Err(e) => {
-                        status_message.set(format!("Error: Failed to get blockhash: {:?}", e));
-                        is_processing.set(false);
-                        return;
-                    }
+                use crate::solana::{
+                    build_nonce_transaction, build_transaction, check_recipient, encode_transaction,
+                    fetch_nonce_account, rpc_call, sign_with_wallet, submit_with_wallet,
                 };
+                use crate::components::shorten_address;
+                use crate::solana_pay::fetch_mint_info;
 
-                // Create transaction
-                let message = Message::new(&[instruction], Some(&sender_pubkey));
-                let mut transaction = Transaction::new_unsigned(message);
-                transaction.message.recent_blockhash = blockhash;
+                let client = rpc_client();
+                progress.set(i18n.t("home.progress.checking"));
 
-                // Serialize transaction to base64
-                let serialized = bincode::serialize(&transaction).unwrap();
-                let tx_base64 = base64::encode(&serialized);
+                // Risky recipients need an explicit acknowledgement
+                if !warnings_acknowledged.cloned() {
+                    match check_recipient(&client, &recipient_pubkey, &sender_pubkey).await {
+                        Ok(warnings) if !warnings.is_empty() => {
+                            toasts.warning(i18n.t_count("home.review_warnings", warnings.len() as u64, &[]));
+                            recipient_warnings.set(warnings);
+                            is_processing.set(false);
+                            return;
+                        }
+                        Ok(_) => {}
+                        Err(e) => {
+                            toasts.error(i18n.t_with("home.error.check_recipient", &[("error", &e)]));
+                            is_processing.set(false);
+                            return;
+                        }
+                    }
+                }
 
-                // Sign transaction via wallet adapter
-                let signed_tx_base64 = match sign_and_send_transaction(tx_base64).await {
-                    Ok(signed) => signed,
+                let mint = match fetch_mint_info(&client, &request).await {
+                    Ok(mint) => mint,
                     Err(e) => {
-                        status_message.set(format!("Error signing transaction: {}", e));
+                        toasts.error(i18n.t_with("home.error.mint", &[("error", &e)]));
                         is_processing.set(false);
                         return;
                     }
                 };
 
-                // Deserialize signed transaction
-                let signed_tx_bytes = match base64::decode(&signed_tx_base64) {
-                    Ok(bytes) => bytes,
+                // Create the transfer instructions
+                let instructions = match request.instructions(&sender_pubkey, mint) {
+                    Ok(instructions) => instructions,
                     Err(e) => {
-                        status_message.set(format!("Error decoding signed transaction: {}", e));
+                        toasts.error(i18n.t_with("home.error.instructions", &[("error", &e)]));
                         is_processing.set(false);
                         return;
                     }
                 };
 
-                let signed_transaction: Transaction = match bincode::deserialize(&signed_tx_bytes) {
-                    Ok(tx) => tx,
-                    Err(e) => {
-                        status_message.set(format!("Error deserializing transaction: {}", e));
-                        is_processing.set(false);
-                        return;
+                // Every transaction built here, sent or exported, carries the priority fee.
+                let priority_fee = settings.peek().priority_fee;
+
+                // A durable nonce keeps the transaction valid until the nonce is advanced
+                let nonce = match nonce_str.trim() {
+                    "" => None,
+                    address => {
+                        let loaded = match Pubkey::from_str(address) {
+                            Ok(address) => fetch_nonce_account(&client, &address).await,
+                            Err(_) => Err(i18n.t("home.invalid_nonce")),
+                        };
+                        match loaded {
+                            Ok(nonce) if nonce.authority == sender_pubkey => Some(nonce),
+                            Ok(_) => {
+                                toasts.error(i18n.t("home.not_nonce_authority"));
+                                is_processing.set(false);
+                                return;
+                            }
+                            Err(e) => {
+                                toasts.error(i18n.t_with("home.error.nonce", &[("error", &e)]));
+                                is_processing.set(false);
+                                return;
+                            }
+                        }
                     }
                 };
 
-                // Send transaction
-                match client.send_transaction(&signed_transaction).await {
+                // Sign now and hand the transaction back for later submission
+                if let (Some(nonce), true) = (&nonce, sign_only) {
+                    let transaction = build_nonce_transaction(&instructions, &sender_pubkey, nonce, priority_fee);
+                    progress.set(i18n.t("home.progress.signing"));
+                    match sign_with_wallet(&transaction).await.and_then(|signed| encode_transaction(&signed)) {
+                        Ok(encoded) => {
+                            signed_output.set(Some(encoded));
+                            toasts.success(i18n.t("home.signed_for_later"));
+                        }
+                        Err(e) => toasts.error(i18n.t_with("home.error.sign", &[("error", &e)])),
+                    }
+                    is_processing.set(false);
+                    return;
+                }
+
+                // Hand the unsigned transaction over for offline or multisig signing
+                if export {
+                    let transaction = match &nonce {
+                        Some(nonce) => build_nonce_transaction(&instructions, &sender_pubkey, nonce, priority_fee),
+                        None => match rpc_call("getLatestBlockhash", client.get_latest_blockhash()).await {
+                            Ok(blockhash) => {
+                                let mut transaction = build_transaction(&instructions, &sender_pubkey, priority_fee);
+                                transaction.message.recent_blockhash = blockhash;
+                                transaction
+                            }
+                            Err(e) => {
+                                let error = format!("{:?}", e);
+                                toasts.error(i18n.t_with("home.error.blockhash", &[("error", &error)]));
+                                is_processing.set(false);
+                                return;
+                            }
+                        },
+                    };
+                    if nonce.is_some() {
+                        toasts.info(i18n.t("home.exported"));
+                    } else {
+                        toasts.warning(i18n.t("home.exported_expiring"));
+                    }
+                    offline_transaction.set(Some(transaction));
+                    is_processing.set(false);
+                    return;
+                }
+
+                // Sign via wallet adapter and send
+                let tx_id = tx_queue.add(i18n.t_with(
+                    "home.queue_label",
+                    &[
+                        ("amount", &i18n.amount(&request.amount.clone().unwrap_or_default())),
+                        ("unit", &i18n.t(if spl_token.is_some() { "home.unit_tokens" } else { "home.unit_sol" })),
+                        ("recipient", &shorten_address(&recipient_pubkey.to_string())),
+                    ],
+                ));
+                tx_queue.awaiting_signature(tx_id);
+                progress.set(i18n.t("home.progress.signing"));
+                let sent = match &nonce {
+                    Some(nonce) => {
+                        let transaction = build_nonce_transaction(&instructions, &sender_pubkey, nonce, priority_fee);
+                        submit_with_wallet(&client, &transaction).await
+                    }
+                    None => send_with_wallet(&client, &sender_pubkey, &instructions).await,
+                };
+                match sent {
                     Ok(signature) => {
-                        status_message.set(format!("Success! Transaction signature: {}", signature));
-                        recipient.set(String::new());
-                        amount.set(String::new());
+                        // Remember the recipient once the transfer is confirmed.
+                        tx_queue.sent(tx_id, signature, move || {
+                            update_address_book(address_book, |book| book.record_recent(&recipient_pubkey))
+                        });
+
+                        reset_all(&[recipient.state(), amount.state(), token_mint.state()]);
+                        memo.set(String::new());
+                        references.set(Vec::new());
+                        pay_note.set(None);
                     }
                     Err(e) => {
-                        status_message.set(format!("Error sending transaction: {:?}", e));
+                        toasts.error(i18n.t_with("home.error.send", &[("error", &e)]));
+                        tx_queue.fail(tx_id, e);
                     }
                 }
             }
 
             #[cfg(not(feature = "web"))]
             {
-                status_message.set("Transfer functionality only available in web build".to_string());
+                let _ = (tx_queue, settings);
+                toasts.info(i18n.t("home.web_only"));
             }
 
             is_processing.set(false);
         });
     };
 
+    // Remount the offline panel for every newly exported transaction.
+    let offline_key = offline_transaction
+        .read()
+        .as_ref()
+        .map(|transaction| solana_sdk::hash::hash(&transaction.message_data()).to_string())
+        .unwrap_or_default();
+
+    let warning_texts: Vec<(String, String)> = recipient_warnings
+        .read()
+        .iter()
+        .map(|warning| {
+            let owner = match warning {
+                RecipientWarning::TokenAccount { owner } => owner.to_string(),
+                _ => String::new(),
+            };
+            (
+                i18n.t(&format!("recipient_warning.{}.title", warning.key())),
+                i18n.t_with(&format!("recipient_warning.{}.message", warning.key()), &[("owner", &owner)]),
+            )
+        })
+        .collect();
+    let amount_label = i18n.t(if token_mint.raw().trim().is_empty() { "home.amount_sol" } else { "home.amount_tokens" });
+    // Nothing to submit until something has been entered.
+    let pristine = !any_dirty(&[recipient.state(), amount.state(), token_mint.state()]);
+    let sol_amount = if token_mint.raw().trim().is_empty() {
+        amount.value().ok().and_then(|value| value.parse::<f64>().ok())
+    } else {
+        None
+    };
+
     rsx! {
         div {
             class: "max-w-2xl mx-auto",
@@ -153,38 +330,39 @@ pub fn Home() -> Element {
             div {
                 class: "text-center mb-12",
                 h1 {
-                    class: "text-5xl font-bold text-white mb-4",
-                    "SOL Transfer"
+                    class: "text-5xl font-bold text-fg mb-4",
+                    {i18n.t("home.title")}
                 }
                 p {
-                    class: "text-gray-300 text-lg",
-                    "Send SOL tokens to any Solana address"
+                    class: "text-fg-muted text-lg",
+                    {i18n.t("home.subtitle")}
                 }
             }
 
             // Transfer form
             div {
-                class: "bg-gray-800/50 backdrop-blur-md rounded-2xl p-8 border border-gray-700 shadow-2xl",
+                class: "bg-surface/50 backdrop-blur-md rounded-2xl p-8 border border-line shadow-2xl",
                 
                 // Wallet status indicator
                 div {
                     class: "mb-6 p-4 rounded-lg",
+                    role: "status",
                     class: if matches!(wallet.cloned(), Wallet::Connected(_)) {
-                        "bg-green-900/30 border border-green-500"
+                        "bg-success-surface border border-success"
                     } else {
-                        "bg-yellow-900/30 border border-yellow-500"
+                        "bg-warning-surface border border-warning"
                     },
                     p {
                         class: "text-center font-medium",
                         class: if matches!(wallet.cloned(), Wallet::Connected(_)) {
-                            "text-green-400"
+                            "text-success-fg"
                         } else {
-                            "text-yellow-400"
+                            "text-warning-fg"
                         },
                         if let Wallet::Connected(_) = wallet.cloned() {
-                            "✓ Wallet Connected"
+                            {i18n.t("home.wallet_connected")}
                         } else {
-                            "⚠ Please connect your wallet to continue"
+                            {i18n.t("home.wallet_disconnected")}
                         }
                     }
                 }
@@ -192,68 +370,218 @@ pub fn Home() -> Element {
                 // Form fields
                 div {
                     class: "space-y-6",
+
+                    // Solana Pay link
+                    div {
+                        label {
+                            class: "block text-fg-muted font-medium mb-2",
+                            r#for: "pay-link",
+                            {i18n.t("home.pay_link")}
+                        }
+                        div {
+                            class: "flex space-x-2",
+                            input {
+                                id: "pay-link",
+                                class: "w-full bg-surface-sunken/50 border border-line-strong rounded-lg px-4 py-3 text-fg placeholder-fg-faint focus:outline-none focus:border-purple-500 focus:ring-2 focus:ring-purple-500/50 transition-all",
+                                r#type: "text",
+                                placeholder: "solana:...",
+                                value: "{pay_link}",
+                                disabled: is_processing.cloned() || !matches!(wallet.cloned(), Wallet::Connected(_)),
+                                oninput: move |evt| pay_link.set(evt.value())
+                            }
+                            button {
+                                class: "bg-surface-raised hover:bg-surface-strong disabled:bg-surface-strong disabled:cursor-not-allowed text-fg font-medium px-4 rounded-lg transition-colors",
+                                disabled: is_processing.cloned() || pay_link.read().is_empty(),
+                                onclick: handle_pay_link,
+                                {i18n.t("home.apply")}
+                            }
+                        }
+                        if let Some(note) = pay_note.cloned() {
+                            p { class: "mt-2 text-sm text-purple-300", "{note}" }
+                        }
+                    }
                     
                     // Recipient address
+                    div {
+                        PubkeyField {
+                            field: recipient.state(),
+                            id: "recipient",
+                            label: i18n.t("home.recipient"),
+                            disabled: is_processing.cloned() || !matches!(wallet.cloned(), Wallet::Connected(_)),
+                        }
+                        if !address_book.read().recent.is_empty() {
+                            div {
+                                class: "mt-3 flex flex-wrap gap-2",
+                                span { class: "text-fg-subtle text-sm py-1", {i18n.t("home.recent")} }
+                                for address in address_book.read().recent.iter().cloned() {
+                                    button {
+                                        key: "{address}",
+                                        class: "bg-surface-raised hover:bg-surface-strong text-fg text-sm px-3 py-1 rounded-full transition-colors",
+                                        title: "{address}",
+                                        disabled: is_processing.cloned(),
+                                        onclick: {
+                                            let address = address.clone();
+                                            move |_| recipient.set(address.clone())
+                                        },
+                                        AddressLabel { address: address.clone() }
+                                    }
+                                }
+                            }
+                        }
+                    }
+
+                    // Recipient warnings
+                    if !recipient_warnings.read().is_empty() {
+                        div {
+                            class: "p-4 rounded-lg bg-warning-surface border border-warning space-y-3",
+                            for (title, message) in warning_texts {
+                                div {
+                                    p { class: "text-warning-fg font-medium", "⚠ {title}" }
+                                    p { class: "text-warning-fg text-sm break-words", "{message}" }
+                                }
+                            }
+                            label {
+                                class: "flex items-center space-x-2 text-warning-fg text-sm cursor-pointer",
+                                input {
+                                    r#type: "checkbox",
+                                    checked: warnings_acknowledged.cloned(),
+                                    disabled: is_processing.cloned(),
+                                    onchange: move |evt| warnings_acknowledged.set(evt.checked())
+                                }
+                                span { {i18n.t("home.acknowledge_warnings")} }
+                            }
+                        }
+                    }
+
+                    // Token
+                    TokenSelect {
+                        field: token_mint.state(),
+                        id: "token",
+                        label: i18n.t("home.token"),
+                        disabled: is_processing.cloned() || !matches!(wallet.cloned(), Wallet::Connected(_)),
+                    }
+
+                    // Amount
+                    AmountField {
+                        field: amount.state(),
+                        id: "amount",
+                        label: amount_label,
+                        disabled: is_processing.cloned() || !matches!(wallet.cloned(), Wallet::Connected(_)),
+                    }
+                    if let Some(sol) = sol_amount {
+                        FiatValue { sol }
+                    }
+
+                    // Memo
                     div {
                         label {
-                            class: "block text-gray-300 font-medium mb-2",
-                            "Recipient Address"
+                            class: "block text-fg-muted font-medium mb-2",
+                            r#for: "memo",
+                            {i18n.t("home.memo")}
                         }
                         input {
-                            class: "w-full bg-gray-900/50 border border-gray-600 rounded-lg px-4 py-3 text-white placeholder-gray-500 focus:outline-none focus:border-purple-500 focus:ring-2 focus:ring-purple-500/50 transition-all",
+                            id: "memo",
+                            class: "w-full bg-surface-sunken/50 border border-line-strong rounded-lg px-4 py-3 text-fg placeholder-fg-faint focus:outline-none focus:border-purple-500 focus:ring-2 focus:ring-purple-500/50 transition-all",
                             r#type: "text",
-                            placeholder: "Enter Solana address (e.g., 7xKX...)",
-                            value: "{recipient}",
+                            placeholder: i18n.t("home.memo_placeholder"),
+                            value: "{memo}",
                             disabled: is_processing.cloned() || !matches!(wallet.cloned(), Wallet::Connected(_)),
-                            oninput: move |evt| recipient.set(evt.value())
+                            oninput: move |evt| memo.set(evt.value())
                         }
                     }
 
-                    // Amount
+                    // Durable nonce
                     div {
                         label {
-                            class: "block text-gray-300 font-medium mb-2",
-                            "Amount (SOL)"
+                            class: "block text-fg-muted font-medium mb-2",
+                            r#for: "nonce-account",
+                            {i18n.t("home.nonce")}
                         }
                         input {
-                            class: "w-full bg-gray-900/50 border border-gray-600 rounded-lg px-4 py-3 text-white placeholder-gray-500 focus:outline-none focus:border-purple-500 focus:ring-2 focus:ring-purple-500/50 transition-all",
-                            r#type: "number",
-                            step: "0.000000001",
-                            min: "0",
-                            placeholder: "0.0",
-                            value: "{amount}",
+                            id: "nonce-account",
+                            class: "w-full bg-surface-sunken/50 border border-line-strong rounded-lg px-4 py-3 text-fg placeholder-fg-faint focus:outline-none focus:border-purple-500 focus:ring-2 focus:ring-purple-500/50 transition-all",
+                            r#type: "text",
+                            placeholder: i18n.t("home.nonce_placeholder"),
+                            value: "{nonce_account}",
                             disabled: is_processing.cloned() || !matches!(wallet.cloned(), Wallet::Connected(_)),
-                            oninput: move |evt| amount.set(evt.value())
+                            oninput: move |evt| nonce_account.set(evt.value())
+                        }
+                        label {
+                            class: "mt-2 flex items-center space-x-2 text-fg-muted text-sm cursor-pointer",
+                            input {
+                                r#type: "checkbox",
+                                checked: sign_later.cloned(),
+                                disabled: is_processing.cloned() || nonce_account.read().trim().is_empty(),
+                                onchange: move |evt| sign_later.set(evt.checked())
+                            }
+                            span { {i18n.t("home.sign_later")} }
                         }
                     }
 
                     // Transfer button
                     button {
-                        class: "w-full bg-purple-600 hover:bg-purple-700 disabled:bg-gray-600 disabled:cursor-not-allowed text-white font-bold py-4 rounded-lg transition-all duration-200 transform hover:scale-105 disabled:hover:scale-100",
-                        disabled: is_processing.cloned() || !matches!(wallet.cloned(), Wallet::Connected(_)),
-                        onclick: handle_transfer,
+                        class: "w-full bg-purple-600 hover:bg-purple-700 disabled:bg-surface-strong disabled:text-fg-subtle disabled:cursor-not-allowed text-white font-bold py-4 rounded-lg transition-all duration-200 transform hover:scale-105 disabled:hover:scale-100",
+                        disabled: is_processing.cloned()
+                            || pristine
+                            || !matches!(wallet.cloned(), Wallet::Connected(_))
+                            || (!recipient_warnings.read().is_empty() && !warnings_acknowledged.cloned()),
+                        aria_busy: is_processing.cloned(),
+                        onclick: move |_| run_transfer(false),
                         if is_processing.cloned() {
-                            "Processing..."
+                            {i18n.t("home.processing")}
+                        } else if sign_later.cloned() && !nonce_account.read().trim().is_empty() {
+                            {i18n.t("home.sign_transfer")}
                         } else {
-                            "Transfer SOL"
+                            {i18n.t("home.transfer")}
+                        }
+                    }
+
+                    p {
+                        class: "sr-only",
+                        role: "status",
+                        if is_processing.cloned() {
+                            "{progress}"
                         }
                     }
+
+                    // Offline signing export
+                    button {
+                        class: "w-full bg-surface-raised hover:bg-surface-strong disabled:bg-surface-strong disabled:cursor-not-allowed text-fg font-medium py-3 rounded-lg transition-colors",
+                        disabled: is_processing.cloned()
+                            || pristine
+                            || !matches!(wallet.cloned(), Wallet::Connected(_))
+                            || (!recipient_warnings.read().is_empty() && !warnings_acknowledged.cloned()),
+                        onclick: move |_| run_transfer(true),
+                        {i18n.t("home.export")}
+                    }
                 }
 
-                // Status message
-                if !status_message.cloned().is_empty() {
+                // Unsigned transaction for offline or multisig signing
+                if let Some(transaction) = offline_transaction.cloned() {
                     div {
-                        class: "mt-6 p-4 rounded-lg",
-                        class: if status_message.cloned().starts_with("Success") {
-                            "bg-green-900/30 border border-green-500 text-green-400"
-                        } else if status_message.cloned().starts_with("Error") {
-                            "bg-red-900/30 border border-red-500 text-red-400"
-                        } else {
-                            "bg-blue-900/30 border border-blue-500 text-blue-400"
-                        },
-                        p {
-                            class: "text-sm break-words",
-                            "{status_message}"
+                        class: "mt-4",
+                        OfflineTransaction {
+                            key: "{offline_key}",
+                            transaction: transaction.clone(),
+                        }
+                    }
+                }
+
+                // Signed transaction for later submission
+                if let Some(encoded) = signed_output.cloned() {
+                    div {
+                        class: "mt-4",
+                        label {
+                            class: "block text-fg-muted font-medium mb-2",
+                            r#for: "signed-transaction",
+                            {i18n.t("home.signed_transaction")}
+                        }
+                        textarea {
+                            id: "signed-transaction",
+                            class: "w-full bg-surface-sunken/50 border border-line-strong rounded-lg px-4 py-3 text-fg font-mono text-sm focus:outline-none",
+                            rows: "5",
+                            readonly: true,
+                            value: "{encoded}"
                         }
                     }
                 }
@@ -261,20 +589,36 @@ pub fn Home() -> Element {
 
             // Information section
             div {
-                class: "mt-8 bg-gray-800/30 rounded-lg p-6 border border-gray-700",
+                class: "mt-8 bg-surface/30 rounded-lg p-6 border border-line",
                 h3 {
-                    class: "text-white font-semibold text-lg mb-3",
-                    "ℹ️ How it works"
+                    class: "text-fg font-semibold text-lg mb-3",
+                    {i18n.t("home.how_it_works")}
                 }
                 ul {
-                    class: "text-gray-300 space-y-2 text-sm",
-                    li { "1. Connect your Solana wallet using the button in the navigation bar" }
-                    li { "2. Enter the recipient's Solana address" }
-                    li { "3. Specify the amount of SOL to transfer" }
-                    li { "4. Click 'Transfer SOL' and approve the transaction in your wallet" }
-                    li { "5. Wait for confirmation on the Solana network" }
+                    class: "text-fg-muted space-y-2 text-sm",
+                    li { {i18n.t("home.step_1")} }
+                    li { {i18n.t("home.step_2")} }
+                    li { {i18n.t("home.step_3")} }
+                    li { {i18n.t("home.step_4")} }
+                    li { {i18n.t("home.step_5")} }
                 }
             }
         }
     }
 }
+
+/// Recipient address from the form, which may be a `.sol` name.
+async fn parse_recipient(input: &str) -> Result<Pubkey, String> {
+    #[cfg(feature = "web")]
+    {
+        use crate::solana::resolve_address;
+
+        let client = rpc_client();
+        resolve_address(&client, input).await
+    }
+
+    #[cfg(not(feature = "web"))]
+    {
+        Pubkey::from_str(input.trim()).map_err(|_| "Invalid recipient address".to_string())
+    }
+}
@@ -1,7 +1,27 @@
 mod home;
 mod about;
 mod not_found;
+mod anchor;
+mod stake;
+mod receive;
+mod pay;
+mod batch;
+mod addresses;
+mod nonce;
+mod multisig;
+mod settings;
+mod debug;
 
 pub use home::*;
 pub use about::*;
 pub use not_found::*;
+pub use anchor::*;
+pub use stake::*;
+pub use receive::*;
+pub use pay::*;
+pub use batch::*;
+pub use addresses::*;
+pub use nonce::*;
+pub use multisig::*;
+pub use settings::*;
+pub use debug::*;
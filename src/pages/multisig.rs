@@ -0,0 +1,539 @@
+use dioxus::prelude::*;
+use crate::components::{shorten_address, AddressInput, AddressLabel, ExplorerLink};
+use crate::hooks::{use_toasts, use_tx_queue, use_wallet, Wallet};
+use crate::squads::{vault_address, Multisig as MultisigAccount, Proposal, ProposalStatus, PERMISSION_EXECUTE, PERMISSION_VOTE};
+use crate::solana::ExplorerTarget;
+use solana_sdk::pubkey::Pubkey;
+use std::str::FromStr;
+
+const INPUT_CLASS: &str = "w-full bg-surface-sunken/50 border border-line-strong rounded-lg px-4 py-3 text-fg placeholder-fg-faint focus:outline-none focus:border-purple-500 focus:ring-2 focus:ring-purple-500/50 transition-all";
+const BUTTON_CLASS: &str = "bg-purple-600 hover:bg-purple-700 disabled:bg-surface-strong disabled:text-fg-subtle disabled:cursor-not-allowed text-white font-medium px-4 py-2 rounded-lg transition-colors";
+
+/// How many of the most recent transactions to show proposals for.
+#[cfg(feature = "web")]
+const PROPOSAL_LIMIT: u64 = 20;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum ProposalAction {
+    Approve,
+    Reject,
+    Execute,
+}
+
+impl ProposalAction {
+    fn label(&self) -> &'static str {
+        match self {
+            ProposalAction::Approve => "Approve",
+            ProposalAction::Reject => "Reject",
+            ProposalAction::Execute => "Execute",
+        }
+    }
+}
+
+/// Squads v4 multisig: propose SOL or token transfers from a vault and vote
+/// on or execute pending proposals as a member.
+#[component]
+pub fn Multisig() -> Element {
+    let wallet = use_wallet();
+    let tx_queue = use_tx_queue();
+    let mut multisig_address = use_signal(|| String::new());
+    let mut vault_index = use_signal(|| 0u8);
+    let mut recipient = use_signal(|| String::new());
+    let mut amount = use_signal(|| String::new());
+    let mut token_mint = use_signal(|| String::new());
+    let mut memo = use_signal(|| String::new());
+    let mut refresh = use_signal(|| 0u32);
+    let toasts = use_toasts();
+    let mut is_processing = use_signal(|| false);
+
+    let multisig_key = Pubkey::from_str(multisig_address.read().trim()).ok();
+    let vault = multisig_key.map(|multisig| vault_address(&multisig, vault_index.cloned()));
+
+    let state = use_resource(move || async move {
+        let _ = refresh();
+        match Pubkey::from_str(multisig_address().trim()) {
+            Ok(multisig) => load_multisig(multisig).await.map(Some),
+            Err(_) => Ok(None),
+        }
+    });
+
+    let handle_propose = move |_| {
+        let wallet_clone = wallet.cloned();
+        let recipient_input = recipient.cloned().trim().to_string();
+        let amount_value = amount.cloned().trim().to_string();
+        let mint_value = token_mint.cloned().trim().to_string();
+        let memo_value = memo.cloned().trim().to_string();
+        let index = vault_index.cloned();
+
+        spawn(async move {
+            let creator = match wallet_clone {
+                Wallet::Connected(pubkey) => pubkey,
+                Wallet::Disconnected => {
+                    toasts.error("Please connect your wallet first");
+                    return;
+                }
+            };
+            let Some(multisig) = multisig_key else {
+                toasts.error("Invalid multisig address");
+                return;
+            };
+            let spl_token = match mint_value.as_str() {
+                "" => None,
+                value => match Pubkey::from_str(value) {
+                    Ok(mint) => Some(mint),
+                    Err(_) => {
+                        toasts.error("Invalid token mint address");
+                        return;
+                    }
+                },
+            };
+
+            is_processing.set(true);
+
+            #[cfg(feature = "web")]
+            {
+                use crate::solana::{resolve_address, rpc_client, send_with_wallet};
+                use crate::solana_pay::{fetch_mint_info, TransferRequest};
+                use crate::squads::{fetch_multisig, propose_vault_transaction, PERMISSION_INITIATE, PERMISSION_VOTE};
+
+                let client = rpc_client();
+                let label = format!("Propose transfer from multisig {}", shorten_address(&multisig.to_string()));
+                let tx_id = tx_queue.add(label);
+                let result = async {
+                    let account = fetch_multisig(&client, &multisig).await?;
+                    let member = account
+                        .member(&creator)
+                        .filter(|member| member.can(PERMISSION_INITIATE))
+                        .ok_or("The connected wallet cannot initiate proposals on this multisig")?;
+                    let request = TransferRequest {
+                        recipient: resolve_address(&client, &recipient_input).await?,
+                        amount: Some(amount_value),
+                        spl_token,
+                        ..Default::default()
+                    };
+                    let mint = fetch_mint_info(&client, &request).await?;
+                    let vault = vault_address(&multisig, index);
+                    let transfer = request.instructions(&vault, mint)?;
+                    let memo = (!memo_value.is_empty()).then_some(memo_value.as_str());
+                    let instructions = propose_vault_transaction(
+                        &multisig,
+                        &creator,
+                        account.transaction_index + 1,
+                        index,
+                        &transfer,
+                        memo,
+                        member.can(PERMISSION_VOTE),
+                    )?;
+                    tx_queue.awaiting_signature(tx_id);
+                    send_with_wallet(&client, &creator, &instructions).await
+                }
+                .await;
+
+                match result {
+                    Ok(signature) => {
+                        recipient.set(String::new());
+                        amount.set(String::new());
+                        memo.set(String::new());
+                        // The page may be gone by the time it confirms.
+                        tx_queue.sent(tx_id, signature, move || {
+                            if let Ok(mut refresh) = refresh.try_write() {
+                                *refresh += 1;
+                            }
+                        });
+                    }
+                    Err(e) => {
+                        tx_queue.fail(tx_id, e.clone());
+                        toasts.error(e);
+                    }
+                }
+            }
+
+            #[cfg(not(feature = "web"))]
+            {
+                let _ = (creator, multisig, spl_token, recipient_input, amount_value, memo_value, index, tx_queue);
+                toasts.info("Multisig proposals are only available in web build");
+            }
+
+            is_processing.set(false);
+        });
+    };
+
+    let mut run_action = move |transaction_index: u64, action: ProposalAction| {
+        let wallet_clone = wallet.cloned();
+
+        spawn(async move {
+            let member = match wallet_clone {
+                Wallet::Connected(pubkey) => pubkey,
+                Wallet::Disconnected => {
+                    toasts.error("Please connect your wallet first");
+                    return;
+                }
+            };
+            let Some(multisig) = multisig_key else {
+                return;
+            };
+
+            is_processing.set(true);
+
+            #[cfg(feature = "web")]
+            {
+                use crate::solana::{rpc_client, send_with_wallet};
+                use crate::squads::{fetch_vault_transaction, proposal_approve, proposal_reject, vault_transaction_execute};
+
+                let client = rpc_client();
+                let tx_id = tx_queue.add(format!("{} multisig transaction #{}", action.label(), transaction_index));
+                let result = async {
+                    let instruction = match action {
+                        ProposalAction::Approve => proposal_approve(&multisig, &member, transaction_index),
+                        ProposalAction::Reject => proposal_reject(&multisig, &member, transaction_index),
+                        ProposalAction::Execute => {
+                            let transaction = fetch_vault_transaction(&client, &multisig, transaction_index).await?;
+                            vault_transaction_execute(&multisig, &member, transaction_index, &transaction)?
+                        }
+                    };
+                    tx_queue.awaiting_signature(tx_id);
+                    send_with_wallet(&client, &member, &[instruction]).await
+                }
+                .await;
+
+                match result {
+                    Ok(signature) => {
+                        // The page may be gone by the time it confirms.
+                        tx_queue.sent(tx_id, signature, move || {
+                            if let Ok(mut refresh) = refresh.try_write() {
+                                *refresh += 1;
+                            }
+                        });
+                    }
+                    Err(e) => {
+                        tx_queue.fail(tx_id, e.clone());
+                        toasts.error(e);
+                    }
+                }
+            }
+
+            #[cfg(not(feature = "web"))]
+            {
+                let _ = (member, multisig, action, tx_queue);
+                toasts.info("Multisig proposals are only available in web build");
+            }
+
+            is_processing.set(false);
+        });
+    };
+
+    let member_key = match wallet.cloned() {
+        Wallet::Connected(pubkey) => Some(pubkey),
+        Wallet::Disconnected => None,
+    };
+    let disabled = is_processing.cloned() || member_key.is_none();
+
+    rsx! {
+        div {
+            class: "max-w-4xl mx-auto",
+
+            // Header
+            div {
+                class: "text-center mb-12",
+                h1 {
+                    class: "text-5xl font-bold text-fg mb-4",
+                    "Multisig"
+                }
+                p {
+                    class: "text-fg-muted text-lg",
+                    "Propose, approve and execute Squads vault transactions"
+                }
+            }
+
+            // Multisig selection and new proposal
+            div {
+                class: "bg-surface/50 backdrop-blur-md rounded-2xl p-8 border border-line shadow-2xl space-y-6",
+
+                div {
+                    class: "flex space-x-4",
+                    div {
+                        class: "flex-1",
+                        label { class: "block text-fg-muted font-medium mb-2", r#for: "multisig-address", "Multisig Address" }
+                        input {
+                            id: "multisig-address",
+                            class: "{INPUT_CLASS} font-mono",
+                            r#type: "text",
+                            placeholder: "Squads multisig account",
+                            value: "{multisig_address}",
+                            oninput: move |evt| multisig_address.set(evt.value())
+                        }
+                    }
+                    div {
+                        class: "w-28",
+                        label { class: "block text-fg-muted font-medium mb-2", r#for: "multisig-vault", "Vault" }
+                        input {
+                            id: "multisig-vault",
+                            class: INPUT_CLASS,
+                            r#type: "number",
+                            min: "0",
+                            max: "255",
+                            value: "{vault_index}",
+                            oninput: move |evt| vault_index.set(evt.value().parse().unwrap_or(0))
+                        }
+                    }
+                }
+
+                if let Some(vault) = vault {
+                    p {
+                        class: "text-fg-subtle text-sm break-all",
+                        "Vault address: "
+                        ExplorerLink {
+                            target: ExplorerTarget::Address(vault.to_string()),
+                            class: "font-mono text-fg-muted hover:text-purple-300",
+                            "{vault}"
+                        }
+                    }
+                }
+
+                h3 { class: "text-fg font-semibold text-lg", "Propose a transfer" }
+
+                div {
+                    label { class: "block text-fg-muted font-medium mb-2", r#for: "multisig-recipient", "Recipient" }
+                    AddressInput {
+                        id: "multisig-recipient",
+                        value: recipient.cloned(),
+                        disabled,
+                        oninput: move |value| recipient.set(value)
+                    }
+                }
+
+                div {
+                    class: "flex space-x-4",
+                    div {
+                        class: "flex-1",
+                        label { class: "block text-fg-muted font-medium mb-2", r#for: "multisig-amount", "Amount" }
+                        input {
+                            id: "multisig-amount",
+                            class: INPUT_CLASS,
+                            r#type: "number",
+                            step: "0.000000001",
+                            min: "0",
+                            placeholder: "0.0",
+                            value: "{amount}",
+                            disabled,
+                            oninput: move |evt| amount.set(evt.value())
+                        }
+                    }
+                    div {
+                        class: "flex-1",
+                        label { class: "block text-fg-muted font-medium mb-2", r#for: "multisig-mint", "Token Mint (empty for SOL)" }
+                        input {
+                            id: "multisig-mint",
+                            class: "{INPUT_CLASS} font-mono",
+                            r#type: "text",
+                            placeholder: "SPL token mint",
+                            value: "{token_mint}",
+                            disabled,
+                            oninput: move |evt| token_mint.set(evt.value())
+                        }
+                    }
+                }
+
+                div {
+                    label { class: "block text-fg-muted font-medium mb-2", r#for: "multisig-memo", "Memo (Optional)" }
+                    input {
+                        id: "multisig-memo",
+                        class: INPUT_CLASS,
+                        r#type: "text",
+                        placeholder: "What is this transfer for?",
+                        value: "{memo}",
+                        disabled,
+                        oninput: move |evt| memo.set(evt.value())
+                    }
+                }
+
+                button {
+                    class: "w-full bg-purple-600 hover:bg-purple-700 disabled:bg-surface-strong disabled:text-fg-subtle disabled:cursor-not-allowed text-white font-bold py-4 rounded-lg transition-all duration-200 transform hover:scale-105 disabled:hover:scale-100",
+                    disabled: disabled || multisig_key.is_none() || recipient.read().trim().is_empty() || amount.read().trim().is_empty(),
+                    onclick: handle_propose,
+                    if is_processing.cloned() {
+                        "Processing..."
+                    } else {
+                        "Create Proposal"
+                    }
+                }
+
+                if member_key.is_none() {
+                    p { class: "text-warning-fg text-center", "⚠ Please connect your wallet to continue" }
+                }
+            }
+
+            // Proposals
+            div {
+                class: "mt-8 bg-surface/50 backdrop-blur-md rounded-2xl p-8 border border-line shadow-2xl",
+                div {
+                    class: "flex items-center justify-between mb-4",
+                    h3 { class: "text-fg font-semibold text-lg", "Proposals" }
+                    button {
+                        class: BUTTON_CLASS,
+                        disabled: multisig_key.is_none(),
+                        onclick: move |_| refresh += 1,
+                        "Refresh"
+                    }
+                }
+
+                {match &*state.read_unchecked() {
+                    Some(Ok(None)) => rsx! {
+                        p { class: "text-fg-subtle", "Enter a multisig address to see its proposals" }
+                    },
+                    Some(Ok(Some((account, proposals)))) => {
+                        let member = member_key.and_then(|key| account.member(&key).copied());
+                        let member_count = account.members.len();
+                        rsx! {
+                            p {
+                                class: "text-fg-subtle text-sm mb-4",
+                                "Threshold {account.threshold} of {member_count} members"
+                                if account.time_lock > 0 {
+                                    ", {account.time_lock}s time lock"
+                                }
+                            }
+                            if member_key.is_some() && member.is_none() {
+                                p { class: "text-warning-fg text-sm mb-4", "⚠ The connected wallet is not a member of this multisig" }
+                            }
+                            if proposals.is_empty() {
+                                p { class: "text-fg-subtle", "No pending proposals" }
+                            }
+                            div {
+                                class: "space-y-3",
+                                for proposal in proposals.iter().cloned() {
+                                    ProposalRow {
+                                        key: "{proposal.address}",
+                                        proposal: proposal.clone(),
+                                        threshold: account.threshold,
+                                        time_lock: account.time_lock,
+                                        can_vote: member.is_some_and(|member| member.can(PERMISSION_VOTE))
+                                            && member_key.is_some_and(|key| !proposal.has_voted(&key)),
+                                        can_execute: member.is_some_and(|member| member.can(PERMISSION_EXECUTE)),
+                                        disabled: is_processing.cloned(),
+                                        onaction: move |action| run_action(proposal.transaction_index, action),
+                                    }
+                                }
+                            }
+                        }
+                    },
+                    Some(Err(e)) => rsx! {
+                        p { class: "text-danger-fg text-sm", "Error: {e}" }
+                    },
+                    None => rsx! {
+                        p { class: "text-fg-subtle", "Loading proposals..." }
+                    },
+                }}
+            }
+        }
+    }
+}
+
+#[component]
+fn ProposalRow(
+    proposal: Proposal,
+    threshold: u16,
+    time_lock: u32,
+    can_vote: bool,
+    can_execute: bool,
+    disabled: bool,
+    onaction: EventHandler<ProposalAction>,
+) -> Element {
+    let status = proposal.status;
+    let status_label = status.label();
+    let approvals = proposal.approved.len();
+    let rejections = proposal.rejected.len();
+    let now = (crate::telemetry::now_ms() / 1000.0) as i64;
+    let time_lock_left = proposal.executable_at(time_lock).map(|at| at - now);
+
+    rsx! {
+        div {
+            class: "bg-surface-sunken/50 rounded-lg border border-line p-4 space-y-3",
+            div {
+                class: "flex items-center justify-between",
+                div {
+                    p { class: "text-fg font-medium", "Transaction #{proposal.transaction_index}" }
+                    p { class: "text-fg-faint text-xs font-mono break-all", "{proposal.address}" }
+                }
+                div {
+                    class: "text-right ml-4 whitespace-nowrap",
+                    p {
+                        class: match status {
+                            ProposalStatus::Approved { .. } | ProposalStatus::Executed => "text-success-fg font-medium",
+                            ProposalStatus::Rejected | ProposalStatus::Cancelled => "text-danger-fg font-medium",
+                            _ => "text-info-fg font-medium",
+                        },
+                        "{status_label}"
+                    }
+                    p { class: "text-fg-subtle text-sm", "{approvals}/{threshold} approvals" }
+                    if rejections > 0 {
+                        p { class: "text-fg-faint text-xs", "{rejections} rejected" }
+                    }
+                }
+            }
+
+            if !proposal.approved.is_empty() {
+                div {
+                    class: "flex flex-wrap gap-2 text-xs text-fg-subtle",
+                    span { "Approved by:" }
+                    for approver in proposal.approved.iter() {
+                        span {
+                            key: "{approver}",
+                            class: "bg-surface rounded px-2 py-1",
+                            AddressLabel { address: approver.to_string() }
+                        }
+                    }
+                }
+            }
+
+            div {
+                class: "flex space-x-2",
+                if status == ProposalStatus::Active && can_vote {
+                    button {
+                        class: BUTTON_CLASS,
+                        disabled,
+                        onclick: move |_| onaction.call(ProposalAction::Approve),
+                        "Approve"
+                    }
+                    button {
+                        class: "bg-red-600 hover:bg-red-700 disabled:bg-surface-strong disabled:text-fg-subtle disabled:cursor-not-allowed text-white font-medium px-4 py-2 rounded-lg transition-colors",
+                        disabled,
+                        onclick: move |_| onaction.call(ProposalAction::Reject),
+                        "Reject"
+                    }
+                }
+                if let Some(left) = time_lock_left.filter(|_| can_execute) {
+                    if left <= 0 {
+                        button {
+                            class: BUTTON_CLASS,
+                            disabled,
+                            onclick: move |_| onaction.call(ProposalAction::Execute),
+                            "Execute"
+                        }
+                    } else {
+                        p { class: "text-fg-subtle text-sm self-center", "Executable in {left}s" }
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(feature = "web")]
+async fn load_multisig(address: Pubkey) -> Result<(MultisigAccount, Vec<Proposal>), String> {
+    use crate::solana::rpc_client;
+    use crate::squads::{fetch_multisig, fetch_proposals};
+
+    let client = rpc_client();
+    let multisig = fetch_multisig(&client, &address).await?;
+    let proposals = fetch_proposals(&client, &multisig, PROPOSAL_LIMIT)
+        .await?
+        .into_iter()
+        .filter(|proposal| proposal.status.is_pending())
+        .collect();
+    Ok((multisig, proposals))
+}
+
+#[cfg(not(feature = "web"))]
+async fn load_multisig(_address: Pubkey) -> Result<(MultisigAccount, Vec<Proposal>), String> {
+    Err("Multisig proposals are only available in web build".to_string())
+}
@@ -0,0 +1,461 @@
+use dioxus::prelude::*;
+use crate::components::{shorten_address, ExplorerLink};
+use crate::hooks::{use_toasts, use_tx_queue, use_wallet, Wallet};
+use crate::solana::{ExplorerTarget, NonceAccount, NonceAction};
+use solana_sdk::{
+    native_token::{lamports_to_sol, sol_to_lamports},
+    pubkey::Pubkey,
+};
+use std::rc::Rc;
+use std::str::FromStr;
+
+const INPUT_CLASS: &str = "w-full bg-surface-sunken/50 border border-line-strong rounded-lg px-4 py-3 text-fg placeholder-fg-faint focus:outline-none focus:border-purple-500 focus:ring-2 focus:ring-purple-500/50 transition-all";
+const BUTTON_CLASS: &str = "bg-purple-600 hover:bg-purple-700 disabled:bg-surface-strong disabled:text-fg-subtle disabled:cursor-not-allowed text-white font-medium px-4 py-2 rounded-lg transition-colors";
+
+/// Durable nonce accounts of the connected wallet, and submission of
+/// transactions that were signed earlier against one of them.
+#[component]
+pub fn Nonce() -> Element {
+    let wallet = use_wallet();
+    let tx_queue = use_tx_queue();
+    let mut refresh = use_signal(|| 0u32);
+    let mut amount = use_signal(|| String::new());
+    let mut signed_transaction = use_signal(|| String::new());
+    let mut selected_nonce = use_signal(|| None::<Pubkey>);
+    let toasts = use_toasts();
+    let mut is_processing = use_signal(|| false);
+
+    let nonces = use_resource(move || async move {
+        let _ = refresh();
+        let authority = match wallet() {
+            Wallet::Connected(pubkey) => pubkey,
+            Wallet::Disconnected => return Ok(Vec::new()),
+        };
+        load_nonces(authority).await
+    });
+
+    let mut run_action = move |action: NonceAction| {
+        let wallet_clone = wallet.cloned();
+
+        spawn(async move {
+            is_processing.set(true);
+
+            let authority = match wallet_clone {
+                Wallet::Connected(pubkey) => pubkey,
+                Wallet::Disconnected => {
+                    toasts.error("Please connect your wallet first");
+                    is_processing.set(false);
+                    return;
+                }
+            };
+
+            #[cfg(feature = "web")]
+            {
+                use crate::solana::{nonce_account_size, rpc_call, rpc_client, send_with_wallet_and_signers};
+                use solana_sdk::signature::{Keypair, Signer};
+
+                let client = rpc_client();
+                let mut action = action;
+
+                // An empty amount funds the new account with just the rent.
+                if let NonceAction::Create { lamports } = &mut action {
+                    let rent = match rpc_call(
+                        "getMinimumBalanceForRentExemption",
+                        client.get_minimum_balance_for_rent_exemption(nonce_account_size()),
+                    )
+                    .await
+                    {
+                        Ok(rent) => rent,
+                        Err(e) => {
+                            toasts.error(format!("Failed to get rent exemption: {:?}", e));
+                            is_processing.set(false);
+                            return;
+                        }
+                    };
+                    if *lamports == 0 {
+                        *lamports = rent;
+                    } else if *lamports < rent {
+                        toasts.error(format!(
+                            "A nonce account needs at least {} SOL to cover rent",
+                            lamports_to_sol(rent)
+                        ));
+                        is_processing.set(false);
+                        return;
+                    }
+                }
+
+                let new_account = action.needs_new_account().then(Keypair::new);
+                let new_pubkey = new_account.as_ref().map(|keypair| keypair.pubkey());
+                let instructions = match action.instructions(&authority, new_pubkey.as_ref()) {
+                    Ok(instructions) => instructions,
+                    Err(e) => {
+                        toasts.error(e);
+                        is_processing.set(false);
+                        return;
+                    }
+                };
+                let signers: Vec<&Keypair> = new_account.iter().collect();
+
+                let tx_id = tx_queue.add(action_label(&action));
+                tx_queue.awaiting_signature(tx_id);
+                match send_with_wallet_and_signers(&client, &authority, &instructions, &signers).await {
+                    Ok(signature) => {
+                        amount.set(String::new());
+                        // The page may be gone by the time it confirms.
+                        tx_queue.sent(tx_id, signature, move || {
+                            if let Ok(mut refresh) = refresh.try_write() {
+                                *refresh += 1;
+                            }
+                        });
+                    }
+                    Err(e) => {
+                        tx_queue.fail(tx_id, e.clone());
+                        toasts.error(e);
+                    }
+                }
+            }
+
+            #[cfg(not(feature = "web"))]
+            {
+                let _ = (authority, action, tx_queue);
+                toasts.info("Nonce accounts are only available in web build");
+            }
+
+            is_processing.set(false);
+        });
+    };
+
+    let handle_create = move |_| {
+        let lamports = match amount.cloned().trim() {
+            "" => 0,
+            value => match value.parse::<f64>() {
+                Ok(sol) if sol > 0.0 => sol_to_lamports(sol),
+                _ => {
+                    toasts.error("Invalid amount");
+                    return;
+                }
+            },
+        };
+        run_action(NonceAction::Create { lamports });
+    };
+
+    let handle_submit = move |_| {
+        let transaction_base64 = signed_transaction.cloned();
+
+        spawn(async move {
+            #[cfg(feature = "web")]
+            {
+                use crate::solana::{decode_transaction, rpc_client, send_signed_transaction};
+
+                is_processing.set(true);
+
+                let client = rpc_client();
+                let tx_id = tx_queue.add("Submit signed transaction");
+                let result = match decode_transaction(&transaction_base64) {
+                    Ok(transaction) => send_signed_transaction(&client, &transaction).await,
+                    Err(e) => Err(e),
+                };
+                match result {
+                    Ok(signature) => {
+                        signed_transaction.set(String::new());
+                        // The page may be gone by the time it confirms.
+                        tx_queue.sent(tx_id, signature, move || {
+                            if let Ok(mut refresh) = refresh.try_write() {
+                                *refresh += 1;
+                            }
+                        });
+                    }
+                    Err(e) => {
+                        tx_queue.fail(tx_id, e.clone());
+                        toasts.error(e);
+                    }
+                }
+                is_processing.set(false);
+            }
+
+            #[cfg(not(feature = "web"))]
+            {
+                let _ = (transaction_base64, tx_queue);
+                toasts.info("Nonce accounts are only available in web build");
+            }
+        });
+    };
+
+    let connected = matches!(wallet.cloned(), Wallet::Connected(_));
+    let disabled = is_processing.cloned() || !connected;
+
+    rsx! {
+        div {
+            class: "max-w-4xl mx-auto",
+
+            // Header
+            div {
+                class: "text-center mb-12",
+                h1 {
+                    class: "text-5xl font-bold text-fg mb-4",
+                    "Durable Nonces"
+                }
+                p {
+                    class: "text-fg-muted text-lg",
+                    "Sign transactions now and submit them whenever you're ready"
+                }
+            }
+
+            // Create
+            div {
+                class: "bg-surface/50 backdrop-blur-md rounded-2xl p-8 border border-line shadow-2xl space-y-6",
+                h3 { class: "text-fg font-semibold text-lg", "New nonce account" }
+                div {
+                    label {
+                        class: "block text-fg-muted font-medium mb-2",
+                        r#for: "nonce-funding",
+                        "Funding (SOL, leave empty for the rent-exempt minimum)"
+                    }
+                    input {
+                        id: "nonce-funding",
+                        class: INPUT_CLASS,
+                        r#type: "number",
+                        step: "0.000000001",
+                        min: "0",
+                        placeholder: "0.0",
+                        value: "{amount}",
+                        disabled,
+                        oninput: move |evt| amount.set(evt.value())
+                    }
+                }
+                button {
+                    class: "w-full bg-purple-600 hover:bg-purple-700 disabled:bg-surface-strong disabled:text-fg-subtle disabled:cursor-not-allowed text-white font-bold py-4 rounded-lg transition-all duration-200 transform hover:scale-105 disabled:hover:scale-100",
+                    disabled,
+                    onclick: handle_create,
+                    if is_processing.cloned() {
+                        "Processing..."
+                    } else {
+                        "Create Nonce Account"
+                    }
+                }
+            }
+
+            // Existing nonce accounts
+            div {
+                class: "mt-8 bg-surface/50 backdrop-blur-md rounded-2xl p-8 border border-line shadow-2xl",
+                h3 { class: "text-fg font-semibold text-lg mb-4", "Your nonce accounts" }
+
+                if !connected {
+                    p { class: "text-warning-fg", "⚠ Please connect your wallet to continue" }
+                } else {
+                    {match &*nonces.read_unchecked() {
+                        Some(Ok(list)) if list.is_empty() => rsx! {
+                            p { class: "text-fg-subtle", "No nonce accounts found" }
+                        },
+                        Some(Ok(list)) => rsx! {
+                            div {
+                                class: "space-y-3",
+                                for account in list.iter().cloned() {
+                                    NonceRow {
+                                        key: "{account.address}",
+                                        account: account.clone(),
+                                        selected: selected_nonce.cloned() == Some(account.address),
+                                        disabled,
+                                        onselect: move |address: Pubkey| {
+                                            if selected_nonce.cloned() == Some(address) {
+                                                selected_nonce.set(None);
+                                            } else {
+                                                selected_nonce.set(Some(address));
+                                            }
+                                        },
+                                        onaction: move |action| run_action(action),
+                                    }
+                                }
+                            }
+                        },
+                        Some(Err(e)) => rsx! {
+                            p { class: "text-danger-fg text-sm", "Error: {e}" }
+                        },
+                        None => rsx! {
+                            p { class: "text-fg-subtle", "Loading nonce accounts..." }
+                        },
+                    }}
+                }
+            }
+
+            // Submit a transaction signed earlier
+            div {
+                class: "mt-8 bg-surface/50 backdrop-blur-md rounded-2xl p-8 border border-line shadow-2xl space-y-4",
+                h3 { class: "text-fg font-semibold text-lg", "Submit a signed transaction" }
+                p {
+                    class: "text-fg-subtle text-sm",
+                    "Paste a transaction signed against a durable nonce. It stays valid until the nonce is advanced."
+                }
+                textarea {
+                    class: "{INPUT_CLASS} font-mono text-sm",
+                    aria_label: "Signed transaction",
+                    rows: "5",
+                    placeholder: "Base64-encoded signed transaction",
+                    value: "{signed_transaction}",
+                    disabled: is_processing.cloned(),
+                    oninput: move |evt| signed_transaction.set(evt.value())
+                }
+                button {
+                    class: BUTTON_CLASS,
+                    disabled: is_processing.cloned() || signed_transaction.read().trim().is_empty(),
+                    onclick: handle_submit,
+                    "Submit Transaction"
+                }
+            }
+        }
+    }
+}
+
+#[component]
+fn NonceRow(
+    account: NonceAccount,
+    selected: bool,
+    disabled: bool,
+    onselect: EventHandler<Pubkey>,
+    onaction: EventHandler<NonceAction>,
+) -> Element {
+    let wallet = use_wallet();
+    let mut new_authority = use_signal(|| String::new());
+    let mut withdraw_amount = use_signal(|| String::new());
+    let mut row_button = use_signal(|| None::<Rc<MountedData>>);
+
+    let address = account.address;
+    let balance = account.lamports;
+
+    // Escape collapses the actions and returns focus to the row.
+    let close = move || {
+        onselect.call(address);
+        if let Some(button) = row_button.cloned() {
+            spawn(async move {
+                let _ = button.set_focus(true).await;
+            });
+        }
+    };
+
+    rsx! {
+        div {
+            class: "bg-surface-sunken/50 rounded-lg border border-line",
+            onkeydown: move |evt| {
+                if evt.key() == Key::Escape && selected {
+                    close();
+                }
+            },
+            button {
+                class: "w-full flex items-center justify-between p-4 text-left",
+                aria_expanded: selected,
+                aria_controls: "nonce-{address}-actions",
+                onmounted: move |evt| row_button.set(Some(evt.data())),
+                onclick: move |_| onselect.call(address),
+                div {
+                    p { class: "text-fg font-mono text-sm break-all", "{address}" }
+                    p { class: "text-fg-faint text-xs break-all", "Nonce: {account.nonce}" }
+                }
+                div {
+                    class: "text-right ml-4 whitespace-nowrap",
+                    p { class: "text-fg font-medium", "{lamports_to_sol(balance)} SOL" }
+                }
+            }
+
+            if selected {
+                div {
+                    id: "nonce-{address}-actions",
+                    class: "border-t border-line p-4 space-y-4",
+
+                    ExplorerLink {
+                        target: ExplorerTarget::Address(address.to_string()),
+                        class: "block text-purple-400 hover:text-purple-300 text-sm underline",
+                        "View account on explorer"
+                    }
+
+                    button {
+                        class: BUTTON_CLASS,
+                        disabled,
+                        onclick: move |_| onaction.call(NonceAction::Advance { nonce: address }),
+                        "Advance Nonce"
+                    }
+
+                    div {
+                        class: "flex space-x-2",
+                        input {
+                            class: INPUT_CLASS,
+                            r#type: "text",
+                            aria_label: "New authority address",
+                            placeholder: "New authority address",
+                            value: "{new_authority}",
+                            disabled,
+                            oninput: move |evt| new_authority.set(evt.value())
+                        }
+                        button {
+                            class: BUTTON_CLASS,
+                            disabled,
+                            onclick: move |_| {
+                                if let Ok(authority) = Pubkey::from_str(new_authority.cloned().trim()) {
+                                    onaction.call(NonceAction::Authorize { nonce: address, new_authority: authority });
+                                }
+                            },
+                            "Authorize"
+                        }
+                    }
+
+                    div {
+                        class: "flex space-x-2",
+                        input {
+                            class: INPUT_CLASS,
+                            r#type: "number",
+                            step: "0.000000001",
+                            min: "0",
+                            aria_label: "Withdraw amount in SOL",
+                            placeholder: "Withdraw amount (max {lamports_to_sol(balance)} SOL)",
+                            value: "{withdraw_amount}",
+                            disabled,
+                            oninput: move |evt| withdraw_amount.set(evt.value())
+                        }
+                        button {
+                            class: BUTTON_CLASS,
+                            disabled,
+                            onclick: move |_| {
+                                let Wallet::Connected(to) = wallet.cloned() else {
+                                    return;
+                                };
+                                if let Ok(sol) = withdraw_amount.cloned().parse::<f64>() {
+                                    let lamports = sol_to_lamports(sol).min(balance);
+                                    if lamports > 0 {
+                                        onaction.call(NonceAction::Withdraw { nonce: address, to, lamports });
+                                    }
+                                }
+                            },
+                            "Withdraw"
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// What `action` does, for the transaction queue.
+fn action_label(action: &NonceAction) -> String {
+    let short = |address: &Pubkey| shorten_address(&address.to_string());
+    match action {
+        NonceAction::Create { lamports } => format!("Create nonce account with {} SOL", lamports_to_sol(*lamports)),
+        NonceAction::Advance { nonce } => format!("Advance nonce {}", short(nonce)),
+        NonceAction::Authorize { nonce, new_authority } => {
+            format!("Authorize {} on nonce {}", short(new_authority), short(nonce))
+        }
+        NonceAction::Withdraw { nonce, lamports, .. } => {
+            format!("Withdraw {} SOL from nonce {}", lamports_to_sol(*lamports), short(nonce))
+        }
+    }
+}
+
+#[cfg(feature = "web")]
+async fn load_nonces(authority: Pubkey) -> Result<Vec<NonceAccount>, String> {
+    use crate::solana::{fetch_nonce_accounts, rpc_client};
+
+    fetch_nonce_accounts(&rpc_client(), &authority).await
+}
+
+#[cfg(not(feature = "web"))]
+async fn load_nonces(_authority: Pubkey) -> Result<Vec<NonceAccount>, String> {
+    Err("Nonce accounts are only available in web build".to_string())
+}
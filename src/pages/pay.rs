@@ -0,0 +1,276 @@
+use dioxus::prelude::*;
+use crate::hooks::{use_toasts, use_tx_queue, use_wallet, Wallet};
+use crate::solana::format_ui_amount;
+use crate::solana_pay::{TokenBalanceChange, TransactionRequest, TransactionRequestMetadata, ValidatedTransaction};
+use solana_sdk::native_token::lamports_to_sol;
+
+const INPUT_CLASS: &str = "w-full bg-surface-sunken/50 border border-line-strong rounded-lg px-4 py-3 text-fg placeholder-fg-faint focus:outline-none focus:border-purple-500 focus:ring-2 focus:ring-purple-500/50 transition-all";
+
+/// Solana Pay transaction request flow: fetch the merchant's metadata, post
+/// the wallet address, validate the returned transaction, then sign and send.
+#[component]
+pub fn Pay(link: String) -> Element {
+    let wallet = use_wallet();
+    let tx_queue = use_tx_queue();
+    let mut link_input = use_signal(|| link.clone());
+    let mut request = use_signal(|| None::<TransactionRequest>);
+    let mut metadata = use_signal(|| None::<TransactionRequestMetadata>);
+    let mut merchant_message = use_signal(|| None::<String>);
+    let mut validated = use_signal(|| None::<ValidatedTransaction>);
+    let toasts = use_toasts();
+    let mut is_processing = use_signal(|| false);
+
+    let mut load_request = move |url: String| {
+        spawn(async move {
+            validated.set(None);
+            metadata.set(None);
+            merchant_message.set(None);
+
+            let parsed = match TransactionRequest::parse(&url) {
+                Ok(parsed) => parsed,
+                Err(e) => {
+                    toasts.error(e);
+                    return;
+                }
+            };
+            request.set(Some(parsed.clone()));
+
+            #[cfg(feature = "web")]
+            {
+                use crate::solana_pay::fetch_metadata;
+
+                is_processing.set(true);
+                match fetch_metadata(&parsed).await {
+                    Ok(loaded) => metadata.set(Some(loaded)),
+                    Err(e) => toasts.error(e),
+                }
+                is_processing.set(false);
+            }
+
+            #[cfg(not(feature = "web"))]
+            {
+                let _ = parsed;
+                toasts.info("Transaction requests are only available in web build");
+            }
+        });
+    };
+
+    // Load a link passed in the URL once on mount.
+    use_hook(move || {
+        if !link.is_empty() {
+            load_request(link.clone());
+        }
+    });
+
+    let handle_request_transaction = move |_| {
+        let wallet_clone = wallet.cloned();
+        let request_clone = request.cloned();
+
+        spawn(async move {
+            let account = match wallet_clone {
+                Wallet::Connected(pubkey) => pubkey,
+                Wallet::Disconnected => {
+                    toasts.error("Please connect your wallet first");
+                    return;
+                }
+            };
+            let Some(request_clone) = request_clone else {
+                return;
+            };
+
+            #[cfg(feature = "web")]
+            {
+                use crate::solana::rpc_client;
+                use crate::solana_pay::{fetch_transaction, validate_transaction};
+
+                is_processing.set(true);
+
+                let response = match fetch_transaction(&request_clone, &account).await {
+                    Ok(response) => response,
+                    Err(e) => {
+                        toasts.error(e);
+                        is_processing.set(false);
+                        return;
+                    }
+                };
+                merchant_message.set(response.message.clone());
+
+                let client = rpc_client();
+                match validate_transaction(&client, &response.transaction, &account).await {
+                    Ok(checked) => validated.set(Some(checked)),
+                    Err(e) => toasts.error(e),
+                }
+                is_processing.set(false);
+            }
+
+            #[cfg(not(feature = "web"))]
+            {
+                let _ = (account, request_clone);
+                toasts.info("Transaction requests are only available in web build");
+            }
+        });
+    };
+
+    let handle_approve = move |_| {
+        let Some(checked) = validated.cloned() else {
+            return;
+        };
+
+        spawn(async move {
+            #[cfg(feature = "web")]
+            {
+                use crate::solana::{rpc_client, submit_with_wallet};
+
+                is_processing.set(true);
+                let client = rpc_client();
+                let merchant = metadata.cloned().and_then(|metadata| metadata.label);
+                let tx_id = tx_queue.add(match merchant {
+                    Some(merchant) => format!("Pay {}", merchant),
+                    None => "Solana Pay payment".to_string(),
+                });
+                tx_queue.awaiting_signature(tx_id);
+                match submit_with_wallet(&client, &checked.transaction).await {
+                    Ok(signature) => {
+                        validated.set(None);
+                        tx_queue.sent(tx_id, signature, || {});
+                    }
+                    Err(e) => {
+                        tx_queue.fail(tx_id, e.clone());
+                        toasts.error(e);
+                    }
+                }
+                is_processing.set(false);
+            }
+
+            #[cfg(not(feature = "web"))]
+            {
+                let _ = (checked, tx_queue);
+                toasts.info("Transaction requests are only available in web build");
+            }
+        });
+    };
+
+    let connected = matches!(wallet.cloned(), Wallet::Connected(_));
+
+    rsx! {
+        div {
+            class: "max-w-2xl mx-auto",
+
+            // Header
+            div {
+                class: "text-center mb-12",
+                h1 {
+                    class: "text-5xl font-bold text-fg mb-4",
+                    "Pay"
+                }
+                p {
+                    class: "text-fg-muted text-lg",
+                    "Complete a Solana Pay transaction request"
+                }
+            }
+
+            div {
+                class: "bg-surface/50 backdrop-blur-md rounded-2xl p-8 border border-line shadow-2xl space-y-6",
+
+                div {
+                    label { class: "block text-fg-muted font-medium mb-2", r#for: "transaction-request-link", "Transaction Request Link" }
+                    div {
+                        class: "flex space-x-2",
+                        input {
+                            id: "transaction-request-link",
+                            class: INPUT_CLASS,
+                            r#type: "text",
+                            placeholder: "solana:https://...",
+                            value: "{link_input}",
+                            disabled: is_processing.cloned(),
+                            oninput: move |evt| link_input.set(evt.value())
+                        }
+                        button {
+                            class: "bg-surface-raised hover:bg-surface-strong disabled:bg-surface-strong disabled:cursor-not-allowed text-fg font-medium px-4 rounded-lg transition-colors",
+                            disabled: is_processing.cloned() || link_input.read().is_empty(),
+                            onclick: move |_| load_request(link_input.cloned()),
+                            "Load"
+                        }
+                    }
+                }
+
+                if let (Some(request), Some(metadata)) = (request.cloned(), metadata.cloned()) {
+                    div {
+                        class: "flex items-center space-x-4 p-4 rounded-lg bg-surface-sunken/50 border border-line",
+                        if let Some(icon) = metadata.icon.clone() {
+                            img { class: "w-12 h-12 rounded-lg", src: "{icon}", alt: "" }
+                        }
+                        div {
+                            p { class: "text-fg font-semibold", {metadata.label.clone().unwrap_or_else(|| "Unknown merchant".to_string())} }
+                            p { class: "text-fg-subtle text-sm", "{request.host()}" }
+                        }
+                    }
+
+                    if validated.read().is_none() {
+                        button {
+                            class: "w-full bg-purple-600 hover:bg-purple-700 disabled:bg-surface-strong disabled:text-fg-subtle disabled:cursor-not-allowed text-white font-bold py-4 rounded-lg transition-all duration-200 transform hover:scale-105 disabled:hover:scale-100",
+                            disabled: is_processing.cloned() || !connected,
+                            onclick: handle_request_transaction,
+                            if connected { "Request Transaction" } else { "Connect your wallet to continue" }
+                        }
+                    }
+                }
+
+                if let Some(checked) = validated.cloned() {
+                    div {
+                        class: "space-y-3 p-4 rounded-lg bg-surface-sunken/50 border border-line text-sm",
+                        if let Some(message) = merchant_message.cloned() {
+                            p { class: "text-fg", "{message}" }
+                        }
+                        p {
+                            class: "text-fg-muted",
+                            "Balance change: "
+                            span {
+                                class: "font-mono text-fg",
+                                {checked.balance_change.map(format_lamports_change).unwrap_or_else(|| "unknown".to_string())}
+                            }
+                        }
+                        for token in checked.token_changes.iter() {
+                            p {
+                                class: "text-fg-muted",
+                                "Token balance change: "
+                                span { class: "font-mono text-fg", {format_token_change(token)} }
+                                span { class: "font-mono text-fg-subtle break-all", " {token.mint}" }
+                            }
+                        }
+                        p { class: "text-fg-muted", "Instructions: {checked.transaction.message.instructions.len()}" }
+                        if !checked.cosigners.is_empty() {
+                            p { class: "text-fg-muted", "Also signed by: " }
+                            for cosigner in checked.cosigners.iter() {
+                                p { class: "font-mono text-fg-subtle break-all", "{cosigner}" }
+                            }
+                        }
+                    }
+
+                    button {
+                        class: "w-full bg-purple-600 hover:bg-purple-700 disabled:bg-surface-strong disabled:text-fg-subtle disabled:cursor-not-allowed text-white font-bold py-4 rounded-lg transition-all duration-200 transform hover:scale-105 disabled:hover:scale-100",
+                        disabled: is_processing.cloned() || !connected,
+                        onclick: handle_approve,
+                        if is_processing.cloned() { "Processing..." } else { "Approve and Pay" }
+                    }
+                }
+            }
+        }
+    }
+}
+
+fn format_lamports_change(change: i128) -> String {
+    let sign = if change < 0 { "-" } else { "+" };
+    format!("{}{} SOL", sign, lamports_to_sol(change.unsigned_abs() as u64))
+}
+
+fn format_token_change(token: &TokenBalanceChange) -> String {
+    let sign = if token.change < 0 { "-" } else { "+" };
+    let amount = u64::try_from(token.change.unsigned_abs())
+        .map_err(|_| "Amount is too large".to_string())
+        .and_then(|amount| format_ui_amount(amount, token.decimals));
+    match amount {
+        Ok(amount) => format!("{}{}", sign, amount),
+        Err(_) => format!("{}{} base units", sign, token.change.unsigned_abs()),
+    }
+}
@@ -0,0 +1,246 @@
+use dioxus::prelude::*;
+use crate::components::QrCode;
+use crate::hooks::{use_toasts, use_wallet, Wallet};
+use crate::solana_pay::TransferRequest;
+use solana_sdk::{
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+};
+use std::str::FromStr;
+
+const INPUT_CLASS: &str = "w-full bg-surface-sunken/50 border border-line-strong rounded-lg px-4 py-3 text-fg placeholder-fg-faint focus:outline-none focus:border-purple-500 focus:ring-2 focus:ring-purple-500/50 transition-all";
+
+/// How often the reference key is polled while waiting for a payment.
+#[cfg(feature = "web")]
+const POLL_INTERVAL_MS: u64 = 2_000;
+
+#[component]
+pub fn Receive() -> Element {
+    let wallet = use_wallet();
+    let mut recipient = use_signal(|| String::new());
+    let mut amount = use_signal(|| String::new());
+    let mut token_mint = use_signal(|| String::new());
+    let mut label = use_signal(|| String::new());
+    let mut message = use_signal(|| String::new());
+    let mut memo = use_signal(|| String::new());
+    let mut request = use_signal(|| None::<TransferRequest>);
+    let mut paid_signature = use_signal(|| None::<String>);
+    let toasts = use_toasts();
+
+    // Default the recipient to the connected wallet.
+    use_effect(move || {
+        if let Wallet::Connected(pubkey) = wallet() {
+            if recipient.peek().is_empty() {
+                recipient.set(pubkey.to_string());
+            }
+        }
+    });
+
+    // Poll the request's reference key until a transaction includes it.
+    let _watcher = use_resource(move || async move {
+        let Some(watched) = request.cloned() else {
+            return;
+        };
+        let Some(reference) = watched.references.first().copied() else {
+            return;
+        };
+
+        #[cfg(feature = "web")]
+        {
+            use crate::solana::rpc_client;
+            use crate::solana_pay::{find_reference, validate_transfer};
+
+            let client = rpc_client();
+            // Only new errors are shown, not one per poll.
+            let mut last_error = None;
+            loop {
+                match find_reference(&client, &reference).await {
+                    // The reference only says a transaction mentions this
+                    // request; check that it actually pays it.
+                    Ok(Some(signature)) => {
+                        match validate_transfer(&client, &signature, &watched).await {
+                            Ok(()) => {
+                                toasts.success("Payment received");
+                                paid_signature.set(Some(signature));
+                            }
+                            Err(e) => toasts.error(format!(
+                                "Transaction {} does not pay this request: {}",
+                                signature, e
+                            )),
+                        }
+                        return;
+                    }
+                    Ok(None) => {}
+                    Err(e) => {
+                        if last_error.as_ref() != Some(&e) {
+                            toasts.error(e.clone());
+                        }
+                        last_error = Some(e);
+                    }
+                }
+                gloo::timers::future::sleep(std::time::Duration::from_millis(POLL_INTERVAL_MS)).await;
+            }
+        }
+
+        #[cfg(not(feature = "web"))]
+        {
+            let _ = (watched, reference);
+        }
+    });
+
+    let handle_generate = move |_| {
+        let recipient_pubkey = match Pubkey::from_str(recipient.cloned().trim()) {
+            Ok(pubkey) => pubkey,
+            Err(_) => {
+                toasts.error("Invalid recipient address");
+                return;
+            }
+        };
+        let spl_token = match token_mint.cloned().trim() {
+            "" => None,
+            mint => match Pubkey::from_str(mint) {
+                Ok(mint) => Some(mint),
+                Err(_) => {
+                    toasts.error("Invalid token mint");
+                    return;
+                }
+            },
+        };
+        let non_empty = |value: String| Some(value.trim().to_string()).filter(|value| !value.is_empty());
+
+        let generated = TransferRequest {
+            recipient: recipient_pubkey,
+            amount: non_empty(amount.cloned()),
+            spl_token,
+            // A fresh reference key identifies the payment on chain.
+            references: vec![Keypair::new().pubkey()],
+            label: non_empty(label.cloned()),
+            message: non_empty(message.cloned()),
+            memo: non_empty(memo.cloned()),
+        };
+
+        // Round-trip through the parser so invalid amounts are caught here.
+        match TransferRequest::parse(&generated.to_url()) {
+            Ok(_) => {
+                paid_signature.set(None);
+                request.set(Some(generated));
+            }
+            Err(e) => toasts.error(e),
+        }
+    };
+
+    let url = request.read().as_ref().map(TransferRequest::to_url);
+
+    rsx! {
+        div {
+            class: "max-w-2xl mx-auto",
+
+            // Header
+            div {
+                class: "text-center mb-12",
+                h1 {
+                    class: "text-5xl font-bold text-fg mb-4",
+                    "Receive"
+                }
+                p {
+                    class: "text-fg-muted text-lg",
+                    "Create a Solana Pay link and QR code for a payment"
+                }
+            }
+
+            div {
+                class: "bg-surface/50 backdrop-blur-md rounded-2xl p-8 border border-line shadow-2xl space-y-6",
+
+                div {
+                    label { class: "block text-fg-muted font-medium mb-2", r#for: "receive-recipient", "Recipient Address" }
+                    input {
+                        id: "receive-recipient",
+                        class: INPUT_CLASS,
+                        r#type: "text",
+                        placeholder: "Enter Solana address (e.g., 7xKX...)",
+                        value: "{recipient}",
+                        oninput: move |evt| recipient.set(evt.value())
+                    }
+                }
+                div {
+                    label { class: "block text-fg-muted font-medium mb-2", r#for: "receive-amount", "Amount (optional)" }
+                    input {
+                        id: "receive-amount",
+                        class: INPUT_CLASS,
+                        r#type: "text",
+                        inputmode: "decimal",
+                        placeholder: "Leave empty to let the payer choose",
+                        value: "{amount}",
+                        oninput: move |evt| amount.set(evt.value())
+                    }
+                }
+                div {
+                    label { class: "block text-fg-muted font-medium mb-2", r#for: "receive-mint", "Token Mint (leave empty for SOL)" }
+                    input {
+                        id: "receive-mint",
+                        class: INPUT_CLASS,
+                        r#type: "text",
+                        placeholder: "SPL token mint address",
+                        value: "{token_mint}",
+                        oninput: move |evt| token_mint.set(evt.value())
+                    }
+                }
+                div {
+                    label { class: "block text-fg-muted font-medium mb-2", r#for: "receive-label", "Label (optional)" }
+                    input {
+                        id: "receive-label",
+                        class: INPUT_CLASS,
+                        r#type: "text",
+                        placeholder: "Shown to the payer, e.g. your store name",
+                        value: "{label}",
+                        oninput: move |evt| label.set(evt.value())
+                    }
+                }
+                div {
+                    label { class: "block text-fg-muted font-medium mb-2", r#for: "receive-message", "Message (optional)" }
+                    input {
+                        id: "receive-message",
+                        class: INPUT_CLASS,
+                        r#type: "text",
+                        placeholder: "Shown to the payer, e.g. order #123",
+                        value: "{message}",
+                        oninput: move |evt| message.set(evt.value())
+                    }
+                }
+                div {
+                    label { class: "block text-fg-muted font-medium mb-2", r#for: "receive-memo", "Memo (optional)" }
+                    input {
+                        id: "receive-memo",
+                        class: INPUT_CLASS,
+                        r#type: "text",
+                        placeholder: "Recorded on chain with the payment",
+                        value: "{memo}",
+                        oninput: move |evt| memo.set(evt.value())
+                    }
+                }
+
+                button {
+                    class: "w-full bg-purple-600 hover:bg-purple-700 text-white font-bold py-4 rounded-lg transition-all duration-200 transform hover:scale-105",
+                    onclick: handle_generate,
+                    "Create Payment Request"
+                }
+            }
+
+            if let Some(url) = url {
+                div {
+                    class: "mt-8 bg-surface/50 backdrop-blur-md rounded-2xl p-8 border border-line shadow-2xl text-center space-y-4",
+                    QrCode { data: url.clone() }
+                    p { class: "text-fg-muted text-sm font-mono break-all", "{url}" }
+                    match paid_signature.cloned() {
+                        Some(signature) => rsx! {
+                            p { class: "text-success-fg text-sm break-all", "Payment received: {signature}" }
+                        },
+                        None => rsx! {
+                            p { class: "text-info-fg text-sm", "Scan with a Solana Pay compatible wallet. This page updates once the payment lands." }
+                        },
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,318 @@
+use dioxus::prelude::*;
+use crate::hooks::{update_settings, use_i18n, use_settings, Commitment, DisplayCurrency, Language, SendStrategy, Theme};
+use crate::solana::{Cluster, Explorer, DEFAULT_RPC_URL};
+
+const INPUT_CLASS: &str = "w-full bg-surface-sunken/50 border border-line-strong rounded-lg px-4 py-3 text-fg placeholder-fg-faint focus:outline-none focus:border-purple-500 focus:ring-2 focus:ring-purple-500/50 transition-all";
+const SELECT_CLASS: &str = "w-full bg-surface-sunken/50 border border-line-strong rounded-lg px-4 py-3 text-fg focus:outline-none focus:border-purple-500 focus:ring-2 focus:ring-purple-500/50 transition-all";
+const LABEL_CLASS: &str = "block text-fg-muted font-medium mb-2";
+const HINT_CLASS: &str = "mt-2 text-sm text-fg-subtle";
+
+/// The trimmed URL, if it is http or https.
+fn parse_url(input: &str) -> Option<String> {
+    let url = input.trim();
+    (url.starts_with("https://") || url.starts_with("http://")).then(|| url.to_string())
+}
+
+/// App preferences, saved in local storage.
+#[component]
+pub fn Settings() -> Element {
+    let settings = use_settings();
+    let i18n = use_i18n();
+    let current = settings.cloned();
+    let mut rpc_draft = use_signal(|| current.rpc_url().to_string());
+    let mut rpc_error = use_signal(|| None::<String>);
+    let mut fee_draft = use_signal(|| current.priority_fee.to_string());
+    let mut fee_error = use_signal(|| None::<String>);
+    let mut telemetry_draft = use_signal(|| current.telemetry_url.clone());
+    let mut telemetry_error = use_signal(|| None::<String>);
+
+    let cluster = Cluster::from_rpc_url(current.rpc_url());
+    let custom_unsupported = matches!(cluster, Cluster::Custom(_)) && !current.explorer.supports_custom_cluster();
+    let rpc_changed = rpc_draft.read().trim() != current.rpc_url();
+    let is_default_rpc = current.rpc_url() == DEFAULT_RPC_URL;
+    let telemetry_changed = telemetry_draft.read().trim() != current.telemetry_url;
+
+    let handle_save_rpc = move |_| match parse_url(&rpc_draft.read()) {
+        Some(url) => {
+            rpc_error.set(None);
+            update_settings(settings, |settings| settings.rpc_url = url);
+        }
+        None => rpc_error.set(Some(i18n.t("settings.invalid_url"))),
+    };
+
+    let handle_save_telemetry = move |_| {
+        let draft = telemetry_draft.read().trim().to_string();
+        let parsed = if draft.is_empty() { Some(draft) } else { parse_url(&draft) };
+        match parsed {
+            Some(url) => {
+                telemetry_error.set(None);
+                update_settings(settings, |settings| settings.telemetry_url = url);
+            }
+            None => telemetry_error.set(Some(i18n.t("settings.invalid_url"))),
+        }
+    };
+
+    rsx! {
+        div {
+            class: "max-w-2xl mx-auto",
+
+            // Header
+            div {
+                class: "text-center mb-12",
+                h1 {
+                    class: "text-5xl font-bold text-fg mb-4",
+                    {i18n.t("settings.title")}
+                }
+                p {
+                    class: "text-fg-muted text-lg",
+                    {i18n.t("settings.subtitle")}
+                }
+            }
+
+            div {
+                class: "bg-surface/50 backdrop-blur-md rounded-2xl p-8 border border-line shadow-2xl space-y-6",
+
+                // Cluster
+                div {
+                    label { class: LABEL_CLASS, r#for: "rpc-url", {i18n.t("settings.rpc_url")} }
+                    div {
+                        class: "flex space-x-2",
+                        input {
+                            id: "rpc-url",
+                            class: INPUT_CLASS,
+                            r#type: "url",
+                            placeholder: DEFAULT_RPC_URL,
+                            value: rpc_draft.cloned(),
+                            oninput: move |evt| rpc_draft.set(evt.value()),
+                        }
+                        button {
+                            class: "px-4 py-3 bg-purple-600 hover:bg-purple-700 disabled:bg-surface-strong disabled:text-fg-subtle disabled:cursor-not-allowed text-white rounded-lg font-medium transition-colors",
+                            disabled: !rpc_changed,
+                            onclick: handle_save_rpc,
+                            {i18n.t("settings.save")}
+                        }
+                    }
+                    if let Some(error) = rpc_error.cloned() {
+                        p { class: "mt-2 text-sm text-danger-fg", "{error}" }
+                    }
+                    div {
+                        class: "flex items-center justify-between",
+                        p {
+                            class: HINT_CLASS,
+                            {i18n.t(&format!("settings.cluster.{}", cluster.name()))}
+                        }
+                        if !is_default_rpc {
+                            button {
+                                class: "mt-2 text-sm text-fg-subtle hover:text-fg underline",
+                                onclick: move |_| {
+                                    rpc_draft.set(DEFAULT_RPC_URL.to_string());
+                                    rpc_error.set(None);
+                                    update_settings(settings, |settings| settings.rpc_url = DEFAULT_RPC_URL.to_string());
+                                },
+                                {i18n.t("settings.use_default")}
+                            }
+                        }
+                    }
+                }
+
+                // Commitment
+                div {
+                    label { class: LABEL_CLASS, r#for: "commitment", {i18n.t("settings.commitment")} }
+                    select {
+                        id: "commitment",
+                        class: SELECT_CLASS,
+                        onchange: move |evt| {
+                            if let Some(selected) = Commitment::from_key(&evt.value()) {
+                                update_settings(settings, |settings| settings.commitment = selected);
+                            }
+                        },
+                        for option_commitment in Commitment::ALL {
+                            option {
+                                value: option_commitment.key(),
+                                selected: current.commitment == option_commitment,
+                                {i18n.t(&format!("commitment.{}", option_commitment.key()))}
+                            }
+                        }
+                    }
+                    p { class: HINT_CLASS, {i18n.t("settings.commitment_hint")} }
+                }
+
+                // Explorer
+                div {
+                    label { class: LABEL_CLASS, r#for: "explorer", {i18n.t("settings.explorer")} }
+                    select {
+                        id: "explorer",
+                        class: SELECT_CLASS,
+                        onchange: move |evt| {
+                            if let Some(selected) = Explorer::from_key(&evt.value()) {
+                                update_settings(settings, |settings| settings.explorer = selected);
+                            }
+                        },
+                        for option_explorer in Explorer::ALL {
+                            option {
+                                value: option_explorer.key(),
+                                selected: current.explorer == option_explorer,
+                                {option_explorer.label()}
+                            }
+                        }
+                    }
+                    p { class: HINT_CLASS, {i18n.t("settings.explorer_hint")} }
+                    if custom_unsupported {
+                        p {
+                            class: "mt-2 text-sm text-warning-fg",
+                            {i18n.t("settings.explorer_no_custom")}
+                        }
+                    }
+                }
+
+                // Priority fee
+                div {
+                    label { class: LABEL_CLASS, r#for: "priority-fee", {i18n.t("settings.priority_fee")} }
+                    input {
+                        id: "priority-fee",
+                        class: INPUT_CLASS,
+                        r#type: "text",
+                        inputmode: "numeric",
+                        placeholder: "0",
+                        value: fee_draft.cloned(),
+                        oninput: move |evt| {
+                            let value = evt.value();
+                            match value.trim().parse::<u64>() {
+                                Ok(fee) => {
+                                    fee_error.set(None);
+                                    update_settings(settings, |settings| settings.priority_fee = fee);
+                                }
+                                Err(_) if value.trim().is_empty() => {
+                                    fee_error.set(None);
+                                    update_settings(settings, |settings| settings.priority_fee = 0);
+                                }
+                                Err(_) => fee_error.set(Some(i18n.t("settings.priority_fee_invalid"))),
+                            }
+                            fee_draft.set(value);
+                        },
+                    }
+                    if let Some(error) = fee_error.cloned() {
+                        p { class: "mt-2 text-sm text-danger-fg", "{error}" }
+                    }
+                    p { class: HINT_CLASS, {i18n.t("settings.priority_fee_hint")} }
+                }
+
+                // Send strategy
+                div {
+                    label { class: LABEL_CLASS, r#for: "send-strategy", {i18n.t("settings.send_via")} }
+                    select {
+                        id: "send-strategy",
+                        aria_describedby: "send-strategy-hint",
+                        class: SELECT_CLASS,
+                        onchange: move |evt| {
+                            if let Some(selected) = SendStrategy::from_key(&evt.value()) {
+                                update_settings(settings, |settings| settings.send_strategy = selected);
+                            }
+                        },
+                        for option_strategy in SendStrategy::ALL {
+                            option {
+                                value: option_strategy.key(),
+                                selected: current.send_strategy == option_strategy,
+                                {i18n.t(&format!("settings.send_via.{}", option_strategy.key()))}
+                            }
+                        }
+                    }
+                    p { id: "send-strategy-hint", class: HINT_CLASS, {i18n.t("settings.send_via_hint")} }
+                }
+
+                // Display currency
+                div {
+                    label { class: LABEL_CLASS, r#for: "currency", {i18n.t("settings.currency")} }
+                    select {
+                        id: "currency",
+                        class: SELECT_CLASS,
+                        onchange: move |evt| {
+                            if let Some(selected) = DisplayCurrency::from_key(&evt.value()) {
+                                update_settings(settings, |settings| settings.currency = selected);
+                            }
+                        },
+                        for option_currency in DisplayCurrency::ALL {
+                            option {
+                                value: option_currency.key(),
+                                selected: current.currency == option_currency,
+                                {i18n.t(&format!("currency.{}", option_currency.key()))}
+                            }
+                        }
+                    }
+                    p { class: HINT_CLASS, {i18n.t("settings.currency_hint")} }
+                }
+
+                // Theme
+                div {
+                    label { class: LABEL_CLASS, r#for: "theme", {i18n.t("settings.theme")} }
+                    select {
+                        id: "theme",
+                        class: SELECT_CLASS,
+                        onchange: move |evt| {
+                            if let Some(selected) = Theme::from_key(&evt.value()) {
+                                update_settings(settings, |settings| settings.theme = selected);
+                            }
+                        },
+                        for option_theme in Theme::ALL {
+                            option {
+                                value: option_theme.key(),
+                                selected: current.theme == option_theme,
+                                {i18n.t(&format!("theme.{}", option_theme.key()))}
+                            }
+                        }
+                    }
+                }
+
+                // Language
+                div {
+                    label { class: LABEL_CLASS, r#for: "language", {i18n.t("settings.language")} }
+                    select {
+                        id: "language",
+                        class: SELECT_CLASS,
+                        onchange: move |evt| {
+                            if let Some(selected) = Language::from_key(&evt.value()) {
+                                update_settings(settings, |settings| settings.language = selected);
+                            }
+                        },
+                        for option_language in Language::ALL {
+                            option {
+                                value: option_language.key(),
+                                selected: current.language == option_language,
+                                {option_language.label()}
+                            }
+                        }
+                    }
+                }
+
+                // Telemetry
+                div {
+                    label { class: LABEL_CLASS, r#for: "telemetry-url", {i18n.t("settings.telemetry")} }
+                    div {
+                        class: "flex space-x-2",
+                        input {
+                            id: "telemetry-url",
+                            class: INPUT_CLASS,
+                            r#type: "url",
+                            placeholder: "https://collector.example.com/events",
+                            value: telemetry_draft.cloned(),
+                            oninput: move |evt| telemetry_draft.set(evt.value()),
+                        }
+                        button {
+                            class: "px-4 py-3 bg-purple-600 hover:bg-purple-700 disabled:bg-surface-strong disabled:text-fg-subtle disabled:cursor-not-allowed text-white rounded-lg font-medium transition-colors",
+                            disabled: !telemetry_changed,
+                            onclick: handle_save_telemetry,
+                            {i18n.t("settings.save")}
+                        }
+                    }
+                    if let Some(error) = telemetry_error.cloned() {
+                        p { class: "mt-2 text-sm text-danger-fg", "{error}" }
+                    }
+                    p {
+                        class: HINT_CLASS,
+                        {i18n.t("settings.telemetry_hint")}
+                    }
+                }
+            }
+        }
+    }
+}
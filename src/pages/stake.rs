@@ -0,0 +1,606 @@
+use dioxus::prelude::*;
+use crate::components::shorten_address;
+use crate::hooks::{use_toasts, use_tx_queue, use_wallet, Wallet};
+use crate::solana::{parse_ui_amount, StakeAccount, ValidatorInfo, LAMPORTS_DECIMALS};
+use solana_sdk::{
+    instruction::Instruction,
+    native_token::lamports_to_sol,
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+    stake::{
+        self,
+        state::{Authorized, Lockup, StakeStateV2},
+    },
+    system_instruction,
+};
+use std::rc::Rc;
+use std::str::FromStr;
+
+const INPUT_CLASS: &str = "w-full bg-surface-sunken/50 border border-line-strong rounded-lg px-4 py-3 text-fg placeholder-fg-faint focus:outline-none focus:border-purple-500 focus:ring-2 focus:ring-purple-500/50 transition-all";
+const BUTTON_CLASS: &str = "bg-purple-600 hover:bg-purple-700 disabled:bg-surface-strong disabled:text-fg-subtle disabled:cursor-not-allowed text-white font-medium px-4 py-2 rounded-lg transition-colors";
+
+#[derive(Clone, Debug, PartialEq)]
+enum StakeAction {
+    CreateAndDelegate { vote_account: Pubkey, lamports: u64 },
+    Deactivate { stake: Pubkey },
+    Withdraw { stake: Pubkey, lamports: u64 },
+    Split { source: StakeAccount, lamports: u64 },
+    Merge { destination: Pubkey, source: Pubkey },
+}
+
+impl StakeAction {
+    /// What the action does, for the transaction queue.
+    fn label(&self) -> String {
+        let short = |address: &Pubkey| shorten_address(&address.to_string());
+        match self {
+            StakeAction::CreateAndDelegate { vote_account, lamports } => {
+                format!("Stake {} SOL with {}", lamports_to_sol(*lamports), short(vote_account))
+            }
+            StakeAction::Deactivate { stake } => format!("Deactivate stake {}", short(stake)),
+            StakeAction::Withdraw { stake, lamports } => {
+                format!("Withdraw {} SOL from stake {}", lamports_to_sol(*lamports), short(stake))
+            }
+            StakeAction::Split { source, lamports } => {
+                format!("Split {} SOL from stake {}", lamports_to_sol(*lamports), short(&source.address))
+            }
+            StakeAction::Merge { destination, source } => {
+                format!("Merge stake {} into {}", short(source), short(destination))
+            }
+        }
+    }
+
+    fn needs_new_account(&self) -> bool {
+        matches!(self, StakeAction::CreateAndDelegate { .. } | StakeAction::Split { .. })
+    }
+
+    /// Instructions for this action with `owner` as staker and withdrawer.
+    /// `new_account` is the freshly generated stake account for create/split,
+    /// `rent` the rent-exempt minimum for a stake account.
+    fn instructions(
+        &self,
+        owner: &Pubkey,
+        new_account: Option<&Pubkey>,
+        rent: u64,
+    ) -> Result<Vec<Instruction>, String> {
+        let new_account = || new_account.ok_or_else(|| "A new stake account is required".to_string());
+        let instructions = match self {
+            StakeAction::CreateAndDelegate { vote_account, lamports } => {
+                stake::instruction::create_account_and_delegate_stake(
+                    owner,
+                    new_account()?,
+                    vote_account,
+                    &Authorized::auto(owner),
+                    &Lockup::default(),
+                    *lamports,
+                )
+            }
+            StakeAction::Deactivate { stake } => {
+                vec![stake::instruction::deactivate_stake(stake, owner)]
+            }
+            StakeAction::Withdraw { stake, lamports } => {
+                vec![stake::instruction::withdraw(stake, owner, owner, *lamports, None)]
+            }
+            StakeAction::Split { source, lamports } => {
+                // The split destination must already be rent exempt.
+                let split_stake = new_account()?;
+                let mut instructions = vec![system_instruction::transfer(owner, split_stake, rent)];
+                instructions.extend(stake::instruction::split(&source.address, owner, *lamports, split_stake));
+                instructions
+            }
+            StakeAction::Merge { destination, source } => {
+                stake::instruction::merge(destination, source, owner)
+            }
+        };
+        Ok(instructions)
+    }
+}
+
+#[component]
+pub fn Stake() -> Element {
+    let wallet = use_wallet();
+    let tx_queue = use_tx_queue();
+    let mut refresh = use_signal(|| 0u32);
+    let mut amount = use_signal(|| String::new());
+    let mut vote_account = use_signal(|| String::new());
+    let mut selected_stake = use_signal(|| None::<Pubkey>);
+    let toasts = use_toasts();
+    let mut is_processing = use_signal(|| false);
+
+    // Stake accounts of the connected wallet, with the current epoch.
+    let stakes = use_resource(move || async move {
+        let _ = refresh();
+        let owner = match wallet() {
+            Wallet::Connected(pubkey) => pubkey,
+            Wallet::Disconnected => return Ok((Vec::new(), 0)),
+        };
+        load_stakes(owner).await
+    });
+
+    let validators = use_resource(move || async move { load_validators().await });
+
+    let mut run_action = move |action: StakeAction| {
+        let wallet_clone = wallet.cloned();
+
+        spawn(async move {
+            is_processing.set(true);
+
+            let owner = match wallet_clone {
+                Wallet::Connected(pubkey) => pubkey,
+                Wallet::Disconnected => {
+                    toasts.error("Please connect your wallet first");
+                    is_processing.set(false);
+                    return;
+                }
+            };
+
+            #[cfg(feature = "web")]
+            {
+                use crate::solana::{rpc_call, rpc_client, send_with_wallet_and_signers};
+
+                let client = rpc_client();
+                let rent = match rpc_call(
+                    "getMinimumBalanceForRentExemption",
+                    client.get_minimum_balance_for_rent_exemption(StakeStateV2::size_of()),
+                )
+                .await
+                {
+                    Ok(rent) => rent,
+                    Err(e) => {
+                        toasts.error(format!("Failed to get rent exemption: {:?}", e));
+                        is_processing.set(false);
+                        return;
+                    }
+                };
+
+                // New stake must be delegable once the reserve is set aside.
+                let minimum_delegation = if action.needs_new_account() {
+                    match rpc_call("getStakeMinimumDelegation", client.get_stake_minimum_delegation()).await {
+                        Ok(minimum_delegation) => minimum_delegation,
+                        Err(e) => {
+                            toasts.error(format!("Failed to get the minimum delegation: {:?}", e));
+                            is_processing.set(false);
+                            return;
+                        }
+                    }
+                } else {
+                    0
+                };
+
+                let checked = match &action {
+                    StakeAction::CreateAndDelegate { lamports, .. } => {
+                        if lamports.saturating_sub(rent) < minimum_delegation.max(1) {
+                            Err(format!(
+                                "Stake must be at least {} SOL: the rent-exempt reserve plus the minimum delegation",
+                                lamports_to_sol(rent + minimum_delegation.max(1))
+                            ))
+                        } else {
+                            Ok(())
+                        }
+                    }
+                    StakeAction::Split { source, lamports } => source.check_split(*lamports, rent, minimum_delegation),
+                    _ => Ok(()),
+                };
+                if let Err(e) = checked {
+                    toasts.error(e);
+                    is_processing.set(false);
+                    return;
+                }
+
+                let new_account = action.needs_new_account().then(Keypair::new);
+                let new_pubkey = new_account.as_ref().map(|keypair| keypair.pubkey());
+                let instructions = match action.instructions(&owner, new_pubkey.as_ref(), rent) {
+                    Ok(instructions) => instructions,
+                    Err(e) => {
+                        toasts.error(e);
+                        is_processing.set(false);
+                        return;
+                    }
+                };
+                let signers: Vec<&Keypair> = new_account.iter().collect();
+
+                let tx_id = tx_queue.add(action.label());
+                tx_queue.awaiting_signature(tx_id);
+                match send_with_wallet_and_signers(&client, &owner, &instructions, &signers).await {
+                    Ok(signature) => {
+                        amount.set(String::new());
+                        selected_stake.set(None);
+                        // The page may be gone by the time it confirms.
+                        tx_queue.sent(tx_id, signature, move || {
+                            if let Ok(mut refresh) = refresh.try_write() {
+                                *refresh += 1;
+                            }
+                        });
+                    }
+                    Err(e) => {
+                        tx_queue.fail(tx_id, e.clone());
+                        toasts.error(e);
+                    }
+                }
+            }
+
+            #[cfg(not(feature = "web"))]
+            {
+                let _ = (owner, action, tx_queue);
+                toasts.info("Staking is only available in web build");
+            }
+
+            is_processing.set(false);
+        });
+    };
+
+    let handle_delegate = move |_| {
+        let vote_account = match Pubkey::from_str(&vote_account.cloned()) {
+            Ok(pubkey) => pubkey,
+            Err(_) => {
+                toasts.error("Select a validator");
+                return;
+            }
+        };
+        let lamports = match parse_sol(&amount.cloned()) {
+            Ok(lamports) => lamports,
+            Err(e) => {
+                toasts.error(e);
+                return;
+            }
+        };
+        run_action(StakeAction::CreateAndDelegate { vote_account, lamports });
+    };
+
+    let connected = matches!(wallet.cloned(), Wallet::Connected(_));
+    let disabled = is_processing.cloned() || !connected;
+
+    rsx! {
+        div {
+            class: "max-w-4xl mx-auto",
+
+            // Header
+            div {
+                class: "text-center mb-12",
+                h1 {
+                    class: "text-5xl font-bold text-fg mb-4",
+                    "Stake SOL"
+                }
+                p {
+                    class: "text-fg-muted text-lg",
+                    "Delegate to a validator and manage your stake accounts"
+                }
+            }
+
+            // Create and delegate
+            div {
+                class: "bg-surface/50 backdrop-blur-md rounded-2xl p-8 border border-line shadow-2xl space-y-6",
+                h3 { class: "text-fg font-semibold text-lg", "New stake" }
+
+                div {
+                    label {
+                        class: "block text-fg-muted font-medium mb-2",
+                        r#for: "stake-validator",
+                        "Validator"
+                    }
+                    {match &*validators.read_unchecked() {
+                        Some(Ok(list)) => rsx! {
+                            select {
+                                id: "stake-validator",
+                                class: INPUT_CLASS,
+                                value: "{vote_account}",
+                                disabled,
+                                onchange: move |evt| vote_account.set(evt.value()),
+                                option { value: "", "Select a validator" }
+                                for validator in list.iter() {
+                                    option {
+                                        value: "{validator.vote_account}",
+                                        {validator_label(validator)}
+                                    }
+                                }
+                            }
+                        },
+                        Some(Err(e)) => rsx! {
+                            p { class: "text-danger-fg text-sm", "Error: {e}" }
+                        },
+                        None => rsx! {
+                            p { class: "text-fg-subtle text-sm", "Loading validators..." }
+                        },
+                    }}
+                }
+
+                div {
+                    label {
+                        class: "block text-fg-muted font-medium mb-2",
+                        r#for: "stake-amount",
+                        "Amount (SOL)"
+                    }
+                    input {
+                        id: "stake-amount",
+                        class: INPUT_CLASS,
+                        r#type: "number",
+                        step: "0.000000001",
+                        min: "0",
+                        placeholder: "0.0",
+                        value: "{amount}",
+                        disabled,
+                        oninput: move |evt| amount.set(evt.value())
+                    }
+                }
+
+                button {
+                    class: "w-full bg-purple-600 hover:bg-purple-700 disabled:bg-surface-strong disabled:text-fg-subtle disabled:cursor-not-allowed text-white font-bold py-4 rounded-lg transition-all duration-200 transform hover:scale-105 disabled:hover:scale-100",
+                    disabled,
+                    onclick: handle_delegate,
+                    if is_processing.cloned() {
+                        "Processing..."
+                    } else {
+                        "Create and Delegate"
+                    }
+                }
+            }
+
+            // Existing stake accounts
+            div {
+                class: "mt-8 bg-surface/50 backdrop-blur-md rounded-2xl p-8 border border-line shadow-2xl",
+                h3 { class: "text-fg font-semibold text-lg mb-4", "Your stake accounts" }
+
+                if !connected {
+                    p { class: "text-warning-fg", "⚠ Please connect your wallet to continue" }
+                } else {
+                    {match &*stakes.read_unchecked() {
+                        Some(Ok((list, _))) if list.is_empty() => rsx! {
+                            p { class: "text-fg-subtle", "No stake accounts found" }
+                        },
+                        Some(Ok((list, epoch))) => rsx! {
+                            div {
+                                class: "space-y-3",
+                                for account in list.iter().cloned() {
+                                    StakeRow {
+                                        key: "{account.address}",
+                                        merge_candidates: list
+                                            .iter()
+                                            .filter(|other| account.is_mergeable_with(other, *epoch))
+                                            .map(|other| other.address)
+                                            .collect::<Vec<_>>(),
+                                        account: account.clone(),
+                                        epoch: *epoch,
+                                        selected: selected_stake.cloned() == Some(account.address),
+                                        disabled,
+                                        onselect: move |address: Pubkey| {
+                                            if selected_stake.cloned() == Some(address) {
+                                                selected_stake.set(None);
+                                            } else {
+                                                selected_stake.set(Some(address));
+                                            }
+                                        },
+                                        onaction: move |action| run_action(action),
+                                    }
+                                }
+                            }
+                        },
+                        Some(Err(e)) => rsx! {
+                            p { class: "text-danger-fg text-sm", "Error: {e}" }
+                        },
+                        None => rsx! {
+                            p { class: "text-fg-subtle", "Loading stake accounts..." }
+                        },
+                    }}
+                }
+            }
+        }
+    }
+}
+
+#[component]
+fn StakeRow(
+    account: StakeAccount,
+    /// Accounts that can be merged into this one.
+    merge_candidates: Vec<Pubkey>,
+    epoch: u64,
+    selected: bool,
+    disabled: bool,
+    onselect: EventHandler<Pubkey>,
+    onaction: EventHandler<StakeAction>,
+) -> Element {
+    let mut withdraw_amount = use_signal(|| String::new());
+    let mut split_amount = use_signal(|| String::new());
+    let mut merge_source = use_signal(|| String::new());
+    let toasts = use_toasts();
+    let mut row_button = use_signal(|| None::<Rc<MountedData>>);
+
+    let address = account.address;
+    let split_source = account.clone();
+    let status = account.status(epoch);
+    let withdrawable = account.withdrawable(epoch);
+    let voter = account
+        .delegated_vote_account()
+        .map(|vote| vote.to_string())
+        .unwrap_or_else(|| "—".to_string());
+
+    // Escape collapses the actions and returns focus to the row.
+    let close = move || {
+        onselect.call(address);
+        if let Some(button) = row_button.cloned() {
+            spawn(async move {
+                let _ = button.set_focus(true).await;
+            });
+        }
+    };
+
+    rsx! {
+        div {
+            class: "bg-surface-sunken/50 rounded-lg border border-line",
+            onkeydown: move |evt| {
+                if evt.key() == Key::Escape && selected {
+                    close();
+                }
+            },
+            button {
+                class: "w-full flex items-center justify-between p-4 text-left",
+                aria_expanded: selected,
+                aria_controls: "stake-{address}-actions",
+                onmounted: move |evt| row_button.set(Some(evt.data())),
+                onclick: move |_| onselect.call(address),
+                div {
+                    p { class: "text-fg font-mono text-sm break-all", "{address}" }
+                    p { class: "text-fg-faint text-xs break-all", "Validator: {voter}" }
+                }
+                div {
+                    class: "text-right ml-4 whitespace-nowrap",
+                    p { class: "text-fg font-medium", "{lamports_to_sol(account.lamports)} SOL" }
+                    p { class: "text-purple-400 text-xs", "{status.label()}" }
+                }
+            }
+
+            if selected {
+                div {
+                    id: "stake-{address}-actions",
+                    class: "border-t border-line p-4 space-y-4",
+
+                    if account.state.delegation().is_some() && account.state.delegation().map(|d| d.deactivation_epoch) == Some(u64::MAX) {
+                        button {
+                            class: BUTTON_CLASS,
+                            disabled,
+                            onclick: move |_| onaction.call(StakeAction::Deactivate { stake: address }),
+                            "Deactivate"
+                        }
+                    }
+
+                    div {
+                        class: "flex space-x-2",
+                        input {
+                            class: INPUT_CLASS,
+                            aria_label: "Withdraw amount in SOL",
+                            r#type: "number",
+                            step: "0.000000001",
+                            min: "0",
+                            placeholder: "Withdraw amount (max {lamports_to_sol(withdrawable)} SOL)",
+                            value: "{withdraw_amount}",
+                            disabled: disabled || withdrawable == 0,
+                            oninput: move |evt| withdraw_amount.set(evt.value())
+                        }
+                        button {
+                            class: BUTTON_CLASS,
+                            disabled: disabled || withdrawable == 0,
+                            onclick: move |_| {
+                                match parse_sol(&withdraw_amount.cloned()) {
+                                    Ok(lamports) if lamports > withdrawable => toasts.error(format!(
+                                        "You can withdraw at most {} SOL",
+                                        lamports_to_sol(withdrawable)
+                                    )),
+                                    Ok(lamports) => onaction.call(StakeAction::Withdraw { stake: address, lamports }),
+                                    Err(e) => toasts.error(e),
+                                }
+                            },
+                            "Withdraw"
+                        }
+                    }
+
+                    div {
+                        class: "flex space-x-2",
+                        input {
+                            class: INPUT_CLASS,
+                            r#type: "number",
+                            step: "0.000000001",
+                            min: "0",
+                            aria_label: "Split amount in SOL",
+                            placeholder: "Split amount (SOL)",
+                            value: "{split_amount}",
+                            disabled,
+                            oninput: move |evt| split_amount.set(evt.value())
+                        }
+                        button {
+                            class: BUTTON_CLASS,
+                            disabled,
+                            onclick: move |_| {
+                                match parse_sol(&split_amount.cloned()) {
+                                    Ok(lamports) => onaction.call(StakeAction::Split {
+                                        source: split_source.clone(),
+                                        lamports,
+                                    }),
+                                    Err(e) => toasts.error(e),
+                                }
+                            },
+                            "Split"
+                        }
+                    }
+
+                    if !merge_candidates.is_empty() {
+                        div {
+                            class: "flex space-x-2",
+                            select {
+                                class: INPUT_CLASS,
+                                aria_label: "Stake account to merge",
+                                value: "{merge_source}",
+                                disabled,
+                                onchange: move |evt| merge_source.set(evt.value()),
+                                option { value: "", "Merge another account into this one" }
+                                for candidate in merge_candidates.iter() {
+                                    option { value: "{candidate}", "{candidate}" }
+                                }
+                            }
+                            button {
+                                class: BUTTON_CLASS,
+                                disabled,
+                                onclick: move |_| match Pubkey::from_str(&merge_source.cloned()) {
+                                    Ok(source) => onaction.call(StakeAction::Merge { destination: address, source }),
+                                    Err(_) => toasts.error("Select a stake account to merge"),
+                                },
+                                "Merge"
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// A positive SOL amount entered in a form, in lamports.
+fn parse_sol(amount: &str) -> Result<u64, String> {
+    match parse_ui_amount(amount, LAMPORTS_DECIMALS)? {
+        0 => Err("Amount must be greater than 0".to_string()),
+        lamports => Ok(lamports),
+    }
+}
+
+fn validator_label(validator: &ValidatorInfo) -> String {
+    let vote = validator.vote_account.to_string();
+    let skip_rate = validator
+        .skip_rate
+        .map(|rate| format!("{:.1}% skip", rate * 100.0))
+        .unwrap_or_else(|| "skip rate n/a".to_string());
+    format!(
+        "{}...{} — {}% commission, {}, {:.0} SOL staked",
+        &vote[..4],
+        &vote[vote.len() - 4..],
+        validator.commission,
+        skip_rate,
+        lamports_to_sol(validator.activated_stake)
+    )
+}
+
+#[cfg(feature = "web")]
+async fn load_stakes(owner: Pubkey) -> Result<(Vec<StakeAccount>, u64), String> {
+    use crate::solana::{fetch_stake_accounts, rpc_call, rpc_client};
+
+    let client = rpc_client();
+    let epoch = rpc_call("getEpochInfo", client.get_epoch_info())
+        .await
+        .map_err(|e| format!("Failed to get epoch info: {:?}", e))?
+        .epoch;
+    let stakes = fetch_stake_accounts(&client, &owner).await?;
+    Ok((stakes, epoch))
+}
+
+#[cfg(not(feature = "web"))]
+async fn load_stakes(_owner: Pubkey) -> Result<(Vec<StakeAccount>, u64), String> {
+    Err("Staking is only available in web build".to_string())
+}
+
+#[cfg(feature = "web")]
+async fn load_validators() -> Result<Vec<ValidatorInfo>, String> {
+    use crate::solana::{fetch_validators, rpc_client};
+
+    fetch_validators(&rpc_client()).await
+}
+
+#[cfg(not(feature = "web"))]
+async fn load_validators() -> Result<Vec<ValidatorInfo>, String> {
+    Err("Staking is only available in web build".to_string())
+}
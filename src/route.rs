@@ -11,6 +11,26 @@ pub enum Route {
         Home {},
         #[route("/about")]
         About {},
+        #[route("/anchor")]
+        Anchor {},
+        #[route("/stake")]
+        Stake {},
+        #[route("/receive")]
+        Receive {},
+        #[route("/pay?:link")]
+        Pay { link: String },
+        #[route("/batch")]
+        Batch {},
+        #[route("/addresses")]
+        Addresses {},
+        #[route("/nonce")]
+        Nonce {},
+        #[route("/multisig")]
+        Multisig {},
+        #[route("/settings")]
+        Settings {},
+        #[route("/debug?:enable")]
+        Debug { enable: String },
     #[end_layout]
 
     #[route("/:.._route")]
@@ -0,0 +1,45 @@
+#[cfg(feature = "web")]
+use solana_client_wasm::{
+    utils::{
+        rpc_config::{RpcAccountInfoConfig, RpcProgramAccountsConfig},
+        rpc_filter::{Memcmp, MemcmpEncodedBytes, RpcFilterType},
+    },
+    WasmClient,
+};
+#[cfg(feature = "web")]
+use solana_extra_wasm::account_decoder::UiAccountEncoding;
+#[cfg(feature = "web")]
+use solana_sdk::{account::Account, pubkey::Pubkey};
+
+/// Filter matching accounts whose data contains `bytes` at `offset`.
+#[cfg(feature = "web")]
+pub fn memcmp(offset: usize, bytes: &[u8]) -> RpcFilterType {
+    RpcFilterType::Memcmp(Memcmp {
+        offset,
+        bytes: MemcmpEncodedBytes::Base58(bs58::encode(bytes).into_string()),
+        encoding: None,
+    })
+}
+
+/// `getProgramAccounts` with base64-encoded data and the given filters.
+#[cfg(feature = "web")]
+pub async fn get_program_accounts(
+    client: &WasmClient,
+    program_id: &Pubkey,
+    filters: Vec<RpcFilterType>,
+) -> Result<Vec<(Pubkey, Account)>, String> {
+    use super::rpc_call;
+
+    let config = RpcProgramAccountsConfig {
+        filters: Some(filters),
+        account_config: RpcAccountInfoConfig {
+            encoding: Some(UiAccountEncoding::Base64),
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    rpc_call("getProgramAccounts", client.get_program_accounts_with_config(program_id, config))
+        .await
+        .map_err(|e| format!("Failed to fetch program accounts: {:?}", e))
+}
@@ -0,0 +1,228 @@
+use solana_sdk::{
+    instruction::Instruction,
+    packet::PACKET_DATA_SIZE,
+    pubkey::Pubkey,
+};
+use std::str::FromStr;
+
+use super::build_transaction;
+
+/// One line of a payout CSV: `address,amount[,token]`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct PayoutRow {
+    /// 1-based line number in the uploaded file.
+    pub line: usize,
+    pub recipient: Pubkey,
+    /// Decimal amount in SOL or whole tokens.
+    pub amount: String,
+    /// SPL token mint; `None` pays SOL.
+    pub token: Option<Pubkey>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct PayoutRowError {
+    pub line: usize,
+    pub message: String,
+}
+
+/// Rows whose instructions fit together in a single transaction.
+#[derive(Clone, Debug, PartialEq)]
+pub struct PayoutBatch {
+    /// Indices into the row list this batch pays.
+    pub rows: Vec<usize>,
+    pub instructions: Vec<Instruction>,
+}
+
+/// Parses a payout CSV. A header row and blank lines are skipped; every
+/// other line either yields a row or an error, so all problems can be shown
+/// at once.
+pub fn parse_payouts_csv(text: &str) -> (Vec<PayoutRow>, Vec<PayoutRowError>) {
+    let mut rows = Vec::new();
+    let mut errors = Vec::new();
+
+    for (index, raw) in text.lines().enumerate() {
+        let line = index + 1;
+        let raw = raw.trim();
+        if raw.is_empty() || raw.starts_with('#') {
+            continue;
+        }
+
+        let fields: Vec<&str> = raw
+            .split(',')
+            .map(|field| field.trim().trim_matches('"').trim())
+            .collect();
+
+        // Header row
+        if line == 1 && fields.first().is_some_and(|f| f.eq_ignore_ascii_case("address")) {
+            continue;
+        }
+
+        let mut error = |message: &str| {
+            errors.push(PayoutRowError {
+                line,
+                message: message.to_string(),
+            })
+        };
+
+        let (address, amount, token) = match fields.as_slice() {
+            [address, amount] => (*address, *amount, ""),
+            [address, amount, token] => (*address, *amount, *token),
+            _ => {
+                error("Expected address,amount[,token]");
+                continue;
+            }
+        };
+
+        let Ok(recipient) = Pubkey::from_str(address) else {
+            error("Invalid address");
+            continue;
+        };
+        let token = match token {
+            "" | "SOL" | "sol" => None,
+            mint => match Pubkey::from_str(mint) {
+                Ok(mint) => Some(mint),
+                Err(_) => {
+                    error("Invalid token mint");
+                    continue;
+                }
+            },
+        };
+
+        rows.push(PayoutRow {
+            line,
+            recipient,
+            amount: amount.to_string(),
+            token,
+        });
+    }
+
+    (rows, errors)
+}
+
+/// Size of a legacy transaction once signed, in bytes, including the
+/// priority fee instruction added when it is sent.
+fn transaction_size(instructions: &[Instruction], payer: &Pubkey, priority_fee: u64) -> usize {
+    let transaction = build_transaction(instructions, payer, priority_fee);
+    bincode::serialized_size(&transaction).unwrap_or(u64::MAX) as usize
+}
+
+/// Greedily packs each row's instructions into as few transactions as fit in
+/// the packet size limit, keeping row order. `row_instructions[i]` holds the
+/// instructions paying row `i`; `priority_fee` is the compute unit price the
+/// batches will be sent with. Fails with the index of a row that doesn't fit
+/// in a transaction on its own.
+pub fn pack_payouts(
+    payer: &Pubkey,
+    row_instructions: &[Vec<Instruction>],
+    priority_fee: u64,
+) -> Result<Vec<PayoutBatch>, usize> {
+    let mut batches: Vec<PayoutBatch> = Vec::new();
+    let mut current = PayoutBatch {
+        rows: Vec::new(),
+        instructions: Vec::new(),
+    };
+
+    for (index, instructions) in row_instructions.iter().enumerate() {
+        let mut candidate = current.instructions.clone();
+        candidate.extend(instructions.iter().cloned());
+
+        if transaction_size(&candidate, payer, priority_fee) <= PACKET_DATA_SIZE {
+            current.rows.push(index);
+            current.instructions = candidate;
+            continue;
+        }
+
+        if current.rows.is_empty() {
+            return Err(index);
+        }
+        batches.push(std::mem::replace(
+            &mut current,
+            PayoutBatch {
+                rows: vec![index],
+                instructions: instructions.clone(),
+            },
+        ));
+        if transaction_size(&current.instructions, payer, priority_fee) > PACKET_DATA_SIZE {
+            return Err(index);
+        }
+    }
+
+    if !current.rows.is_empty() {
+        batches.push(current);
+    }
+    Ok(batches)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use solana_sdk::system_instruction;
+
+    #[test]
+    fn parses_rows_and_reports_bad_lines() {
+        let recipient = Pubkey::new_unique();
+        let mint = Pubkey::new_unique();
+        let csv = format!(
+            "address,amount,token\n{recipient},1.5\n\n# refunds\n\"{recipient}\", 2 ,SOL\n{recipient},3,{mint}\nnope,1\n{recipient}\n{recipient},1,bad-mint\n"
+        );
+        let (rows, errors) = parse_payouts_csv(&csv);
+
+        assert_eq!(
+            rows,
+            vec![
+                PayoutRow { line: 2, recipient, amount: "1.5".to_string(), token: None },
+                PayoutRow { line: 5, recipient, amount: "2".to_string(), token: None },
+                PayoutRow { line: 6, recipient, amount: "3".to_string(), token: Some(mint) },
+            ]
+        );
+        let errors: Vec<_> = errors.iter().map(|error| (error.line, error.message.as_str())).collect();
+        assert_eq!(
+            errors,
+            vec![
+                (7, "Invalid address"),
+                (8, "Expected address,amount[,token]"),
+                (9, "Invalid token mint"),
+            ]
+        );
+    }
+
+    #[test]
+    fn header_only_counts_on_the_first_line() {
+        let (rows, errors) = parse_payouts_csv("\naddress,amount");
+        assert!(rows.is_empty());
+        assert_eq!(errors, vec![PayoutRowError { line: 2, message: "Invalid address".to_string() }]);
+    }
+
+    fn transfers(payer: &Pubkey, count: usize) -> Vec<Vec<Instruction>> {
+        (0..count)
+            .map(|_| vec![system_instruction::transfer(payer, &Pubkey::new_unique(), 1)])
+            .collect()
+    }
+
+    #[test]
+    fn packs_rows_in_order_within_the_packet_limit() {
+        let payer = Pubkey::new_unique();
+        for priority_fee in [0, 10_000] {
+            let rows = transfers(&payer, 40);
+            let batches = pack_payouts(&payer, &rows, priority_fee).unwrap();
+
+            assert!(batches.len() > 1);
+            let packed: Vec<usize> = batches.iter().flat_map(|batch| batch.rows.clone()).collect();
+            assert_eq!(packed, (0..rows.len()).collect::<Vec<_>>());
+            for batch in &batches {
+                assert!(transaction_size(&batch.instructions, &payer, priority_fee) <= PACKET_DATA_SIZE);
+            }
+        }
+    }
+
+    #[test]
+    fn reports_a_row_that_never_fits() {
+        let payer = Pubkey::new_unique();
+        let mut rows = transfers(&payer, 3);
+        rows[1] = vec![Instruction::new_with_bytes(Pubkey::new_unique(), &[0; PACKET_DATA_SIZE], Vec::new())];
+
+        assert_eq!(pack_payouts(&payer, &rows, 0), Err(1));
+        assert_eq!(pack_payouts(&payer, &rows[1..], 0), Err(0));
+        assert_eq!(pack_payouts(&payer, &[], 0), Ok(Vec::new()));
+    }
+}
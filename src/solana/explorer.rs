@@ -0,0 +1,194 @@
+use serde::{Deserialize, Serialize};
+
+use crate::solana_pay::encode_component;
+
+/// Cluster an RPC endpoint belongs to, used to point explorer links at the
+/// same network.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Cluster {
+    Mainnet,
+    Devnet,
+    Testnet,
+    /// Any other endpoint, e.g. a local validator.
+    Custom(String),
+}
+
+impl Cluster {
+    /// Recognizes the public endpoints by host; anything else is custom.
+    /// Third-party mainnet RPCs therefore show up as custom, which still links
+    /// to the right data.
+    pub fn from_rpc_url(url: &str) -> Self {
+        let host = url.trim().to_lowercase();
+        if host.contains("api.mainnet-beta.solana.com") {
+            Cluster::Mainnet
+        } else if host.contains("api.devnet.solana.com") {
+            Cluster::Devnet
+        } else if host.contains("api.testnet.solana.com") {
+            Cluster::Testnet
+        } else {
+            Cluster::Custom(url.trim().to_string())
+        }
+    }
+
+    /// Short name for telemetry. Never the custom URL, which can carry an
+    /// API key.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Cluster::Mainnet => "mainnet",
+            Cluster::Devnet => "devnet",
+            Cluster::Testnet => "testnet",
+            Cluster::Custom(_) => "custom",
+        }
+    }
+}
+
+/// Something an explorer has a page for.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ExplorerTarget {
+    Transaction(String),
+    Address(String),
+    Block(u64),
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Explorer {
+    #[default]
+    SolanaExplorer,
+    Solscan,
+    SolanaFm,
+    Xray,
+}
+
+impl Explorer {
+    pub const ALL: [Explorer; 4] = [Explorer::SolanaExplorer, Explorer::Solscan, Explorer::SolanaFm, Explorer::Xray];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            Explorer::SolanaExplorer => "Solana Explorer",
+            Explorer::Solscan => "Solscan",
+            Explorer::SolanaFm => "SolanaFM",
+            Explorer::Xray => "XRAY",
+        }
+    }
+
+    pub fn key(&self) -> &'static str {
+        match self {
+            Explorer::SolanaExplorer => "solana-explorer",
+            Explorer::Solscan => "solscan",
+            Explorer::SolanaFm => "solanafm",
+            Explorer::Xray => "xray",
+        }
+    }
+
+    pub fn from_key(key: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|explorer| explorer.key() == key)
+    }
+
+    /// Whether links can point at a custom RPC endpoint.
+    pub fn supports_custom_cluster(&self) -> bool {
+        matches!(self, Explorer::SolanaExplorer | Explorer::Solscan)
+    }
+
+    pub fn url(&self, cluster: &Cluster, target: &ExplorerTarget) -> String {
+        let base = match self {
+            Explorer::SolanaExplorer => "https://explorer.solana.com",
+            Explorer::Solscan => "https://solscan.io",
+            Explorer::SolanaFm => "https://solana.fm",
+            Explorer::Xray => "https://xray.helius.xyz",
+        };
+        let path = match (self, target) {
+            (_, ExplorerTarget::Transaction(signature)) => format!("tx/{}", signature),
+            (Explorer::Solscan | Explorer::Xray, ExplorerTarget::Address(address)) => format!("account/{}", address),
+            (_, ExplorerTarget::Address(address)) => format!("address/{}", address),
+            (_, ExplorerTarget::Block(slot)) => format!("block/{}", slot),
+        };
+        match self.cluster_query(cluster) {
+            Some(query) => format!("{}/{}?{}", base, path, query),
+            None => format!("{}/{}", base, path),
+        }
+    }
+
+    /// Query string selecting `cluster`; mainnet is every explorer's default.
+    /// Explorers without custom RPC support fall back to mainnet.
+    fn cluster_query(&self, cluster: &Cluster) -> Option<String> {
+        let query = match (self, cluster) {
+            (_, Cluster::Mainnet) => return None,
+            (Explorer::SolanaExplorer | Explorer::Solscan, Cluster::Devnet) => "cluster=devnet".to_string(),
+            (Explorer::SolanaExplorer | Explorer::Solscan, Cluster::Testnet) => "cluster=testnet".to_string(),
+            (Explorer::SolanaExplorer | Explorer::Solscan, Cluster::Custom(url)) => {
+                format!("cluster=custom&customUrl={}", encode_component(url))
+            }
+            (Explorer::SolanaFm, Cluster::Devnet) => "cluster=devnet-solana".to_string(),
+            (Explorer::SolanaFm, Cluster::Testnet) => "cluster=testnet-solana".to_string(),
+            (Explorer::Xray, Cluster::Devnet) => "network=devnet".to_string(),
+            (Explorer::Xray, Cluster::Testnet) => "network=testnet".to_string(),
+            (Explorer::SolanaFm | Explorer::Xray, Cluster::Custom(_)) => return None,
+        };
+        Some(query)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SIGNATURE: &str = "5VERv8NMvzbJMEkV8xnrLkEaWRtSz9CosKDYjCJjBRnbJLgp8uirBgmQpjKhoR4tjF3ZpRzrFmBV6UjKdiSZkQUW";
+
+    #[test]
+    fn recognizes_public_clusters() {
+        assert_eq!(Cluster::from_rpc_url("https://api.mainnet-beta.solana.com"), Cluster::Mainnet);
+        assert_eq!(Cluster::from_rpc_url(" https://API.devnet.solana.com/ "), Cluster::Devnet);
+        assert_eq!(Cluster::from_rpc_url("https://api.testnet.solana.com"), Cluster::Testnet);
+        assert_eq!(
+            Cluster::from_rpc_url("http://127.0.0.1:8899"),
+            Cluster::Custom("http://127.0.0.1:8899".to_string())
+        );
+    }
+
+    #[test]
+    fn mainnet_links_have_no_query() {
+        let target = ExplorerTarget::Transaction(SIGNATURE.to_string());
+        assert_eq!(
+            Explorer::SolanaExplorer.url(&Cluster::Mainnet, &target),
+            format!("https://explorer.solana.com/tx/{}", SIGNATURE)
+        );
+        assert_eq!(
+            Explorer::Xray.url(&Cluster::Mainnet, &ExplorerTarget::Address("abc".to_string())),
+            "https://xray.helius.xyz/account/abc"
+        );
+        assert_eq!(
+            Explorer::SolanaFm.url(&Cluster::Mainnet, &ExplorerTarget::Block(42)),
+            "https://solana.fm/block/42"
+        );
+    }
+
+    #[test]
+    fn each_explorer_names_clusters_its_own_way() {
+        let target = ExplorerTarget::Address("abc".to_string());
+        assert_eq!(
+            Explorer::Solscan.url(&Cluster::Devnet, &target),
+            "https://solscan.io/account/abc?cluster=devnet"
+        );
+        assert_eq!(
+            Explorer::SolanaFm.url(&Cluster::Testnet, &target),
+            "https://solana.fm/address/abc?cluster=testnet-solana"
+        );
+        assert_eq!(
+            Explorer::Xray.url(&Cluster::Devnet, &target),
+            "https://xray.helius.xyz/account/abc?network=devnet"
+        );
+    }
+
+    #[test]
+    fn custom_clusters_pass_the_encoded_url() {
+        let cluster = Cluster::Custom("http://localhost:8899".to_string());
+        let target = ExplorerTarget::Block(7);
+        assert_eq!(
+            Explorer::SolanaExplorer.url(&cluster, &target),
+            "https://explorer.solana.com/block/7?cluster=custom&customUrl=http%3A%2F%2Flocalhost%3A8899"
+        );
+        // Explorers without custom RPC support fall back to mainnet.
+        assert_eq!(Explorer::SolanaFm.url(&cluster, &target), "https://solana.fm/block/7");
+        assert_eq!(Explorer::Xray.url(&cluster, &target), "https://xray.helius.xyz/block/7");
+    }
+}
@@ -0,0 +1,58 @@
+mod accounts;
+mod batch;
+mod explorer;
+mod nonce;
+mod offline;
+mod price;
+mod recipient;
+mod sns;
+mod stake;
+mod token;
+mod transaction;
+
+pub use accounts::*;
+pub use batch::*;
+pub use explorer::*;
+pub use nonce::*;
+pub use offline::*;
+pub use price::*;
+pub use recipient::*;
+pub use sns::*;
+pub use stake::*;
+pub use token::*;
+pub use transaction::*;
+
+/// RPC endpoint used until another one is picked in settings.
+pub const DEFAULT_RPC_URL: &str = "https://api.mainnet-beta.solana.com";
+
+/// Client for the RPC endpoint and commitment level picked in settings, used
+/// for all chain reads and transaction submission.
+#[cfg(feature = "web")]
+pub fn rpc_client() -> solana_client_wasm::WasmClient {
+    use crate::hooks::{current_settings, Commitment};
+    use solana_sdk::commitment_config::CommitmentConfig;
+
+    let settings = current_settings();
+    let commitment = match settings.commitment {
+        Commitment::Processed => CommitmentConfig::processed(),
+        Commitment::Confirmed => CommitmentConfig::confirmed(),
+        Commitment::Finalized => CommitmentConfig::finalized(),
+    };
+    solana_client_wasm::WasmClient::new_with_commitment(settings.rpc_url(), commitment)
+}
+
+/// Awaits an RPC request in an `rpc` span carrying the cluster name,
+/// recording its latency and outcome under `method`.
+#[cfg(feature = "web")]
+pub async fn rpc_call<T, E: std::fmt::Debug>(
+    method: &'static str,
+    request: impl std::future::Future<Output = Result<T, E>>,
+) -> Result<T, E> {
+    use crate::hooks::current_settings;
+    use tracing::Instrument;
+
+    let cluster = Cluster::from_rpc_url(current_settings().rpc_url()).name();
+    crate::telemetry::timed(method, request)
+        .instrument(tracing::info_span!("rpc", cluster))
+        .await
+}
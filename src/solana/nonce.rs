@@ -0,0 +1,165 @@
+use solana_sdk::{
+    hash::Hash,
+    instruction::Instruction,
+    nonce::state::{State, Versions},
+    pubkey::Pubkey,
+    system_instruction,
+};
+
+/// Offset of the authority in a serialized nonce account:
+/// 4-byte version tag, 4-byte state tag.
+pub const NONCE_AUTHORITY_OFFSET: usize = 8;
+
+/// An initialized durable nonce account.
+#[derive(Clone, Debug, PartialEq)]
+pub struct NonceAccount {
+    pub address: Pubkey,
+    pub lamports: u64,
+    pub authority: Pubkey,
+    /// Stored nonce, used in place of a recent blockhash.
+    pub nonce: Hash,
+    pub lamports_per_signature: u64,
+}
+
+impl NonceAccount {
+    /// Parses account data; `None` unless it is an initialized nonce account.
+    pub fn parse(address: Pubkey, lamports: u64, data: &[u8]) -> Option<Self> {
+        let versions = bincode::deserialize::<Versions>(data).ok()?;
+        match versions.state() {
+            State::Initialized(data) => Some(NonceAccount {
+                address,
+                lamports,
+                authority: data.authority,
+                nonce: *data.durable_nonce.as_hash(),
+                lamports_per_signature: data.fee_calculator.lamports_per_signature,
+            }),
+            State::Uninitialized => None,
+        }
+    }
+}
+
+/// Size of a nonce account, for its rent-exempt minimum.
+pub fn nonce_account_size() -> usize {
+    State::size()
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum NonceAction {
+    Create { lamports: u64 },
+    Advance { nonce: Pubkey },
+    Authorize { nonce: Pubkey, new_authority: Pubkey },
+    Withdraw { nonce: Pubkey, to: Pubkey, lamports: u64 },
+}
+
+impl NonceAction {
+    pub fn needs_new_account(&self) -> bool {
+        matches!(self, NonceAction::Create { .. })
+    }
+
+    /// Instructions for this action with `authority` as payer and current
+    /// nonce authority. `new_account` is the freshly generated nonce account
+    /// for [`NonceAction::Create`].
+    pub fn instructions(&self, authority: &Pubkey, new_account: Option<&Pubkey>) -> Result<Vec<Instruction>, String> {
+        let instructions = match self {
+            NonceAction::Create { lamports } => system_instruction::create_nonce_account(
+                authority,
+                new_account.ok_or("A new nonce account is required")?,
+                authority,
+                *lamports,
+            ),
+            NonceAction::Advance { nonce } => {
+                vec![system_instruction::advance_nonce_account(nonce, authority)]
+            }
+            NonceAction::Authorize { nonce, new_authority } => {
+                vec![system_instruction::authorize_nonce_account(nonce, authority, new_authority)]
+            }
+            NonceAction::Withdraw { nonce, to, lamports } => {
+                vec![system_instruction::withdraw_nonce_account(nonce, authority, to, *lamports)]
+            }
+        };
+        Ok(instructions)
+    }
+}
+
+/// Reads one nonce account.
+#[cfg(feature = "web")]
+pub async fn fetch_nonce_account(
+    client: &solana_client_wasm::WasmClient,
+    address: &Pubkey,
+) -> Result<NonceAccount, String> {
+    use super::rpc_call;
+
+    let account = rpc_call("getAccountInfo", client.get_account(address))
+        .await
+        .map_err(|e| format!("Failed to fetch nonce account: {:?}", e))?;
+    if account.owner != solana_sdk::system_program::id() {
+        return Err(format!("{} is not a nonce account", address));
+    }
+    NonceAccount::parse(*address, account.lamports, &account.data)
+        .ok_or_else(|| format!("{} is not an initialized nonce account", address))
+}
+
+/// Lists nonce accounts whose authority is `authority`.
+#[cfg(feature = "web")]
+pub async fn fetch_nonce_accounts(
+    client: &solana_client_wasm::WasmClient,
+    authority: &Pubkey,
+) -> Result<Vec<NonceAccount>, String> {
+    use super::{get_program_accounts, memcmp};
+    use solana_client_wasm::utils::rpc_filter::RpcFilterType;
+
+    let filters = vec![
+        RpcFilterType::DataSize(nonce_account_size() as u64),
+        memcmp(NONCE_AUTHORITY_OFFSET, authority.as_ref()),
+    ];
+    let accounts = get_program_accounts(client, &solana_sdk::system_program::id(), filters).await?;
+
+    let mut nonces = accounts
+        .into_iter()
+        .filter_map(|(address, account)| NonceAccount::parse(address, account.lamports, &account.data))
+        .collect::<Vec<_>>();
+    nonces.sort_by(|a, b| b.lamports.cmp(&a.lamports));
+    Ok(nonces)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use solana_sdk::nonce::state::{Data, DurableNonce};
+
+    fn nonce_data(authority: &Pubkey, blockhash: &Hash) -> Vec<u8> {
+        let durable_nonce = DurableNonce::from_blockhash(blockhash);
+        let state = State::Initialized(Data::new(*authority, durable_nonce, 5000));
+        bincode::serialize(&Versions::new(state)).unwrap()
+    }
+
+    #[test]
+    fn parses_initialized_nonce_accounts() {
+        let address = Pubkey::new_unique();
+        let authority = Pubkey::new_unique();
+        let blockhash = Hash::new_unique();
+        let data = nonce_data(&authority, &blockhash);
+
+        assert_eq!(data.len(), nonce_account_size());
+        assert_eq!(&data[NONCE_AUTHORITY_OFFSET..NONCE_AUTHORITY_OFFSET + 32], authority.as_ref());
+        assert_eq!(
+            NonceAccount::parse(address, 1_447_680, &data),
+            Some(NonceAccount {
+                address,
+                lamports: 1_447_680,
+                authority,
+                nonce: *DurableNonce::from_blockhash(&blockhash).as_hash(),
+                lamports_per_signature: 5000,
+            })
+        );
+    }
+
+    #[test]
+    fn rejects_uninitialized_or_foreign_accounts() {
+        let address = Pubkey::new_unique();
+        let uninitialized = bincode::serialize(&Versions::new(State::Uninitialized)).unwrap();
+        assert_eq!(NonceAccount::parse(address, 0, &uninitialized), None);
+        assert_eq!(NonceAccount::parse(address, 0, &[]), None);
+        assert_eq!(NonceAccount::parse(address, 0, &[9; 80]), None);
+    }
+}
@@ -0,0 +1,199 @@
+use solana_sdk::{pubkey::Pubkey, signature::Signature, transaction::Transaction};
+use std::str::FromStr;
+
+use super::{decode_transaction, encode_transaction};
+
+/// Text encodings offered when exporting a transaction.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum TransactionEncoding {
+    #[default]
+    Base64,
+    Base58,
+}
+
+impl TransactionEncoding {
+    pub const ALL: [TransactionEncoding; 2] = [TransactionEncoding::Base64, TransactionEncoding::Base58];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            TransactionEncoding::Base64 => "Base64",
+            TransactionEncoding::Base58 => "Base58",
+        }
+    }
+
+    pub fn encode(&self, transaction: &Transaction) -> Result<String, String> {
+        match self {
+            TransactionEncoding::Base64 => encode_transaction(transaction),
+            TransactionEncoding::Base58 => bincode::serialize(transaction)
+                .map(|bytes| bs58::encode(bytes).into_string())
+                .map_err(|e| format!("Failed to serialize transaction: {}", e)),
+        }
+    }
+}
+
+/// Parses a wire-format transaction in either base64 or base58.
+pub fn decode_transaction_any(text: &str) -> Result<Transaction, String> {
+    let text = text.trim();
+    if let Ok(transaction) = decode_transaction(text) {
+        return Ok(transaction);
+    }
+    let bytes = bs58::decode(text)
+        .into_vec()
+        .map_err(|_| "Not a base64 or base58 transaction".to_string())?;
+    bincode::deserialize(&bytes).map_err(|e| format!("Failed to deserialize transaction: {}", e))
+}
+
+/// Required signers that have not signed yet.
+pub fn missing_signers(transaction: &Transaction) -> Vec<Pubkey> {
+    let num_signers = transaction.message.header.num_required_signatures as usize;
+    transaction
+        .message
+        .account_keys
+        .iter()
+        .take(num_signers)
+        .zip(transaction.signatures.iter())
+        .filter(|(_, signature)| **signature == Signature::default())
+        .map(|(key, _)| *key)
+        .collect()
+}
+
+/// Adds `signature` for `signer` after checking it against the message.
+pub fn add_signature(transaction: &mut Transaction, signer: &Pubkey, signature: Signature) -> Result<(), String> {
+    let num_signers = transaction.message.header.num_required_signatures as usize;
+    let index = transaction
+        .message
+        .account_keys
+        .iter()
+        .take(num_signers)
+        .position(|key| key == signer)
+        .ok_or_else(|| format!("{} is not a signer of this transaction", signer))?;
+    if !signature.verify(signer.as_ref(), &transaction.message_data()) {
+        return Err(format!("Signature for {} does not match this transaction", signer));
+    }
+    transaction.signatures[index] = signature;
+    Ok(())
+}
+
+/// Imports signatures into `transaction` from either a signed copy of the same
+/// transaction (base64 or base58) or `pubkey=signature` lines, as printed by
+/// `solana ... --sign-only`. Returns how many signatures were added.
+pub fn import_signatures(transaction: &mut Transaction, text: &str) -> Result<usize, String> {
+    let text = text.trim();
+    if text.is_empty() {
+        return Err("Nothing to import".to_string());
+    }
+
+    let mut pairs = Vec::new();
+    if let Ok(signed) = decode_transaction_any(text) {
+        if signed.message != transaction.message {
+            return Err("The imported transaction does not match this one".to_string());
+        }
+        for (key, signature) in signed.message.account_keys.iter().zip(signed.signatures.iter()) {
+            if *signature != Signature::default() {
+                pairs.push((*key, *signature));
+            }
+        }
+    } else {
+        for line in text.lines().map(str::trim).filter(|line| !line.is_empty()) {
+            let (key, signature) = line
+                .split_once(['=', ':'])
+                .ok_or_else(|| format!("Expected pubkey=signature, got: {}", line))?;
+            let key = Pubkey::from_str(key.trim()).map_err(|_| format!("Invalid signer: {}", key.trim()))?;
+            let signature = Signature::from_str(signature.trim())
+                .map_err(|_| format!("Invalid signature for {}", key))?;
+            pairs.push((key, signature));
+        }
+    }
+
+    let mut added = 0;
+    for (key, signature) in pairs {
+        if missing_signers(transaction).contains(&key) {
+            add_signature(transaction, &key, signature)?;
+            added += 1;
+        }
+    }
+    Ok(added)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use solana_sdk::{
+        hash::Hash,
+        message::Message,
+        signature::{Keypair, Signer},
+        system_instruction,
+    };
+
+    /// A transfer from `from` paid by `payer`, with no signatures yet.
+    fn unsigned_transfer(payer: &Keypair, from: &Keypair) -> Transaction {
+        let instruction = system_instruction::transfer(&from.pubkey(), &Pubkey::new_unique(), 1);
+        let message = Message::new_with_blockhash(&[instruction], Some(&payer.pubkey()), &Hash::new_unique());
+        Transaction::new_unsigned(message)
+    }
+
+    #[test]
+    fn lists_missing_signers_in_message_order() {
+        let payer = Keypair::new();
+        let from = Keypair::new();
+        let mut transaction = unsigned_transfer(&payer, &from);
+        assert_eq!(missing_signers(&transaction), vec![payer.pubkey(), from.pubkey()]);
+
+        transaction.partial_sign(&[&from], transaction.message.recent_blockhash);
+        assert_eq!(missing_signers(&transaction), vec![payer.pubkey()]);
+    }
+
+    #[test]
+    fn imports_signatures_from_a_signed_copy() {
+        let payer = Keypair::new();
+        let from = Keypair::new();
+        let mut transaction = unsigned_transfer(&payer, &from);
+
+        let mut signed = transaction.clone();
+        signed.partial_sign(&[&from], signed.message.recent_blockhash);
+        for encoding in TransactionEncoding::ALL {
+            let mut copy = transaction.clone();
+            assert_eq!(import_signatures(&mut copy, &encoding.encode(&signed).unwrap()), Ok(1));
+            assert_eq!(missing_signers(&copy), vec![payer.pubkey()]);
+        }
+
+        let other = unsigned_transfer(&payer, &from);
+        let text = TransactionEncoding::Base64.encode(&other).unwrap();
+        assert!(import_signatures(&mut transaction, &text).is_err());
+    }
+
+    #[test]
+    fn imports_signature_lines() {
+        let payer = Keypair::new();
+        let from = Keypair::new();
+        let mut transaction = unsigned_transfer(&payer, &from);
+        let message = transaction.message_data();
+        let text = format!(
+            "{}={}\n\n  {}: {}\n{}={}",
+            payer.pubkey(),
+            payer.sign_message(&message),
+            from.pubkey(),
+            from.sign_message(&message),
+            // Not a signer of this transaction: skipped.
+            Pubkey::new_unique(),
+            Keypair::new().sign_message(&message),
+        );
+
+        assert_eq!(import_signatures(&mut transaction, &text), Ok(2));
+        assert!(missing_signers(&transaction).is_empty());
+        assert!(transaction.verify().is_ok());
+    }
+
+    #[test]
+    fn rejects_bad_signature_imports() {
+        let payer = Keypair::new();
+        let from = Keypair::new();
+        let mut transaction = unsigned_transfer(&payer, &from);
+
+        assert!(import_signatures(&mut transaction, "  ").is_err());
+        assert!(import_signatures(&mut transaction, "not a signature").is_err());
+        let forged = format!("{}={}", payer.pubkey(), from.sign_message(&transaction.message_data()));
+        assert!(import_signatures(&mut transaction, &forged).is_err());
+        assert_eq!(missing_signers(&transaction), vec![payer.pubkey(), from.pubkey()]);
+    }
+}
@@ -0,0 +1,25 @@
+/// Price of one SOL in `currency`, from CoinGecko's public API.
+#[cfg(feature = "web")]
+pub async fn fetch_sol_price(currency: crate::hooks::DisplayCurrency) -> Result<f64, String> {
+    use gloo::net::http::Request;
+
+    let url = format!(
+        "https://api.coingecko.com/api/v3/simple/price?ids=solana&vs_currencies={}",
+        currency.key()
+    );
+    let response = Request::get(&url)
+        .header("Accept", "application/json")
+        .send()
+        .await
+        .map_err(|e| format!("Failed to fetch price: {}", e))?;
+    if !response.ok() {
+        return Err(format!("Price API responded with status {}", response.status()));
+    }
+    let body = response
+        .json::<serde_json::Value>()
+        .await
+        .map_err(|e| format!("Price API returned invalid data: {}", e))?;
+    body["solana"][currency.key()]
+        .as_f64()
+        .ok_or_else(|| format!("No SOL price in {}", currency.label()))
+}
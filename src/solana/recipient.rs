@@ -0,0 +1,145 @@
+use solana_sdk::pubkey::Pubkey;
+
+use super::{ACCOUNT_TYPE_ACCOUNT, ACCOUNT_TYPE_MINT, ACCOUNT_TYPE_OFFSET, MINT_LEN, TOKEN_ACCOUNT_LEN};
+
+/// Something about a recipient that suggests funds sent there may be lost.
+#[derive(Clone, Debug, PartialEq)]
+pub enum RecipientWarning {
+    /// The recipient is the sending wallet.
+    Sender,
+    /// Off the ed25519 curve, e.g. a program derived address.
+    OffCurve,
+    /// An executable program account.
+    Executable,
+    /// An SPL token mint.
+    TokenMint,
+    /// An SPL token account, with the wallet that owns it.
+    TokenAccount { owner: Pubkey },
+    /// No balance and no transaction history.
+    Unused,
+}
+
+impl RecipientWarning {
+    /// Catalog key of the warning: its text is under
+    /// `recipient_warning.{key}.title` and `.message`, the latter with an
+    /// `{owner}` placeholder for token accounts.
+    pub fn key(&self) -> &'static str {
+        match self {
+            RecipientWarning::Sender => "sender",
+            RecipientWarning::OffCurve => "off_curve",
+            RecipientWarning::Executable => "executable",
+            RecipientWarning::TokenMint => "token_mint",
+            RecipientWarning::TokenAccount { .. } => "token_account",
+            RecipientWarning::Unused => "unused",
+        }
+    }
+}
+
+/// Checks that need no chain data.
+pub fn check_recipient_address(recipient: &Pubkey, sender: &Pubkey) -> Vec<RecipientWarning> {
+    let mut warnings = Vec::new();
+    if recipient == sender {
+        warnings.push(RecipientWarning::Sender);
+    }
+    if !recipient.is_on_curve() {
+        warnings.push(RecipientWarning::OffCurve);
+    }
+    warnings
+}
+
+/// Classifies an account owned by a token program as a mint or token account.
+fn token_account_warning(data: &[u8]) -> Option<RecipientWarning> {
+    let account_type = data.get(ACCOUNT_TYPE_OFFSET).copied();
+    let token_account_owner = || {
+        data.get(32..64)
+            .and_then(|owner| <[u8; 32]>::try_from(owner).ok())
+            .map(|owner| RecipientWarning::TokenAccount { owner: Pubkey::from(owner) })
+    };
+
+    match (data.len(), account_type) {
+        (MINT_LEN, _) | (_, Some(ACCOUNT_TYPE_MINT)) => Some(RecipientWarning::TokenMint),
+        (TOKEN_ACCOUNT_LEN, _) | (_, Some(ACCOUNT_TYPE_ACCOUNT)) => token_account_owner(),
+        _ => None,
+    }
+}
+
+/// Runs [`check_recipient_address`] plus the checks that read the recipient
+/// account and its history.
+#[cfg(feature = "web")]
+pub async fn check_recipient(
+    client: &solana_client_wasm::WasmClient,
+    recipient: &Pubkey,
+    sender: &Pubkey,
+) -> Result<Vec<RecipientWarning>, String> {
+    use super::{is_token_program, rpc_call};
+    use solana_sdk::commitment_config::CommitmentConfig;
+
+    let mut warnings = check_recipient_address(recipient, sender);
+
+    let account = rpc_call(
+        "getAccountInfo",
+        client.get_account_with_commitment(recipient, CommitmentConfig::confirmed()),
+    )
+    .await
+    .map_err(|e| format!("Failed to fetch recipient account: {:?}", e))?;
+
+    match account {
+        Some(account) if account.executable => warnings.push(RecipientWarning::Executable),
+        Some(account) if is_token_program(&account.owner) => {
+            warnings.extend(token_account_warning(&account.data));
+        }
+        Some(_) => {}
+        None => {
+            let signatures = rpc_call("getSignaturesForAddress", client.get_signatures_for_address(recipient))
+                .await
+                .map_err(|e| format!("Failed to fetch recipient history: {:?}", e))?;
+            if signatures.is_empty() {
+                warnings.push(RecipientWarning::Unused);
+            }
+        }
+    }
+
+    Ok(warnings)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use solana_sdk::signature::{Keypair, Signer};
+
+    #[test]
+    fn warns_about_the_sender_and_off_curve_addresses() {
+        let sender = Keypair::new().pubkey();
+        assert_eq!(check_recipient_address(&sender, &sender), vec![RecipientWarning::Sender]);
+
+        let pda = Pubkey::find_program_address(&[b"vault"], &Pubkey::new_unique()).0;
+        assert_eq!(check_recipient_address(&pda, &sender), vec![RecipientWarning::OffCurve]);
+    }
+
+    #[test]
+    fn classifies_token_mints() {
+        assert_eq!(token_account_warning(&[0; MINT_LEN]), Some(RecipientWarning::TokenMint));
+
+        // Token-2022 mint with extensions, padded to the account length.
+        let mut extended = vec![0; TOKEN_ACCOUNT_LEN + 4];
+        extended[ACCOUNT_TYPE_OFFSET] = ACCOUNT_TYPE_MINT;
+        assert_eq!(token_account_warning(&extended), Some(RecipientWarning::TokenMint));
+    }
+
+    #[test]
+    fn classifies_token_accounts_with_their_owner() {
+        let owner = Pubkey::new_unique();
+        let mut data = vec![0; TOKEN_ACCOUNT_LEN];
+        data[32..64].copy_from_slice(owner.as_ref());
+        assert_eq!(token_account_warning(&data), Some(RecipientWarning::TokenAccount { owner }));
+
+        data.extend([ACCOUNT_TYPE_ACCOUNT, 0, 0]);
+        assert_eq!(token_account_warning(&data), Some(RecipientWarning::TokenAccount { owner }));
+    }
+
+    #[test]
+    fn ignores_other_token_program_accounts() {
+        assert_eq!(token_account_warning(&[]), None);
+        assert_eq!(token_account_warning(&[0; 355]), None);
+    }
+}
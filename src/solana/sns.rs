@@ -0,0 +1,366 @@
+use solana_sdk::{hash::hashv, pubkey, pubkey::Pubkey, signature::Signature};
+#[cfg(feature = "web")]
+use std::{cell::RefCell, collections::HashMap};
+
+#[cfg(feature = "web")]
+use super::Cluster;
+
+/// Solana Name Service program.
+pub const NAME_PROGRAM_ID: Pubkey = pubkey!("namesLPneVptA9Z5rqUDD9tMTWEJwofgaYwp8cawRkX");
+/// Parent of every `.sol` domain.
+pub const SOL_TLD_AUTHORITY: Pubkey = pubkey!("58PwtjSDuFHuUkYjH9BYnnQKHfwo9reZhC2zMJv9JPkx");
+/// Class of the accounts mapping a domain key back to its name.
+pub const REVERSE_LOOKUP_CLASS: Pubkey = pubkey!("33m47vH6Eav6jr5Ry86XjhRft2jRBLDnDgPSHoquXi2Z");
+/// Program holding each wallet's primary ("favourite") domain.
+pub const NAME_OFFERS_PROGRAM_ID: Pubkey = pubkey!("85iDfUvr3HJyLM2zcq5BXSiDvUWfw6cSE1FfNBo8Ap29");
+/// Program that wraps domains in NFTs. A tokenized domain is owned by its
+/// central state while the NFT holder controls it.
+pub const NAME_TOKENIZER_ID: Pubkey = pubkey!("nftD3vbNkNqfj2Sd3HZwbpw4BxxKWr4AjGb9X38JeZk");
+
+const HASH_PREFIX: &str = "SPL Name Service";
+/// Name registry header: parent name, owner, class.
+const NAME_REGISTRY_HEADER_LEN: usize = 96;
+const NAME_OWNER_OFFSET: usize = 32;
+/// Favourite domain account: 1-byte tag, then the domain's name account.
+const FAVOURITE_DOMAIN_OFFSET: usize = 1;
+/// Name of the v1 SOL record: the address payments to the domain go to.
+const SOL_RECORD: &str = "\u{1}SOL";
+/// SOL record body: destination address, then the owner's signature.
+const SOL_RECORD_LEN: usize = 96;
+/// Token account layout: mint, owner, amount.
+#[cfg(feature = "web")]
+const TOKEN_ACCOUNT_LEN: u64 = 165;
+#[cfg(feature = "web")]
+const TOKEN_OWNER_OFFSET: usize = 32;
+#[cfg(feature = "web")]
+const TOKEN_AMOUNT_OFFSET: usize = 64;
+
+/// How long resolved names stay cached. Domains change hands and records
+/// get updated, so results can't be kept for the whole session.
+#[cfg(feature = "web")]
+const CACHE_TTL_MS: f64 = 5.0 * 60.0 * 1000.0;
+
+/// Cached value with the time it was fetched.
+#[cfg(feature = "web")]
+type Cached<T> = (T, f64);
+
+// Keyed by cluster: the same name can resolve differently on devnet.
+#[cfg(feature = "web")]
+thread_local! {
+    static NAME_CACHE: RefCell<HashMap<(Cluster, String), Cached<Pubkey>>> = RefCell::new(HashMap::new());
+    static REVERSE_CACHE: RefCell<HashMap<(Cluster, Pubkey), Cached<Option<String>>>> = RefCell::new(HashMap::new());
+}
+
+#[cfg(feature = "web")]
+fn current_cluster() -> Cluster {
+    Cluster::from_rpc_url(crate::hooks::current_settings().rpc_url())
+}
+
+/// The cached value for `key`, unless it has expired.
+#[cfg(feature = "web")]
+fn cached<K: Eq + std::hash::Hash, T: Clone>(cache: &RefCell<HashMap<K, Cached<T>>>, key: &K) -> Option<T> {
+    let now = crate::telemetry::now_ms();
+    cache
+        .borrow()
+        .get(key)
+        .filter(|(_, fetched_at)| now - fetched_at < CACHE_TTL_MS)
+        .map(|(value, _)| value.clone())
+}
+
+/// Whether `input` looks like a `.sol` name rather than an address.
+pub fn is_sns_name(input: &str) -> bool {
+    let input = input.trim().to_lowercase();
+    input.len() > ".sol".len() && input.ends_with(".sol")
+}
+
+fn hashed_name(name: &str) -> [u8; 32] {
+    hashv(&[HASH_PREFIX.as_bytes(), name.as_bytes()]).to_bytes()
+}
+
+/// Address of the name account for an already hashed name.
+pub fn name_account_key(hashed_name: &[u8; 32], class: Option<&Pubkey>, parent: Option<&Pubkey>) -> Pubkey {
+    let class = class.copied().unwrap_or_default();
+    let parent = parent.copied().unwrap_or_default();
+    Pubkey::find_program_address(&[hashed_name, class.as_ref(), parent.as_ref()], &NAME_PROGRAM_ID).0
+}
+
+/// Name account of `alice.sol` or `sub.alice.sol`.
+pub fn domain_key(name: &str) -> Result<Pubkey, String> {
+    let name = name.trim().to_lowercase();
+    let stem = name.strip_suffix(".sol").ok_or("Names must end in .sol")?;
+
+    match stem.split('.').collect::<Vec<_>>().as_slice() {
+        [domain] if !domain.is_empty() => Ok(name_account_key(&hashed_name(domain), None, Some(&SOL_TLD_AUTHORITY))),
+        [sub, domain] if !sub.is_empty() && !domain.is_empty() => {
+            let parent = name_account_key(&hashed_name(domain), None, Some(&SOL_TLD_AUTHORITY));
+            // Subdomain names are stored with a leading NUL byte.
+            Ok(name_account_key(&hashed_name(&format!("\0{}", sub)), None, Some(&parent)))
+        }
+        _ => Err(format!("Unsupported name: {}", name)),
+    }
+}
+
+/// Reverse lookup account holding the name of `domain_key`.
+pub fn reverse_lookup_key(domain_key: &Pubkey) -> Pubkey {
+    name_account_key(&hashed_name(&domain_key.to_string()), Some(&REVERSE_LOOKUP_CLASS), None)
+}
+
+/// SOL record account of a domain.
+pub fn sol_record_key(domain_key: &Pubkey) -> Pubkey {
+    name_account_key(&hashed_name(SOL_RECORD), None, Some(domain_key))
+}
+
+/// Mint of the NFT a tokenized domain is wrapped in.
+pub fn tokenized_mint(domain_key: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[b"tokenized_name", domain_key.as_ref()], &NAME_TOKENIZER_ID).0
+}
+
+/// Owner the name registry records for every tokenized domain.
+pub fn tokenizer_central_state() -> Pubkey {
+    Pubkey::find_program_address(&[NAME_TOKENIZER_ID.as_ref()], &NAME_TOKENIZER_ID).0
+}
+
+/// Account recording `owner`'s primary domain.
+pub fn favourite_domain_key(owner: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[b"favourite_domain", owner.as_ref()], &NAME_OFFERS_PROGRAM_ID).0
+}
+
+fn read_pubkey(data: &[u8], offset: usize) -> Option<Pubkey> {
+    data.get(offset..offset + 32)
+        .and_then(|bytes| <[u8; 32]>::try_from(bytes).ok())
+        .map(Pubkey::from)
+}
+
+/// Owner recorded in a name account.
+pub fn parse_name_owner(data: &[u8]) -> Option<Pubkey> {
+    read_pubkey(data, NAME_OWNER_OFFSET)
+}
+
+/// Destination of a SOL record, if `owner` signed it. The signature covers
+/// the hex of the destination followed by the record key, so a record left
+/// by a previous owner is ignored.
+pub fn parse_sol_record(data: &[u8], record_key: &Pubkey, owner: &Pubkey) -> Option<Pubkey> {
+    let body = data.get(NAME_REGISTRY_HEADER_LEN..NAME_REGISTRY_HEADER_LEN + SOL_RECORD_LEN)?;
+    let destination = read_pubkey(body, 0)?;
+    let signature = Signature::try_from(&body[32..]).ok()?;
+    let message: String = [destination.as_ref(), record_key.as_ref()]
+        .concat()
+        .iter()
+        .map(|byte| format!("{:02x}", byte))
+        .collect();
+    signature.verify(owner.as_ref(), message.as_bytes()).then_some(destination)
+}
+
+/// Name stored in a reverse lookup account as a Borsh string.
+pub fn parse_reverse_name(data: &[u8]) -> Option<String> {
+    let body = data.get(NAME_REGISTRY_HEADER_LEN..)?;
+    let len = u32::from_le_bytes(body.get(..4)?.try_into().ok()?) as usize;
+    let name = std::str::from_utf8(body.get(4..4usize.checked_add(len)?)?).ok()?;
+    Some(name.trim_start_matches('\0').to_string())
+}
+
+/// Address a `.sol` name resolves to, read from chain: its SOL record if
+/// the current owner signed one, otherwise the owner, which for tokenized
+/// domains is the NFT holder. Results are cached per cluster for a while.
+#[cfg(feature = "web")]
+pub async fn resolve_name(client: &solana_client_wasm::WasmClient, name: &str) -> Result<Pubkey, String> {
+    let name = name.trim().to_lowercase();
+    let cache_key = (current_cluster(), name.clone());
+    if let Some(address) = NAME_CACHE.with(|cache| cached(cache, &cache_key)) {
+        return Ok(address);
+    }
+
+    let key = domain_key(&name)?;
+    let owner = domain_owner(client, &key)
+        .await?
+        .ok_or_else(|| format!("{} is not registered", name))?;
+    let record_key = sol_record_key(&key);
+    let address = fetch_account_data(client, &record_key)
+        .await?
+        .and_then(|data| parse_sol_record(&data, &record_key, &owner))
+        .unwrap_or(owner);
+
+    let now = crate::telemetry::now_ms();
+    NAME_CACHE.with(|cache| cache.borrow_mut().insert(cache_key, (address, now)));
+    Ok(address)
+}
+
+/// Data of `key`, or `None` if the account doesn't exist.
+#[cfg(feature = "web")]
+async fn fetch_account_data(client: &solana_client_wasm::WasmClient, key: &Pubkey) -> Result<Option<Vec<u8>>, String> {
+    use super::rpc_call;
+    use solana_sdk::commitment_config::CommitmentConfig;
+
+    rpc_call("getAccountInfo", client.get_account_with_commitment(key, CommitmentConfig::confirmed()))
+        .await
+        .map(|account| account.map(|account| account.data))
+        .map_err(|e| format!("Failed to fetch name account: {:?}", e))
+}
+
+/// Who controls the domain: the owner in its name account, or the holder of
+/// its NFT if it is tokenized. `None` if the domain isn't registered.
+#[cfg(feature = "web")]
+async fn domain_owner(client: &solana_client_wasm::WasmClient, domain_key: &Pubkey) -> Result<Option<Pubkey>, String> {
+    let Some(data) = fetch_account_data(client, domain_key).await? else {
+        return Ok(None);
+    };
+    let owner = parse_name_owner(&data).ok_or("Invalid name account")?;
+    if owner != tokenizer_central_state() {
+        return Ok(Some(owner));
+    }
+    nft_holder(client, &tokenized_mint(domain_key))
+        .await?
+        .ok_or_else(|| "Tokenized domain has no NFT holder".to_string())
+        .map(Some)
+}
+
+/// Owner of the token account holding the one token of `mint`.
+#[cfg(feature = "web")]
+async fn nft_holder(client: &solana_client_wasm::WasmClient, mint: &Pubkey) -> Result<Option<Pubkey>, String> {
+    use super::{get_program_accounts, memcmp, TOKEN_PROGRAM_ID};
+    use solana_client_wasm::utils::rpc_filter::RpcFilterType;
+
+    let filters = vec![
+        RpcFilterType::DataSize(TOKEN_ACCOUNT_LEN),
+        memcmp(0, mint.as_ref()),
+        memcmp(TOKEN_AMOUNT_OFFSET, &1u64.to_le_bytes()),
+    ];
+    let accounts = get_program_accounts(client, &TOKEN_PROGRAM_ID, filters).await?;
+    Ok(accounts
+        .into_iter()
+        .next()
+        .and_then(|(_, account)| read_pubkey(&account.data, TOKEN_OWNER_OFFSET)))
+}
+
+/// Parses `input` as an address, resolving it first if it is a `.sol` name.
+#[cfg(feature = "web")]
+pub async fn resolve_address(client: &solana_client_wasm::WasmClient, input: &str) -> Result<Pubkey, String> {
+    use std::str::FromStr;
+
+    if is_sns_name(input) {
+        resolve_name(client, input).await
+    } else {
+        Pubkey::from_str(input.trim()).map_err(|_| "Invalid address".to_string())
+    }
+}
+
+/// Primary `.sol` name of `owner`, if it set one and still controls it.
+/// Results, including the lack of a name, are cached per cluster for a
+/// while.
+#[cfg(feature = "web")]
+pub async fn lookup_name(client: &solana_client_wasm::WasmClient, owner: &Pubkey) -> Result<Option<String>, String> {
+    let cache_key = (current_cluster(), *owner);
+    if let Some(name) = REVERSE_CACHE.with(|cache| cached(cache, &cache_key)) {
+        return Ok(name);
+    }
+
+    let mut name = None;
+    let favourite = fetch_account_data(client, &favourite_domain_key(owner)).await?;
+    if let Some(domain) = favourite.and_then(|data| read_pubkey(&data, FAVOURITE_DOMAIN_OFFSET)) {
+        if domain_owner(client, &domain).await?.as_ref() == Some(owner) {
+            name = fetch_account_data(client, &reverse_lookup_key(&domain))
+                .await?
+                .and_then(|data| parse_reverse_name(&data))
+                .map(|name| format!("{}.sol", name));
+        }
+    }
+
+    let now = crate::telemetry::now_ms();
+    REVERSE_CACHE.with(|cache| cache.borrow_mut().insert(cache_key, (name.clone(), now)));
+    Ok(name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use solana_sdk::signature::{Keypair, Signer};
+    use std::str::FromStr;
+
+    fn key(address: &str) -> Pubkey {
+        Pubkey::from_str(address).unwrap()
+    }
+
+    fn name_account(body: &[u8]) -> Vec<u8> {
+        let mut data = vec![0; NAME_REGISTRY_HEADER_LEN];
+        data.extend_from_slice(body);
+        data
+    }
+
+    #[test]
+    fn recognizes_sol_names() {
+        assert!(is_sns_name("bonfida.sol"));
+        assert!(is_sns_name(" Bonfida.SOL "));
+        assert!(!is_sns_name(".sol"));
+        assert!(!is_sns_name("Crf8hzfthWGbGbLTVCiqRqV5MVnbpHB1L9KQMd6gsinb"));
+    }
+
+    #[test]
+    fn derives_domain_keys() {
+        let bonfida = key("Crf8hzfthWGbGbLTVCiqRqV5MVnbpHB1L9KQMd6gsinb");
+        assert_eq!(domain_key("bonfida.sol").unwrap(), bonfida);
+        assert_eq!(domain_key(" Bonfida.sol ").unwrap(), bonfida);
+        assert_eq!(
+            domain_key("dex.bonfida.sol").unwrap(),
+            key("HoFfFXqFHAC8RP3duuQNzag1ieUwJRBv1HtRNiWFq4Qu")
+        );
+
+        assert!(domain_key("bonfida").is_err());
+        assert!(domain_key(".bonfida.sol").is_err());
+        assert!(domain_key("a.b.bonfida.sol").is_err());
+    }
+
+    #[test]
+    fn derives_record_keys() {
+        let bonfida = key("Crf8hzfthWGbGbLTVCiqRqV5MVnbpHB1L9KQMd6gsinb");
+        assert_eq!(reverse_lookup_key(&bonfida), key("DqgmWxe2PPrfy45Ja3UPyFGwcbRzkRuwXt3NyxjX8krg"));
+        assert_eq!(sol_record_key(&bonfida), key("5WCZ6uhXPXJ7UrzBvXBnE9biZykq1ezJ6JhYe6CHgA7d"));
+    }
+
+    #[test]
+    fn reads_name_owners() {
+        let owner = Pubkey::new_unique();
+        let mut data = vec![0; NAME_REGISTRY_HEADER_LEN];
+        data[NAME_OWNER_OFFSET..NAME_OWNER_OFFSET + 32].copy_from_slice(owner.as_ref());
+        assert_eq!(parse_name_owner(&data), Some(owner));
+        assert_eq!(parse_name_owner(&data[..40]), None);
+    }
+
+    #[test]
+    fn reads_reverse_names() {
+        let mut body = 7u32.to_le_bytes().to_vec();
+        body.extend_from_slice(b"bonfida");
+        assert_eq!(parse_reverse_name(&name_account(&body)).as_deref(), Some("bonfida"));
+
+        // Subdomains are stored with a leading NUL byte.
+        let mut body = 4u32.to_le_bytes().to_vec();
+        body.extend_from_slice(b"\0dex");
+        assert_eq!(parse_reverse_name(&name_account(&body)).as_deref(), Some("dex"));
+
+        let mut truncated = 8u32.to_le_bytes().to_vec();
+        truncated.extend_from_slice(b"bonfida");
+        assert_eq!(parse_reverse_name(&name_account(&truncated)), None);
+        assert_eq!(parse_reverse_name(&name_account(&u32::MAX.to_le_bytes())), None);
+    }
+
+    #[test]
+    fn accepts_sol_records_signed_by_the_owner() {
+        let owner = Keypair::new();
+        let destination = Pubkey::new_unique();
+        let record_key = sol_record_key(&Pubkey::new_unique());
+        let message: String = [destination.as_ref(), record_key.as_ref()]
+            .concat()
+            .iter()
+            .map(|byte| format!("{:02x}", byte))
+            .collect();
+
+        let mut body = destination.to_bytes().to_vec();
+        body.extend_from_slice(owner.sign_message(message.as_bytes()).as_ref());
+        let data = name_account(&body);
+        assert_eq!(parse_sol_record(&data, &record_key, &owner.pubkey()), Some(destination));
+
+        // Left by a previous owner, or copied from another record.
+        assert_eq!(parse_sol_record(&data, &record_key, &Pubkey::new_unique()), None);
+        assert_eq!(parse_sol_record(&data, &Pubkey::new_unique(), &owner.pubkey()), None);
+        assert_eq!(parse_sol_record(&data[..data.len() - 1], &record_key, &owner.pubkey()), None);
+    }
+}
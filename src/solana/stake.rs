@@ -0,0 +1,202 @@
+use solana_sdk::{native_token::lamports_to_sol, pubkey::Pubkey, stake::state::StakeStateV2};
+
+/// Offset of the withdrawer authority in a serialized stake account:
+/// 4-byte enum tag, 8-byte rent exempt reserve, 32-byte staker.
+pub const STAKE_WITHDRAWER_OFFSET: usize = 44;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct StakeAccount {
+    pub address: Pubkey,
+    pub lamports: u64,
+    pub state: StakeStateV2,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum StakeStatus {
+    Initialized,
+    Activating,
+    Active,
+    Deactivating,
+    Inactive,
+}
+
+impl StakeStatus {
+    pub fn label(&self) -> &'static str {
+        match self {
+            StakeStatus::Initialized => "Undelegated",
+            StakeStatus::Activating => "Activating",
+            StakeStatus::Active => "Active",
+            StakeStatus::Deactivating => "Deactivating",
+            StakeStatus::Inactive => "Inactive",
+        }
+    }
+}
+
+impl StakeAccount {
+    pub fn delegated_vote_account(&self) -> Option<Pubkey> {
+        self.state.delegation().map(|delegation| delegation.voter_pubkey)
+    }
+
+    /// Approximate status from activation/deactivation epochs. Warmup and
+    /// cooldown are treated as lasting a single epoch.
+    pub fn status(&self, current_epoch: u64) -> StakeStatus {
+        let Some(delegation) = self.state.delegation() else {
+            return StakeStatus::Initialized;
+        };
+        if delegation.deactivation_epoch != u64::MAX {
+            if delegation.deactivation_epoch >= current_epoch {
+                StakeStatus::Deactivating
+            } else {
+                StakeStatus::Inactive
+            }
+        } else if delegation.activation_epoch >= current_epoch {
+            StakeStatus::Activating
+        } else {
+            StakeStatus::Active
+        }
+    }
+
+    /// Lamports that can be withdrawn right now.
+    pub fn withdrawable(&self, current_epoch: u64) -> u64 {
+        match self.status(current_epoch) {
+            StakeStatus::Initialized | StakeStatus::Inactive => self.lamports,
+            _ => 0,
+        }
+    }
+
+    /// Checks that `lamports` can be split into a new account that is already
+    /// rent exempt. A delegated split must be at least `minimum_delegation`,
+    /// and unless the account is split in full, what stays behind must cover
+    /// its rent-exempt reserve (`rent` if unknown) plus that minimum.
+    pub fn check_split(&self, lamports: u64, rent: u64, minimum_delegation: u64) -> Result<(), String> {
+        if lamports == 0 || lamports > self.lamports {
+            return Err(format!(
+                "Split amount must be more than 0 and at most {} SOL",
+                lamports_to_sol(self.lamports)
+            ));
+        }
+        let minimum = if self.state.delegation().is_some() {
+            minimum_delegation
+        } else {
+            0
+        };
+        if lamports < minimum {
+            return Err(format!(
+                "Split amount must be at least the minimum delegation of {} SOL",
+                lamports_to_sol(minimum)
+            ));
+        }
+        let reserve = self
+            .state
+            .meta()
+            .map(|meta| meta.rent_exempt_reserve)
+            .unwrap_or(rent);
+        let remaining = self.lamports - lamports;
+        if remaining > 0 && remaining < reserve + minimum {
+            return Err(format!(
+                "Split the whole account or leave at least {} SOL in it",
+                lamports_to_sol(reserve + minimum)
+            ));
+        }
+        Ok(())
+    }
+
+    pub fn is_mergeable_with(&self, other: &StakeAccount, current_epoch: u64) -> bool {
+        if self.address == other.address {
+            return false;
+        }
+        match (self.status(current_epoch), other.status(current_epoch)) {
+            (StakeStatus::Active, StakeStatus::Active) => {
+                self.delegated_vote_account() == other.delegated_vote_account()
+            }
+            (
+                StakeStatus::Initialized | StakeStatus::Inactive,
+                StakeStatus::Initialized | StakeStatus::Inactive,
+            ) => true,
+            _ => false,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct ValidatorInfo {
+    pub vote_account: Pubkey,
+    pub identity: Pubkey,
+    pub commission: u8,
+    pub activated_stake: u64,
+    /// Share of leader slots skipped in the current epoch, if known.
+    pub skip_rate: Option<f64>,
+}
+
+/// Lists stake accounts whose withdraw authority is `withdrawer`.
+#[cfg(feature = "web")]
+pub async fn fetch_stake_accounts(
+    client: &solana_client_wasm::WasmClient,
+    withdrawer: &Pubkey,
+) -> Result<Vec<StakeAccount>, String> {
+    use super::{get_program_accounts, memcmp};
+    use solana_sdk::stake::program::id as stake_program_id;
+
+    let filters = vec![memcmp(STAKE_WITHDRAWER_OFFSET, withdrawer.as_ref())];
+    let accounts = get_program_accounts(client, &stake_program_id(), filters).await?;
+
+    let mut stakes = accounts
+        .into_iter()
+        .filter_map(|(address, account)| {
+            bincode::deserialize::<StakeStateV2>(&account.data)
+                .ok()
+                .map(|state| StakeAccount {
+                    address,
+                    lamports: account.lamports,
+                    state,
+                })
+        })
+        .collect::<Vec<_>>();
+    stakes.sort_by(|a, b| b.lamports.cmp(&a.lamports));
+    Ok(stakes)
+}
+
+/// Current, non-delinquent validators sorted by activated stake, with skip
+/// rates taken from block production when the RPC node provides it.
+#[cfg(feature = "web")]
+pub async fn fetch_validators(
+    client: &solana_client_wasm::WasmClient,
+) -> Result<Vec<ValidatorInfo>, String> {
+    use super::rpc_call;
+    use std::str::FromStr;
+
+    let vote_accounts = rpc_call("getVoteAccounts", client.get_vote_accounts())
+        .await
+        .map_err(|e| format!("Failed to fetch vote accounts: {:?}", e))?;
+
+    // Block production is best effort; validators are still listed without it.
+    let production = rpc_call("getBlockProduction", client.get_block_production())
+        .await
+        .ok()
+        .map(|response| response.value);
+
+    let mut validators = vote_accounts
+        .current
+        .into_iter()
+        .filter_map(|vote| {
+            let vote_account = Pubkey::from_str(&vote.vote_pubkey).ok()?;
+            let identity = Pubkey::from_str(&vote.node_pubkey).ok()?;
+            let skip_rate = production
+                .as_ref()
+                .and_then(|production| production.by_identity.get(&vote.node_pubkey))
+                .filter(|(leader_slots, _)| *leader_slots > 0)
+                .map(|(leader_slots, blocks)| {
+                    1.0 - (*blocks as f64 / *leader_slots as f64)
+                });
+            Some(ValidatorInfo {
+                vote_account,
+                identity,
+                commission: vote.commission,
+                activated_stake: vote.activated_stake,
+                skip_rate,
+            })
+        })
+        .collect::<Vec<_>>();
+    validators.sort_by(|a, b| b.activated_stake.cmp(&a.activated_stake));
+    Ok(validators)
+}
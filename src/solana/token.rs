@@ -0,0 +1,183 @@
+use solana_sdk::{
+    instruction::{AccountMeta, Instruction},
+    pubkey,
+    pubkey::Pubkey,
+    system_program,
+};
+
+pub const TOKEN_PROGRAM_ID: Pubkey = pubkey!("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA");
+pub const TOKEN_2022_PROGRAM_ID: Pubkey = pubkey!("TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb");
+pub const ASSOCIATED_TOKEN_PROGRAM_ID: Pubkey = pubkey!("ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL");
+pub const MEMO_PROGRAM_ID: Pubkey = pubkey!("MemoSq4gqABAXKb96qnH8TysNcWxMyWCqXgDLGmfcHr");
+
+pub const LAMPORTS_DECIMALS: u8 = 9;
+
+/// Byte offset of `decimals` in an SPL mint account.
+const MINT_DECIMALS_OFFSET: usize = 44;
+/// Size of an SPL mint without extensions.
+pub(crate) const MINT_LEN: usize = 82;
+/// Size of an SPL token account without extensions.
+pub(crate) const TOKEN_ACCOUNT_LEN: usize = 165;
+/// Token-2022 stores the account type right after the base account layout.
+pub(crate) const ACCOUNT_TYPE_OFFSET: usize = TOKEN_ACCOUNT_LEN;
+pub(crate) const ACCOUNT_TYPE_MINT: u8 = 1;
+pub(crate) const ACCOUNT_TYPE_ACCOUNT: u8 = 2;
+
+/// Mint, owner and amount of an SPL token account.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct TokenAccountBalance {
+    pub mint: Pubkey,
+    pub owner: Pubkey,
+    pub amount: u64,
+}
+
+pub fn is_token_program(program_id: &Pubkey) -> bool {
+    *program_id == TOKEN_PROGRAM_ID || *program_id == TOKEN_2022_PROGRAM_ID
+}
+
+pub fn associated_token_address(owner: &Pubkey, mint: &Pubkey, token_program: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(
+        &[owner.as_ref(), token_program.as_ref(), mint.as_ref()],
+        &ASSOCIATED_TOKEN_PROGRAM_ID,
+    )
+    .0
+}
+
+/// Creates `owner`'s associated token account for `mint` unless it exists.
+pub fn create_associated_token_account_idempotent(
+    payer: &Pubkey,
+    owner: &Pubkey,
+    mint: &Pubkey,
+    token_program: &Pubkey,
+) -> Instruction {
+    Instruction {
+        program_id: ASSOCIATED_TOKEN_PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new(*payer, true),
+            AccountMeta::new(associated_token_address(owner, mint, token_program), false),
+            AccountMeta::new_readonly(*owner, false),
+            AccountMeta::new_readonly(*mint, false),
+            AccountMeta::new_readonly(system_program::id(), false),
+            AccountMeta::new_readonly(*token_program, false),
+        ],
+        data: vec![1],
+    }
+}
+
+/// SPL `TransferChecked` between two token accounts.
+pub fn transfer_checked(
+    token_program: &Pubkey,
+    source: &Pubkey,
+    mint: &Pubkey,
+    destination: &Pubkey,
+    authority: &Pubkey,
+    amount: u64,
+    decimals: u8,
+) -> Instruction {
+    let mut data = Vec::with_capacity(10);
+    data.push(12);
+    data.extend_from_slice(&amount.to_le_bytes());
+    data.push(decimals);
+
+    Instruction {
+        program_id: *token_program,
+        accounts: vec![
+            AccountMeta::new(*source, false),
+            AccountMeta::new_readonly(*mint, false),
+            AccountMeta::new(*destination, false),
+            AccountMeta::new_readonly(*authority, true),
+        ],
+        data,
+    }
+}
+
+pub fn memo(text: &str) -> Instruction {
+    Instruction {
+        program_id: MEMO_PROGRAM_ID,
+        accounts: Vec::new(),
+        data: text.as_bytes().to_vec(),
+    }
+}
+
+pub fn mint_decimals(data: &[u8]) -> Result<u8, String> {
+    data.get(MINT_DECIMALS_OFFSET)
+        .copied()
+        .ok_or_else(|| "Account is not a token mint".to_string())
+}
+
+/// Reads an account owned by a token program; `None` for mints and other
+/// account types.
+pub fn parse_token_account(data: &[u8]) -> Option<TokenAccountBalance> {
+    let is_account = data.len() == TOKEN_ACCOUNT_LEN || data.get(ACCOUNT_TYPE_OFFSET) == Some(&ACCOUNT_TYPE_ACCOUNT);
+    if !is_account {
+        return None;
+    }
+    Some(TokenAccountBalance {
+        mint: Pubkey::try_from(data.get(0..32)?).ok()?,
+        owner: Pubkey::try_from(data.get(32..64)?).ok()?,
+        amount: u64::from_le_bytes(data.get(64..72)?.try_into().ok()?),
+    })
+}
+
+/// Converts a decimal string such as `"1.5"` into base units without going
+/// through floating point.
+pub fn parse_ui_amount(amount: &str, decimals: u8) -> Result<u64, String> {
+    let amount = amount.trim();
+    let (whole, fraction) = amount.split_once('.').unwrap_or((amount, ""));
+    if whole.is_empty() && fraction.is_empty()
+        || !whole.chars().all(|c| c.is_ascii_digit())
+        || !fraction.chars().all(|c| c.is_ascii_digit())
+    {
+        return Err("Invalid amount".to_string());
+    }
+    if fraction.len() > decimals as usize {
+        return Err(format!("Amount has more than {} decimal places", decimals));
+    }
+
+    let scale = decimal_scale(decimals)?;
+    let whole = if whole.is_empty() { 0 } else { whole.parse::<u64>().map_err(|_| "Amount is too large")? };
+    let fraction = format!("{:0<width$}", fraction, width = decimals as usize);
+    let fraction = if fraction.is_empty() { 0 } else { fraction.parse::<u64>().map_err(|_| "Invalid amount")? };
+
+    whole
+        .checked_mul(scale)
+        .and_then(|units| units.checked_add(fraction))
+        .ok_or_else(|| "Amount is too large".to_string())
+}
+
+/// Formats base units as a decimal string, trimming trailing zeros.
+pub fn format_ui_amount(amount: u64, decimals: u8) -> Result<String, String> {
+    let scale = decimal_scale(decimals)?;
+    let whole = amount / scale;
+    let fraction = amount % scale;
+    if fraction == 0 {
+        return Ok(whole.to_string());
+    }
+    let fraction = format!("{:0>width$}", fraction, width = decimals as usize);
+    Ok(format!("{}.{}", whole, fraction.trim_end_matches('0')))
+}
+
+/// Base units per whole token. Mints can declare up to 255 decimals, but
+/// more than 19 doesn't fit a u64 amount.
+fn decimal_scale(decimals: u8) -> Result<u64, String> {
+    10u64
+        .checked_pow(decimals as u32)
+        .ok_or_else(|| format!("Unsupported number of decimals: {}", decimals))
+}
+
+/// Token program and decimals of `mint`, read from chain.
+#[cfg(feature = "web")]
+pub async fn fetch_mint(
+    client: &solana_client_wasm::WasmClient,
+    mint: &Pubkey,
+) -> Result<(Pubkey, u8), String> {
+    use super::rpc_call;
+
+    let account = rpc_call("getAccountInfo", client.get_account(mint))
+        .await
+        .map_err(|e| format!("Failed to fetch token mint: {:?}", e))?;
+    if !is_token_program(&account.owner) {
+        return Err(format!("{} is not a token mint", mint));
+    }
+    Ok((account.owner, mint_decimals(&account.data)?))
+}
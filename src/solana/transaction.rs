@@ -0,0 +1,296 @@
+#[cfg(feature = "web")]
+use solana_client_wasm::WasmClient;
+#[cfg(feature = "web")]
+use solana_sdk::signature::Keypair;
+#[cfg(feature = "web")]
+use super::rpc_call;
+#[cfg(feature = "web")]
+use crate::telemetry::{error_kind, now_ms, timed};
+use super::NonceAccount;
+use solana_sdk::{
+    compute_budget::ComputeBudgetInstruction,
+    instruction::Instruction,
+    message::Message,
+    pubkey::Pubkey,
+    transaction::Transaction,
+};
+
+/// Builds an unsigned transaction paying fees from `payer`. A non-zero
+/// `priority_fee` (micro-lamports per compute unit) adds a compute unit price
+/// instruction, so validators prioritize it.
+pub fn build_transaction(instructions: &[Instruction], payer: &Pubkey, priority_fee: u64) -> Transaction {
+    let message = Message::new(&with_priority_fee(instructions, priority_fee), Some(payer));
+    Transaction::new_unsigned(message)
+}
+
+/// Prepends a compute unit price instruction to `instructions` when
+/// `micro_lamports` is non-zero.
+fn with_priority_fee(instructions: &[Instruction], micro_lamports: u64) -> Vec<Instruction> {
+    let mut prioritized = Vec::with_capacity(instructions.len() + 1);
+    if micro_lamports > 0 {
+        prioritized.push(ComputeBudgetInstruction::set_compute_unit_price(micro_lamports));
+    }
+    prioritized.extend_from_slice(instructions);
+    prioritized
+}
+
+/// Builds an unsigned transaction that uses `nonce`'s stored value instead of
+/// a recent blockhash, so it stays valid until the nonce is advanced. The
+/// advance instruction is prepended; the nonce authority must sign.
+/// `priority_fee` works as in [`build_transaction`].
+pub fn build_nonce_transaction(
+    instructions: &[Instruction],
+    payer: &Pubkey,
+    nonce: &NonceAccount,
+    priority_fee: u64,
+) -> Transaction {
+    let mut message = Message::new_with_nonce(
+        with_priority_fee(instructions, priority_fee),
+        Some(payer),
+        &nonce.address,
+        &nonce.authority,
+    );
+    message.recent_blockhash = nonce.nonce;
+    Transaction::new_unsigned(message)
+}
+
+/// Serializes a transaction as base64 wire format.
+pub fn encode_transaction(transaction: &Transaction) -> Result<String, String> {
+    bincode::serialize(transaction)
+        .map(|bytes| base64::encode(&bytes))
+        .map_err(|e| format!("Failed to serialize transaction: {}", e))
+}
+
+/// Parses a base64 wire-format transaction.
+pub fn decode_transaction(transaction_base64: &str) -> Result<Transaction, String> {
+    let bytes = base64::decode(transaction_base64.trim())
+        .map_err(|e| format!("Failed to decode transaction: {}", e))?;
+    bincode::deserialize(&bytes).map_err(|e| format!("Failed to deserialize transaction: {}", e))
+}
+
+/// Builds a transaction from `instructions`, has the connected wallet sign it
+/// and submits it through `client`. Returns the transaction signature.
+#[cfg(feature = "web")]
+pub async fn send_with_wallet(
+    client: &WasmClient,
+    payer: &Pubkey,
+    instructions: &[Instruction],
+) -> Result<String, String> {
+    send_with_wallet_and_signers(client, payer, instructions, &[]).await
+}
+
+/// Like [`send_with_wallet`], for transactions that also need signatures from
+/// locally generated keypairs (e.g. a new stake account). Those signatures are
+/// added before the wallet signs. The priority fee from settings is added.
+#[cfg(feature = "web")]
+#[tracing::instrument(
+    name = "send",
+    skip_all,
+    fields(instructions = instructions.len(), signers = signers.len(), priority_fee = tracing::field::Empty)
+)]
+pub async fn send_with_wallet_and_signers(
+    client: &WasmClient,
+    payer: &Pubkey,
+    instructions: &[Instruction],
+    signers: &[&Keypair],
+) -> Result<String, String> {
+    use crate::hooks::current_settings;
+
+    // Get recent blockhash from Solana
+    let blockhash = rpc_call("getLatestBlockhash", client.get_latest_blockhash())
+        .await
+        .map_err(|e| format!("Failed to get blockhash: {:?}", e))?;
+
+    let priority_fee = current_settings().priority_fee;
+    tracing::Span::current().record("priority_fee", priority_fee);
+    let mut transaction = build_transaction(instructions, payer, priority_fee);
+    transaction.message.recent_blockhash = blockhash;
+    if !signers.is_empty() {
+        transaction
+            .try_partial_sign(signers, blockhash)
+            .map_err(|e| format!("Failed to sign transaction: {}", e))?;
+    }
+
+    submit_with_wallet(client, &transaction).await
+}
+
+/// Has the connected wallet sign an already built transaction, keeping any
+/// signatures it already carries, and submits it using the saved send
+/// strategy.
+#[cfg(feature = "web")]
+pub async fn submit_with_wallet(client: &WasmClient, transaction: &Transaction) -> Result<String, String> {
+    use crate::hooks::sign_and_send_transaction;
+
+    timed(
+        "wallet.sign_and_send",
+        sign_and_send_transaction(client, encode_transaction(transaction)?),
+    )
+    .await
+}
+
+/// Has the connected wallet sign a transaction without submitting it, e.g. a
+/// durable nonce transaction to be sent later with [`send_signed_transaction`].
+#[cfg(feature = "web")]
+pub async fn sign_with_wallet(transaction: &Transaction) -> Result<Transaction, String> {
+    use crate::hooks::sign_transaction;
+
+    let signed_tx_base64 = timed("wallet.sign", sign_transaction(encode_transaction(transaction)?))
+        .await
+        .map_err(|e| format!("Failed to sign transaction: {}", e))?;
+    decode_transaction(&signed_tx_base64)
+}
+
+/// Submits a fully signed transaction through `client`.
+#[cfg(feature = "web")]
+pub async fn send_signed_transaction(client: &WasmClient, transaction: &Transaction) -> Result<String, String> {
+    if !transaction.is_signed() {
+        return Err("Transaction is missing signatures".to_string());
+    }
+    rpc_call("sendTransaction", client.send_transaction(transaction))
+        .await
+        .map(|signature| signature.to_string())
+        .map_err(|e| format!("Failed to send transaction: {:?}", e))
+}
+
+/// Builds one transaction per instruction list, has the wallet sign them all
+/// with a single prompt and submits each. Fails as a whole if signing fails;
+/// otherwise returns the send result of every transaction, in order. Each
+/// transaction carries the priority fee from settings.
+#[cfg(feature = "web")]
+#[tracing::instrument(
+    name = "send_all",
+    skip_all,
+    fields(transactions = instruction_lists.len(), priority_fee = tracing::field::Empty)
+)]
+pub async fn send_all_with_wallet(
+    client: &WasmClient,
+    payer: &Pubkey,
+    instruction_lists: &[Vec<Instruction>],
+) -> Result<Vec<Result<String, String>>, String> {
+    use crate::hooks::{current_settings, sign_all_transactions};
+
+    let blockhash = rpc_call("getLatestBlockhash", client.get_latest_blockhash())
+        .await
+        .map_err(|e| format!("Failed to get blockhash: {:?}", e))?;
+
+    let priority_fee = current_settings().priority_fee;
+    tracing::Span::current().record("priority_fee", priority_fee);
+    let mut transactions_base64 = Vec::with_capacity(instruction_lists.len());
+    for instructions in instruction_lists {
+        let mut transaction = build_transaction(instructions, payer, priority_fee);
+        transaction.message.recent_blockhash = blockhash;
+        transactions_base64.push(encode_transaction(&transaction)?);
+    }
+
+    let signed = timed("wallet.sign_all", sign_all_transactions(transactions_base64))
+        .await
+        .map_err(|e| format!("Failed to sign transactions: {}", e))?;
+
+    let mut results = Vec::with_capacity(signed.len());
+    for signed_tx_base64 in signed {
+        let result = match decode_transaction(&signed_tx_base64) {
+            Ok(signed_transaction) => send_signed_transaction(client, &signed_transaction).await,
+            Err(e) => Err(e),
+        };
+        results.push(result);
+    }
+    let failed = results.iter().filter(|result| result.is_err()).count();
+    tracing::info!(sent = results.len() - failed, failed, "Batch submitted");
+    Ok(results)
+}
+
+/// How often, and how many times, [`wait_for_confirmation`] polls.
+#[cfg(feature = "web")]
+const CONFIRMATION_POLL_MS: u64 = 1_000;
+#[cfg(feature = "web")]
+const CONFIRMATION_ATTEMPTS: usize = 60;
+
+/// Checks `signature` once against the commitment level picked in settings:
+/// `Some(Ok(slot))` once it reached it, `Some(Err(_))` if the transaction
+/// failed, and `None` while the cluster doesn't know it or it isn't there yet.
+/// Only recent transactions are found unless `search_history` is set.
+#[cfg(feature = "web")]
+pub async fn signature_status(
+    client: &WasmClient,
+    signature: &str,
+    search_history: bool,
+) -> Result<Option<Result<u64, String>>, String> {
+    use crate::hooks::{current_settings, Commitment};
+    use solana_sdk::signature::Signature;
+    use solana_extra_wasm::transaction_status::TransactionConfirmationStatus;
+    use std::str::FromStr;
+
+    let commitment = current_settings().commitment;
+    let signature = Signature::from_str(signature).map_err(|_| "Invalid signature".to_string())?;
+    let statuses = if search_history {
+        rpc_call("getSignatureStatuses", client.get_signature_statuses_with_history(&[signature])).await
+    } else {
+        rpc_call("getSignatureStatuses", client.get_signature_statuses(&[signature])).await
+    }
+    .map_err(|e| format!("Failed to get signature status: {:?}", e))?;
+    let Some(Some(status)) = statuses.into_iter().next() else {
+        return Ok(None);
+    };
+    if let Some(err) = status.err {
+        return Ok(Some(Err(format!("Transaction failed: {:?}", err))));
+    }
+    let reached = match (commitment, &status.confirmation_status) {
+        (_, Some(TransactionConfirmationStatus::Finalized)) => true,
+        (Commitment::Finalized, _) => false,
+        (_, Some(TransactionConfirmationStatus::Confirmed)) => true,
+        (Commitment::Confirmed, _) => false,
+        (Commitment::Processed, _) => true,
+    };
+    Ok(reached.then_some(Ok(status.slot)))
+}
+
+/// Polls until `signature` reaches at least the commitment level picked in
+/// settings and returns the slot it landed in. Fails if the transaction errored or is
+/// not confirmed in time. Set `search_history` for a signature from an earlier
+/// session, which may have dropped out of the recent status cache. Failed status
+/// requests are retried until the attempts run out.
+#[cfg(feature = "web")]
+#[tracing::instrument(name = "confirm", skip_all, fields(signature = %signature))]
+pub async fn wait_for_confirmation(client: &WasmClient, signature: &str, search_history: bool) -> Result<u64, String> {
+    use crate::hooks::current_settings;
+
+    let commitment = current_settings().commitment;
+    let started = now_ms();
+    let elapsed_ms = move || (now_ms() - started).round() as u64;
+
+    let mut rpc_error = None;
+    for attempt in 1..=CONFIRMATION_ATTEMPTS {
+        match signature_status(client, signature, search_history).await {
+            Ok(Some(Ok(slot))) => {
+                tracing::info!(
+                    commitment = commitment.key(),
+                    slot,
+                    polls = attempt,
+                    latency_ms = elapsed_ms(),
+                    "Transaction confirmed"
+                );
+                return Ok(slot);
+            }
+            Ok(Some(Err(error))) => {
+                tracing::warn!(
+                    latency_ms = elapsed_ms(),
+                    error_kind = error_kind(&error),
+                    "Transaction failed"
+                );
+                return Err(error);
+            }
+            Ok(None) => rpc_error = None,
+            Err(error) => {
+                tracing::debug!(polls = attempt, error_kind = error_kind(&error), "Signature status unavailable");
+                rpc_error = Some(error);
+            }
+        }
+        gloo::timers::future::sleep(std::time::Duration::from_millis(CONFIRMATION_POLL_MS)).await;
+    }
+    if let Some(error) = rpc_error {
+        tracing::warn!(latency_ms = elapsed_ms(), error_kind = error_kind(&error), "Transaction status unavailable");
+        return Err(error);
+    }
+    tracing::warn!(latency_ms = elapsed_ms(), error_kind = "timeout", "Transaction not confirmed in time");
+    Err("Transaction was not confirmed in time".to_string())
+}
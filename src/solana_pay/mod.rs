@@ -0,0 +1,111 @@
+mod transaction;
+mod transfer;
+
+pub use transaction::*;
+pub use transfer::*;
+
+/// URL scheme shared by transfer and transaction requests.
+pub const SOLANA_PAY_SCHEME: &str = "solana:";
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum SolanaPayRequest {
+    Transfer(TransferRequest),
+    Transaction(TransactionRequest),
+}
+
+impl SolanaPayRequest {
+    /// Transaction requests carry an http(s) link where transfer requests
+    /// carry a recipient address.
+    pub fn parse(url: &str) -> Result<Self, String> {
+        let rest = url
+            .trim()
+            .strip_prefix(SOLANA_PAY_SCHEME)
+            .ok_or("Not a Solana Pay link")?;
+        if rest.starts_with("http") {
+            TransactionRequest::parse(url).map(SolanaPayRequest::Transaction)
+        } else {
+            TransferRequest::parse(url).map(SolanaPayRequest::Transfer)
+        }
+    }
+}
+
+/// Percent-encodes a query value (RFC 3986 unreserved characters are kept).
+pub fn encode_component(value: &str) -> String {
+    let mut out = String::with_capacity(value.len());
+    for byte in value.bytes() {
+        match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => out.push(byte as char),
+            _ => out.push_str(&format!("%{:02X}", byte)),
+        }
+    }
+    out
+}
+
+/// Reverses [`encode_component`]. `+` is kept as is: Solana Pay links are
+/// URIs, not form data, so a space is always `%20`.
+pub fn decode_component(value: &str) -> Result<String, String> {
+    let bytes = value.as_bytes();
+    let mut out = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'%' => {
+                let hex = value
+                    .get(i + 1..i + 3)
+                    .and_then(|hex| u8::from_str_radix(hex, 16).ok())
+                    .ok_or_else(|| format!("Invalid percent-encoding in `{}`", value))?;
+                out.push(hex);
+                i += 3;
+            }
+            byte => {
+                out.push(byte);
+                i += 1;
+            }
+        }
+    }
+    String::from_utf8(out).map_err(|_| format!("Invalid UTF-8 in `{}`", value))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn components_round_trip() {
+        let value = "Café & Co: 100% / ok?";
+        let encoded = encode_component(value);
+        assert_eq!(encoded, "Caf%C3%A9%20%26%20Co%3A%20100%25%20%2F%20ok%3F");
+        assert_eq!(decode_component(&encoded).unwrap(), value);
+        assert_eq!(decode_component("a+b").unwrap(), "a+b");
+        assert!(decode_component("100%").is_err());
+        assert!(decode_component("%ZZ").is_err());
+    }
+
+    #[test]
+    fn tells_transfers_from_transaction_requests() {
+        let transfer = SolanaPayRequest::parse("solana:mvines9iiHiQTysrwkJjGf2gb9Ex9jXJX8ns3qwf2kN?amount=1");
+        assert!(matches!(transfer, Ok(SolanaPayRequest::Transfer(_))));
+
+        let link = "solana:https%3A%2F%2Fexample.com%2Fpay%3Forder%3D1";
+        let Ok(SolanaPayRequest::Transaction(request)) = SolanaPayRequest::parse(link) else {
+            panic!("expected a transaction request");
+        };
+        assert_eq!(request.link, "https://example.com/pay?order=1");
+        assert_eq!(request.host(), "example.com");
+
+        assert!(SolanaPayRequest::parse("bitcoin:abc").is_err());
+    }
+
+    #[test]
+    fn transaction_requests_need_https_unless_local() {
+        assert!(TransactionRequest::parse("solana:http://example.com/pay").is_err());
+        let local = TransactionRequest::parse("solana:http%3A%2F%2Flocalhost%3A8787%2F").unwrap();
+        assert_eq!(local.host(), "localhost:8787");
+        assert!(TransactionRequest::parse("solana:http://127.0.0.1/pay").is_ok());
+
+        assert!(TransactionRequest::parse("solana:http://localhost.evil.com/pay").is_err());
+        assert!(TransactionRequest::parse("solana:http://127.0.0.1.evil.com/pay").is_err());
+        assert!(TransactionRequest::parse("solana:http://localhost@evil.com/pay").is_err());
+        assert!(TransactionRequest::parse("solana:http://localhost:80@evil.com/pay").is_err());
+    }
+}
@@ -0,0 +1,380 @@
+use serde::{Deserialize, Serialize};
+use solana_sdk::{message::Message, pubkey::Pubkey, signature::Signature, transaction::Transaction};
+
+use super::{decode_component, SOLANA_PAY_SCHEME};
+use crate::solana::TokenAccountBalance;
+
+/// A Solana Pay transaction request: `solana:<https link>`. The merchant
+/// endpoint at `link` returns the transaction to sign.
+#[derive(Clone, Debug, PartialEq)]
+pub struct TransactionRequest {
+    pub link: String,
+}
+
+/// Response to the initial GET request.
+#[derive(Clone, Debug, Default, PartialEq, Deserialize)]
+pub struct TransactionRequestMetadata {
+    #[serde(default)]
+    pub label: Option<String>,
+    #[serde(default)]
+    pub icon: Option<String>,
+}
+
+#[derive(Serialize)]
+struct TransactionRequestBody<'a> {
+    account: &'a str,
+}
+
+/// Response to the POST request.
+#[derive(Clone, Debug, PartialEq, Deserialize)]
+pub struct TransactionRequestResponse {
+    /// Base64-encoded, serialized transaction.
+    pub transaction: String,
+    #[serde(default)]
+    pub message: Option<String>,
+}
+
+/// Outcome of checking a merchant transaction before it is shown to the wallet.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ValidatedTransaction {
+    pub transaction: Transaction,
+    /// Signers other than the wallet that already signed (e.g. the merchant).
+    pub cosigners: Vec<Pubkey>,
+    /// Simulated change of the wallet's SOL balance, in lamports.
+    pub balance_change: Option<i128>,
+    /// Simulated changes of the wallet's token balances, one per mint.
+    pub token_changes: Vec<TokenBalanceChange>,
+    pub logs: Vec<String>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct TokenBalanceChange {
+    pub mint: Pubkey,
+    /// In base units of the mint.
+    pub change: i128,
+    pub decimals: u8,
+}
+
+impl TransactionRequest {
+    pub fn parse(url: &str) -> Result<Self, String> {
+        let rest = url
+            .trim()
+            .strip_prefix(SOLANA_PAY_SCHEME)
+            .ok_or("Not a Solana Pay link")?;
+        let link = decode_component(rest)?;
+
+        let request = TransactionRequest { link };
+        // Plain http is only accepted for a local mock merchant.
+        match request.link.split_once("://").map(|(scheme, _)| scheme) {
+            Some("https") => Ok(request),
+            Some("http") if request.is_local() => Ok(request),
+            _ => Err("Transaction request links must use https".to_string()),
+        }
+    }
+
+    pub fn host(&self) -> &str {
+        let without_scheme = self.link.split_once("://").map(|(_, rest)| rest).unwrap_or(&self.link);
+        without_scheme.split(['/', '?', '#']).next().unwrap_or(without_scheme)
+    }
+
+    /// Whether the host, without its port, is exactly `localhost` or
+    /// `127.0.0.1`.
+    fn is_local(&self) -> bool {
+        let host = self.host();
+        let name = match host.rsplit_once(':') {
+            Some((name, port)) if port.chars().all(|c| c.is_ascii_digit()) => name,
+            _ => host,
+        };
+        matches!(name, "localhost" | "127.0.0.1")
+    }
+}
+
+/// Decodes the merchant's transaction and checks that it only asks the wallet
+/// for what a payment needs: the wallet pays the fee, and every other required
+/// signer has already signed.
+pub fn check_transaction(transaction_base64: &str, wallet: &Pubkey) -> Result<ValidatedTransaction, String> {
+    let bytes = base64::decode(transaction_base64.trim())
+        .map_err(|e| format!("Merchant returned invalid base64: {}", e))?;
+    let transaction: Transaction = bincode::deserialize(&bytes)
+        .map_err(|_| "Merchant returned an unsupported transaction".to_string())?;
+
+    let message = &transaction.message;
+    if message.account_keys.first() != Some(wallet) {
+        return Err("The transaction fee payer is not your wallet".to_string());
+    }
+
+    let message_bytes = message.serialize();
+    let num_signers = message.header.num_required_signatures as usize;
+    let mut cosigners = Vec::new();
+    for (index, key) in message.account_keys.iter().take(num_signers).enumerate() {
+        if key == wallet {
+            continue;
+        }
+        let signature = transaction.signatures.get(index).copied().unwrap_or_default();
+        if signature == Signature::default() || !signature.verify(key.as_ref(), &message_bytes) {
+            return Err(format!("The transaction requires an unexpected signer: {}", key));
+        }
+        cosigners.push(*key);
+    }
+
+    Ok(ValidatedTransaction {
+        transaction,
+        cosigners,
+        balance_change: None,
+        token_changes: Vec::new(),
+        logs: Vec::new(),
+    })
+}
+
+/// Accounts other than the fee payer that the transaction may write, and so
+/// the only token accounts whose balances it can change.
+fn writable_accounts(message: &Message) -> Vec<Pubkey> {
+    let header = &message.header;
+    let num_signers = header.num_required_signatures as usize;
+    let writable_signers = num_signers.saturating_sub(header.num_readonly_signed_accounts as usize);
+    let writable_unsigned = message
+        .account_keys
+        .len()
+        .saturating_sub(header.num_readonly_unsigned_accounts as usize);
+    message
+        .account_keys
+        .iter()
+        .enumerate()
+        .filter(|(index, _)| {
+            *index != 0 && (*index < writable_signers || (num_signers..writable_unsigned).contains(index))
+        })
+        .map(|(_, key)| *key)
+        .collect()
+}
+
+/// Net change per mint of the token accounts `wallet` owns, from each
+/// account's state before and after the transaction. An account the wallet
+/// stops owning counts as spent.
+fn token_balance_changes(
+    wallet: &Pubkey,
+    accounts: &[(Option<TokenAccountBalance>, Option<TokenAccountBalance>)],
+) -> Vec<(Pubkey, i128)> {
+    let mut changes: Vec<(Pubkey, i128)> = Vec::new();
+    for (before, after) in accounts {
+        for (account, sign) in [(before, -1), (after, 1)] {
+            let Some(account) = account.filter(|account| account.owner == *wallet) else {
+                continue;
+            };
+            let amount = sign * account.amount as i128;
+            match changes.iter_mut().find(|(mint, _)| *mint == account.mint) {
+                Some((_, change)) => *change += amount,
+                None => changes.push((account.mint, amount)),
+            }
+        }
+    }
+    changes.retain(|(_, change)| *change != 0);
+    changes
+}
+
+/// GET the merchant's label and icon.
+#[cfg(feature = "web")]
+pub async fn fetch_metadata(request: &TransactionRequest) -> Result<TransactionRequestMetadata, String> {
+    use gloo::net::http::Request;
+
+    let response = Request::get(&request.link)
+        .header("Accept", "application/json")
+        .send()
+        .await
+        .map_err(|e| format!("Failed to reach merchant: {}", e))?;
+    if !response.ok() {
+        return Err(format!("Merchant responded with status {}", response.status()));
+    }
+    response
+        .json::<TransactionRequestMetadata>()
+        .await
+        .map_err(|e| format!("Merchant returned invalid metadata: {}", e))
+}
+
+/// POST the wallet address and receive the transaction to sign.
+#[cfg(feature = "web")]
+pub async fn fetch_transaction(
+    request: &TransactionRequest,
+    account: &Pubkey,
+) -> Result<TransactionRequestResponse, String> {
+    use gloo::net::http::Request;
+
+    let account = account.to_string();
+    let response = Request::post(&request.link)
+        .header("Accept", "application/json")
+        .json(&TransactionRequestBody { account: &account })
+        .map_err(|e| format!("Failed to encode request: {}", e))?
+        .send()
+        .await
+        .map_err(|e| format!("Failed to reach merchant: {}", e))?;
+    if !response.ok() {
+        return Err(format!("Merchant responded with status {}", response.status()));
+    }
+    response
+        .json::<TransactionRequestResponse>()
+        .await
+        .map_err(|e| format!("Merchant returned an invalid response: {}", e))
+}
+
+/// Runs [`check_transaction`] and simulates the result to report how the
+/// wallet's SOL and token balances would change.
+#[cfg(feature = "web")]
+pub async fn validate_transaction(
+    client: &solana_client_wasm::WasmClient,
+    transaction_base64: &str,
+    wallet: &Pubkey,
+) -> Result<ValidatedTransaction, String> {
+    use solana_client_wasm::utils::rpc_config::{
+        RpcSimulateTransactionAccountsConfig, RpcSimulateTransactionConfig,
+    };
+    use crate::solana::{fetch_mint, is_token_program, parse_token_account, rpc_call};
+    use solana_extra_wasm::account_decoder::{UiAccountData, UiAccountEncoding};
+
+    let mut validated = check_transaction(transaction_base64, wallet)?;
+    let watched = writable_accounts(&validated.transaction.message);
+
+    let balance_before = rpc_call("getBalance", client.get_balance(wallet))
+        .await
+        .map_err(|e| format!("Failed to get balance: {:?}", e))?;
+    let accounts_before = if watched.is_empty() {
+        Vec::new()
+    } else {
+        rpc_call("getMultipleAccounts", client.get_multiple_accounts(&watched))
+            .await
+            .map_err(|e| format!("Failed to fetch accounts: {:?}", e))?
+    };
+
+    let mut addresses = vec![wallet.to_string()];
+    addresses.extend(watched.iter().map(|key| key.to_string()));
+    let config = RpcSimulateTransactionConfig {
+        sig_verify: false,
+        accounts: Some(RpcSimulateTransactionAccountsConfig {
+            encoding: Some(UiAccountEncoding::Base64),
+            addresses,
+        }),
+        ..Default::default()
+    };
+    let simulation = rpc_call(
+        "simulateTransaction",
+        client.simulate_transaction_with_config(&validated.transaction, config),
+    )
+    .await
+    .map_err(|e| format!("Failed to simulate transaction: {:?}", e))?;
+
+    if let Some(err) = simulation.err {
+        return Err(format!("Transaction simulation failed: {:?}", err));
+    }
+    validated.logs = simulation.logs.unwrap_or_default();
+
+    let mut accounts_after = simulation.accounts.unwrap_or_default().into_iter();
+    validated.balance_change = accounts_after
+        .next()
+        .flatten()
+        .map(|account| account.lamports as i128 - balance_before as i128);
+
+    let token_accounts: Vec<_> = accounts_before
+        .into_iter()
+        .zip(accounts_after)
+        .map(|(before, after)| {
+            let before = before
+                .filter(|account| is_token_program(&account.owner))
+                .and_then(|account| parse_token_account(&account.data));
+            let after = after
+                .filter(|account| account.owner.parse::<Pubkey>().is_ok_and(|owner| is_token_program(&owner)))
+                .and_then(|account| match account.data {
+                    UiAccountData::Binary(data, UiAccountEncoding::Base64) => base64::decode(data).ok(),
+                    _ => None,
+                })
+                .and_then(|data| parse_token_account(&data));
+            (before, after)
+        })
+        .collect();
+    for (mint, change) in token_balance_changes(wallet, &token_accounts) {
+        let (_, decimals) = fetch_mint(client, &mint).await?;
+        validated.token_changes.push(TokenBalanceChange { mint, change, decimals });
+    }
+
+    Ok(validated)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solana::parse_token_account;
+    use solana_sdk::{
+        instruction::{AccountMeta, Instruction},
+        system_program,
+    };
+
+    fn token_account(mint: &Pubkey, owner: &Pubkey, amount: u64) -> Vec<u8> {
+        let mut data = vec![0; 165];
+        data[0..32].copy_from_slice(mint.as_ref());
+        data[32..64].copy_from_slice(owner.as_ref());
+        data[64..72].copy_from_slice(&amount.to_le_bytes());
+        data
+    }
+
+    #[test]
+    fn reads_token_accounts() {
+        let mint = Pubkey::new_unique();
+        let owner = Pubkey::new_unique();
+        let data = token_account(&mint, &owner, 42);
+        assert_eq!(
+            parse_token_account(&data),
+            Some(TokenAccountBalance { mint, owner, amount: 42 })
+        );
+
+        // Token-2022 account with extensions, then a mint padded to the same length.
+        let mut extended = data.clone();
+        extended.extend([2, 0, 0]);
+        assert!(parse_token_account(&extended).is_some());
+        extended[165] = 1;
+        assert_eq!(parse_token_account(&extended), None);
+        assert_eq!(parse_token_account(&data[..82]), None);
+    }
+
+    #[test]
+    fn watches_writable_accounts_except_the_fee_payer() {
+        let wallet = Pubkey::new_unique();
+        let cosigner = Pubkey::new_unique();
+        let writable = Pubkey::new_unique();
+        let readonly = Pubkey::new_unique();
+        let program = Pubkey::new_unique();
+        let instruction = Instruction::new_with_bytes(
+            program,
+            &[],
+            vec![
+                AccountMeta::new_readonly(cosigner, true),
+                AccountMeta::new(writable, false),
+                AccountMeta::new_readonly(readonly, false),
+                AccountMeta::new_readonly(system_program::id(), false),
+            ],
+        );
+        let message = Message::new(&[instruction], Some(&wallet));
+        assert_eq!(writable_accounts(&message), vec![writable]);
+    }
+
+    #[test]
+    fn sums_token_balance_changes_per_mint() {
+        let wallet = Pubkey::new_unique();
+        let merchant = Pubkey::new_unique();
+        let usdc = Pubkey::new_unique();
+        let bonk = Pubkey::new_unique();
+        let balance = |mint: Pubkey, owner: Pubkey, amount: u64| Some(TokenAccountBalance { mint, owner, amount });
+
+        let changes = token_balance_changes(
+            &wallet,
+            &[
+                // Spent from one account, received in a new one.
+                (balance(usdc, wallet, 100), balance(usdc, wallet, 40)),
+                (None, balance(usdc, wallet, 10)),
+                // Handed over to the merchant.
+                (balance(bonk, wallet, 7), balance(bonk, merchant, 7)),
+                // Not the wallet's.
+                (balance(usdc, merchant, 0), balance(usdc, merchant, 60)),
+                // Unchanged.
+                (balance(bonk, wallet, 5), balance(bonk, wallet, 5)),
+            ],
+        );
+        assert_eq!(changes, vec![(usdc, -50), (bonk, -7)]);
+    }
+}
@@ -0,0 +1,351 @@
+use solana_sdk::{
+    instruction::{AccountMeta, Instruction},
+    pubkey::Pubkey,
+    system_instruction,
+};
+use std::str::FromStr;
+
+use super::{decode_component, encode_component, SOLANA_PAY_SCHEME};
+use crate::solana::{
+    associated_token_address, create_associated_token_account_idempotent, memo, parse_ui_amount,
+    transfer_checked, LAMPORTS_DECIMALS,
+};
+
+/// A Solana Pay transfer request:
+/// `solana:<recipient>?amount=&spl-token=&reference=&label=&message=&memo=`.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct TransferRequest {
+    pub recipient: Pubkey,
+    /// Decimal amount in SOL or in whole tokens, kept as text so no precision
+    /// is lost before the mint decimals are known.
+    pub amount: Option<String>,
+    pub spl_token: Option<Pubkey>,
+    pub references: Vec<Pubkey>,
+    pub label: Option<String>,
+    pub message: Option<String>,
+    pub memo: Option<String>,
+}
+
+/// Token program and decimals of the mint being transferred.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct MintInfo {
+    pub token_program: Pubkey,
+    pub decimals: u8,
+}
+
+impl TransferRequest {
+    pub fn parse(url: &str) -> Result<Self, String> {
+        let rest = url
+            .trim()
+            .strip_prefix(SOLANA_PAY_SCHEME)
+            .ok_or("Not a Solana Pay link")?;
+        let (path, query) = rest.split_once('?').unwrap_or((rest, ""));
+
+        let recipient = Pubkey::from_str(&decode_component(path)?)
+            .map_err(|_| "Invalid recipient in Solana Pay link".to_string())?;
+        let mut request = TransferRequest {
+            recipient,
+            ..Default::default()
+        };
+
+        for pair in query.split('&').filter(|pair| !pair.is_empty()) {
+            let (key, value) = pair.split_once('=').unwrap_or((pair, ""));
+            let value = decode_component(value)?;
+            match key {
+                "amount" => {
+                    // Only the format is checked here; the scale is applied once
+                    // the mint decimals are known.
+                    if !is_decimal(&value) {
+                        return Err(format!("Invalid amount `{}`", value));
+                    }
+                    request.amount = Some(value);
+                }
+                "spl-token" => {
+                    let mint = Pubkey::from_str(&value).map_err(|_| "Invalid spl-token mint".to_string())?;
+                    request.spl_token = Some(mint);
+                }
+                "reference" => {
+                    let reference = Pubkey::from_str(&value).map_err(|_| "Invalid reference key".to_string())?;
+                    request.references.push(reference);
+                }
+                "label" => request.label = Some(value),
+                "message" => request.message = Some(value),
+                "memo" => request.memo = Some(value),
+                // Unknown parameters are ignored, as the spec allows extensions.
+                _ => {}
+            }
+        }
+
+        Ok(request)
+    }
+
+    pub fn to_url(&self) -> String {
+        let mut params = Vec::new();
+        if let Some(amount) = &self.amount {
+            params.push(format!("amount={}", encode_component(amount)));
+        }
+        if let Some(mint) = &self.spl_token {
+            params.push(format!("spl-token={}", mint));
+        }
+        for reference in &self.references {
+            params.push(format!("reference={}", reference));
+        }
+        if let Some(label) = &self.label {
+            params.push(format!("label={}", encode_component(label)));
+        }
+        if let Some(message) = &self.message {
+            params.push(format!("message={}", encode_component(message)));
+        }
+        if let Some(memo) = &self.memo {
+            params.push(format!("memo={}", encode_component(memo)));
+        }
+
+        if params.is_empty() {
+            format!("{}{}", SOLANA_PAY_SCHEME, self.recipient)
+        } else {
+            format!("{}{}?{}", SOLANA_PAY_SCHEME, self.recipient, params.join("&"))
+        }
+    }
+
+    /// Amount in lamports or token base units. `mint` must be given for SPL
+    /// token requests.
+    pub fn amount_base_units(&self, mint: Option<MintInfo>) -> Result<u64, String> {
+        let amount = self.amount.as_deref().ok_or("Amount is required")?;
+        let decimals = mint.map(|mint| mint.decimals).unwrap_or(LAMPORTS_DECIMALS);
+        parse_ui_amount(amount, decimals)
+    }
+
+    /// Instructions paying this request from `payer`: for tokens the
+    /// recipient's associated account is created if needed, then an optional
+    /// memo immediately before the SOL or token transfer, which carries the
+    /// reference keys.
+    pub fn instructions(&self, payer: &Pubkey, mint: Option<MintInfo>) -> Result<Vec<Instruction>, String> {
+        let amount = self.amount_base_units(mint)?;
+        if amount == 0 {
+            return Err("Amount must be greater than 0".to_string());
+        }
+
+        let mut instructions = Vec::new();
+        let mut transfer = match (self.spl_token, mint) {
+            (None, _) => system_instruction::transfer(payer, &self.recipient, amount),
+            (Some(mint_address), Some(mint)) => {
+                instructions.push(create_associated_token_account_idempotent(
+                    payer,
+                    &self.recipient,
+                    &mint_address,
+                    &mint.token_program,
+                ));
+                transfer_checked(
+                    &mint.token_program,
+                    &associated_token_address(payer, &mint_address, &mint.token_program),
+                    &mint_address,
+                    &associated_token_address(&self.recipient, &mint_address, &mint.token_program),
+                    payer,
+                    amount,
+                    mint.decimals,
+                )
+            }
+            (Some(_), None) => return Err("Token mint details are required".to_string()),
+        };
+        transfer
+            .accounts
+            .extend(self.references.iter().map(|reference| AccountMeta::new_readonly(*reference, false)));
+        // The spec puts the memo right before the transfer it describes.
+        if let Some(text) = &self.memo {
+            instructions.push(memo(text));
+        }
+        instructions.push(transfer);
+
+        Ok(instructions)
+    }
+}
+
+/// Non-negative decimal without exponent, as required by the spec.
+fn is_decimal(value: &str) -> bool {
+    let (whole, fraction) = value.split_once('.').unwrap_or((value, ""));
+    !whole.is_empty()
+        && whole.chars().all(|c| c.is_ascii_digit())
+        && fraction.chars().all(|c| c.is_ascii_digit())
+        && !value.ends_with('.')
+}
+
+/// Mint details for the request's token, if it has one.
+#[cfg(feature = "web")]
+pub async fn fetch_mint_info(
+    client: &solana_client_wasm::WasmClient,
+    request: &TransferRequest,
+) -> Result<Option<MintInfo>, String> {
+    use crate::solana::fetch_mint;
+
+    match &request.spl_token {
+        Some(mint) => {
+            let (token_program, decimals) = fetch_mint(client, mint).await?;
+            Ok(Some(MintInfo { token_program, decimals }))
+        }
+        None => Ok(None),
+    }
+}
+
+/// Oldest transaction signature that includes `reference`, if any. Used to
+/// detect that a payment request has been paid.
+#[cfg(feature = "web")]
+pub async fn find_reference(
+    client: &solana_client_wasm::WasmClient,
+    reference: &Pubkey,
+) -> Result<Option<String>, String> {
+    use crate::solana::rpc_call;
+
+    let signatures = rpc_call("getSignaturesForAddress", client.get_signatures_for_address(reference))
+        .await
+        .map_err(|e| format!("Failed to fetch signatures: {:?}", e))?;
+    Ok(signatures
+        .into_iter()
+        .filter(|status| status.err.is_none())
+        .last()
+        .map(|status| status.signature))
+}
+
+/// Checks that the transaction `signature` pays `request`, like the Solana
+/// Pay `validateTransfer`: it succeeded, and the recipient's SOL balance, or
+/// its balance of the requested token, grew by at least the requested amount
+/// (by anything, when the payer picks the amount).
+#[cfg(feature = "web")]
+pub async fn validate_transfer(
+    client: &solana_client_wasm::WasmClient,
+    signature: &str,
+    request: &TransferRequest,
+) -> Result<(), String> {
+    use crate::solana::rpc_call;
+    use solana_client_wasm::utils::rpc_config::RpcTransactionConfig;
+    use solana_extra_wasm::transaction_status::{UiTransactionEncoding, UiTransactionTokenBalance};
+    use solana_sdk::signature::Signature;
+
+    let signature = Signature::from_str(signature).map_err(|_| "Invalid signature".to_string())?;
+    let config = RpcTransactionConfig {
+        encoding: Some(UiTransactionEncoding::Base64),
+        max_supported_transaction_version: Some(0),
+        ..Default::default()
+    };
+    let confirmed = rpc_call("getTransaction", client.get_transaction_with_config(&signature, config))
+        .await
+        .map_err(|e| format!("Failed to fetch transaction: {:?}", e))?;
+    let meta = confirmed.transaction.meta.ok_or("Transaction has no status")?;
+    if let Some(err) = meta.err {
+        return Err(format!("Transaction failed: {:?}", err));
+    }
+
+    let (received, decimals) = match request.spl_token {
+        None => {
+            let transaction = confirmed
+                .transaction
+                .transaction
+                .decode()
+                .ok_or("Failed to decode transaction")?;
+            let index = transaction
+                .message
+                .static_account_keys()
+                .iter()
+                .position(|key| *key == request.recipient)
+                .ok_or("Recipient not found in transaction")?;
+            let before = meta.pre_balances.get(index).copied().unwrap_or(0);
+            let after = meta.post_balances.get(index).copied().unwrap_or(0);
+            (after.saturating_sub(before), LAMPORTS_DECIMALS)
+        }
+        Some(mint) => {
+            let recipient = request.recipient.to_string();
+            let mint = mint.to_string();
+            // The recipient's balance of the mint, in base units, and the
+            // mint decimals.
+            let balance = |balances: Option<Vec<UiTransactionTokenBalance>>| {
+                balances
+                    .unwrap_or_default()
+                    .into_iter()
+                    .filter(|balance| {
+                        balance.mint == mint && Option::<String>::from(balance.owner.clone()) == Some(recipient.clone())
+                    })
+                    .map(|balance| {
+                        let amount = balance.ui_token_amount.amount.parse::<u64>().unwrap_or(0);
+                        (amount, balance.ui_token_amount.decimals)
+                    })
+                    .fold((0u64, None), |(total, _), (amount, decimals)| {
+                        (total.saturating_add(amount), Some(decimals))
+                    })
+            };
+            let (before, _) = balance(meta.pre_token_balances.into());
+            let (after, decimals) = balance(meta.post_token_balances.into());
+            let decimals = decimals.ok_or("Recipient did not receive the requested token")?;
+            (after.saturating_sub(before), decimals)
+        }
+    };
+
+    let expected = match &request.amount {
+        Some(amount) => parse_ui_amount(amount, decimals)?,
+        // Without a requested amount any payment counts.
+        None => 1,
+    };
+    if received < expected {
+        return Err("Amount not transferred".to_string());
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const RECIPIENT: &str = "mvines9iiHiQTysrwkJjGf2gb9Ex9jXJX8ns3qwf2kN";
+    const USDC: &str = "EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v";
+
+    #[test]
+    fn parses_the_spec_example() {
+        let url = format!(
+            "solana:{}?amount=0.01&spl-token={}&reference=82ZJ7nbGpixjeDCmEhUcmwXYfvurzAgGdtSMuHnUgyny&label=Michael&message=Thanks%20for%20all%20the%20fish&memo=OrderId12345",
+            RECIPIENT, USDC
+        );
+        let request = TransferRequest::parse(&url).unwrap();
+        assert_eq!(request.recipient.to_string(), RECIPIENT);
+        assert_eq!(request.amount.as_deref(), Some("0.01"));
+        assert_eq!(request.spl_token.map(|mint| mint.to_string()).as_deref(), Some(USDC));
+        assert_eq!(request.references.len(), 1);
+        assert_eq!(request.label.as_deref(), Some("Michael"));
+        assert_eq!(request.message.as_deref(), Some("Thanks for all the fish"));
+        assert_eq!(request.memo.as_deref(), Some("OrderId12345"));
+    }
+
+    #[test]
+    fn to_url_round_trips() {
+        let request = TransferRequest {
+            recipient: Pubkey::from_str(RECIPIENT).unwrap(),
+            amount: Some("1.5".to_string()),
+            spl_token: Some(Pubkey::from_str(USDC).unwrap()),
+            references: vec![Pubkey::new_unique(), Pubkey::new_unique()],
+            label: Some("Café & Co".to_string()),
+            message: Some("Order #42?".to_string()),
+            memo: Some("a=b".to_string()),
+        };
+        let url = request.to_url();
+        assert!(url.starts_with(&format!("solana:{}?amount=1.5&spl-token=", RECIPIENT)));
+        assert!(url.contains("label=Caf%C3%A9%20%26%20Co"));
+        assert_eq!(TransferRequest::parse(&url).unwrap(), request);
+    }
+
+    #[test]
+    fn recipient_only() {
+        let url = format!("solana:{}", RECIPIENT);
+        let request = TransferRequest::parse(&url).unwrap();
+        assert_eq!(request.amount, None);
+        assert_eq!(request.to_url(), url);
+    }
+
+    #[test]
+    fn rejects_invalid_links() {
+        assert!(TransferRequest::parse(RECIPIENT).is_err());
+        assert!(TransferRequest::parse("solana:not-an-address").is_err());
+        for amount in ["-1", "1e3", "1.", ".5", "1,5"] {
+            let url = format!("solana:{}?amount={}", RECIPIENT, amount);
+            assert!(TransferRequest::parse(&url).is_err(), "{}", amount);
+        }
+        let url = format!("solana:{}?reference=nope", RECIPIENT);
+        assert!(TransferRequest::parse(&url).is_err());
+    }
+}
@@ -0,0 +1,310 @@
+use solana_sdk::{
+    instruction::{AccountMeta, Instruction},
+    message::Message,
+    pubkey::Pubkey,
+    system_program,
+};
+
+use super::{proposal_address, transaction_address, vault_address, VaultTransaction, SQUADS_PROGRAM_ID};
+use crate::anchor::sighash;
+
+/// Serializes `instructions`, executed by the vault, as the compact
+/// `TransactionMessage` Squads expects in `vault_transaction_create`.
+/// Vectors use single-byte lengths and instruction data a `u16` length.
+pub fn vault_transaction_message(vault: &Pubkey, instructions: &[Instruction]) -> Result<Vec<u8>, String> {
+    let message = Message::new(instructions, Some(vault));
+    let header = message.header;
+    let num_keys = message.account_keys.len();
+    if num_keys > u8::MAX as usize || message.instructions.len() > u8::MAX as usize {
+        return Err("Too many accounts or instructions for one vault transaction".to_string());
+    }
+
+    let num_signers = header.num_required_signatures;
+    let num_writable_signers = num_signers - header.num_readonly_signed_accounts;
+    let num_writable_non_signers =
+        (num_keys as u8 - num_signers) - header.num_readonly_unsigned_accounts;
+
+    let mut data = vec![num_signers, num_writable_signers, num_writable_non_signers];
+    data.push(num_keys as u8);
+    for key in &message.account_keys {
+        data.extend_from_slice(key.as_ref());
+    }
+    data.push(message.instructions.len() as u8);
+    for instruction in &message.instructions {
+        let data_len = u16::try_from(instruction.data.len())
+            .map_err(|_| "Instruction data is too large".to_string())?;
+        data.push(instruction.program_id_index);
+        data.push(instruction.accounts.len() as u8);
+        data.extend_from_slice(&instruction.accounts);
+        data.extend_from_slice(&data_len.to_le_bytes());
+        data.extend_from_slice(&instruction.data);
+    }
+    // No address lookup tables.
+    data.push(0);
+    Ok(data)
+}
+
+fn instruction_data(name: &str) -> Vec<u8> {
+    sighash("global", name).to_vec()
+}
+
+fn push_memo(data: &mut Vec<u8>, memo: Option<&str>) {
+    match memo {
+        Some(memo) => {
+            data.push(1);
+            data.extend_from_slice(&(memo.len() as u32).to_le_bytes());
+            data.extend_from_slice(memo.as_bytes());
+        }
+        None => data.push(0),
+    }
+}
+
+/// Stores `transaction_message` as the multisig's next vault transaction.
+/// `transaction_index` must be the multisig's current index plus one.
+pub fn vault_transaction_create(
+    multisig: &Pubkey,
+    creator: &Pubkey,
+    transaction_index: u64,
+    vault_index: u8,
+    transaction_message: &[u8],
+    memo: Option<&str>,
+) -> Instruction {
+    let mut data = instruction_data("vault_transaction_create");
+    data.push(vault_index);
+    // Ephemeral signers.
+    data.push(0);
+    data.extend_from_slice(&(transaction_message.len() as u32).to_le_bytes());
+    data.extend_from_slice(transaction_message);
+    push_memo(&mut data, memo);
+
+    Instruction {
+        program_id: SQUADS_PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new(*multisig, false),
+            AccountMeta::new(transaction_address(multisig, transaction_index), false),
+            AccountMeta::new_readonly(*creator, true),
+            AccountMeta::new(*creator, true),
+            AccountMeta::new_readonly(system_program::id(), false),
+        ],
+        data,
+    }
+}
+
+/// Opens voting on transaction `transaction_index`.
+pub fn proposal_create(multisig: &Pubkey, creator: &Pubkey, transaction_index: u64) -> Instruction {
+    let mut data = instruction_data("proposal_create");
+    data.extend_from_slice(&transaction_index.to_le_bytes());
+    // Not a draft.
+    data.push(0);
+
+    Instruction {
+        program_id: SQUADS_PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new_readonly(*multisig, false),
+            AccountMeta::new(proposal_address(multisig, transaction_index), false),
+            AccountMeta::new_readonly(*creator, true),
+            AccountMeta::new(*creator, true),
+            AccountMeta::new_readonly(system_program::id(), false),
+        ],
+        data,
+    }
+}
+
+fn proposal_vote(name: &str, multisig: &Pubkey, member: &Pubkey, transaction_index: u64) -> Instruction {
+    let mut data = instruction_data(name);
+    push_memo(&mut data, None);
+
+    Instruction {
+        program_id: SQUADS_PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new_readonly(*multisig, false),
+            AccountMeta::new(*member, true),
+            AccountMeta::new(proposal_address(multisig, transaction_index), false),
+        ],
+        data,
+    }
+}
+
+pub fn proposal_approve(multisig: &Pubkey, member: &Pubkey, transaction_index: u64) -> Instruction {
+    proposal_vote("proposal_approve", multisig, member, transaction_index)
+}
+
+pub fn proposal_reject(multisig: &Pubkey, member: &Pubkey, transaction_index: u64) -> Instruction {
+    proposal_vote("proposal_reject", multisig, member, transaction_index)
+}
+
+/// Executes an approved vault transaction. The message accounts are passed
+/// as remaining accounts; the vault signs inside the program, so none of
+/// them are marked as signers here.
+pub fn vault_transaction_execute(
+    multisig: &Pubkey,
+    member: &Pubkey,
+    transaction_index: u64,
+    transaction: &VaultTransaction,
+) -> Result<Instruction, String> {
+    if transaction.has_address_table_lookups {
+        return Err("Transactions using address lookup tables are not supported".to_string());
+    }
+
+    let mut accounts = vec![
+        AccountMeta::new_readonly(*multisig, false),
+        AccountMeta::new(proposal_address(multisig, transaction_index), false),
+        AccountMeta::new_readonly(transaction.address, false),
+        AccountMeta::new_readonly(*member, true),
+    ];
+    accounts.extend(transaction.account_keys.iter().enumerate().map(|(index, key)| {
+        if transaction.is_writable(index) {
+            AccountMeta::new(*key, false)
+        } else {
+            AccountMeta::new_readonly(*key, false)
+        }
+    }));
+
+    Ok(Instruction {
+        program_id: SQUADS_PROGRAM_ID,
+        accounts,
+        data: instruction_data("vault_transaction_execute"),
+    })
+}
+
+/// Instructions proposing `instructions` from vault `vault_index`: create the
+/// vault transaction and open its proposal. The creator's approval is
+/// recorded too when `approve` is set, which needs the vote permission.
+pub fn propose_vault_transaction(
+    multisig: &Pubkey,
+    creator: &Pubkey,
+    transaction_index: u64,
+    vault_index: u8,
+    instructions: &[Instruction],
+    memo: Option<&str>,
+    approve: bool,
+) -> Result<Vec<Instruction>, String> {
+    let vault = vault_address(multisig, vault_index);
+    let message = vault_transaction_message(&vault, instructions)?;
+    let mut proposal = vec![
+        vault_transaction_create(multisig, creator, transaction_index, vault_index, &message, memo),
+        proposal_create(multisig, creator, transaction_index),
+    ];
+    if approve {
+        proposal.push(proposal_approve(multisig, creator, transaction_index));
+    }
+    Ok(proposal)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use solana_sdk::system_instruction;
+
+    const VAULT_TRANSACTION_CREATE: [u8; 8] = [48, 250, 78, 168, 208, 226, 218, 211];
+    const PROPOSAL_APPROVE: [u8; 8] = [144, 37, 164, 136, 188, 216, 42, 248];
+    const VAULT_TRANSACTION_EXECUTE: [u8; 8] = [194, 8, 161, 87, 153, 164, 25, 171];
+
+    /// Lamport transfer instruction data: tag 2, then the amount.
+    const TRANSFER_DATA: [u8; 12] = [2, 0, 0, 0, 64, 66, 15, 0, 0, 0, 0, 0];
+
+    fn key(byte: u8) -> Pubkey {
+        Pubkey::new_from_array([byte; 32])
+    }
+
+    fn transfer_from(vault: &Pubkey) -> Instruction {
+        system_instruction::transfer(vault, &key(6), 1_000_000)
+    }
+
+    #[test]
+    fn serializes_vault_transaction_messages() {
+        let vault = key(5);
+        let message = vault_transaction_message(&vault, &[transfer_from(&vault)]).unwrap();
+
+        let mut expected = vec![1, 1, 1, 3];
+        expected.extend([5; 32]);
+        expected.extend([6; 32]);
+        expected.extend(system_program::id().to_bytes());
+        expected.extend([1, 2, 2, 0, 1, 12, 0]);
+        expected.extend(TRANSFER_DATA);
+        expected.push(0);
+        assert_eq!(message, expected);
+    }
+
+    #[test]
+    fn creates_vault_transactions() {
+        let multisig = key(1);
+        let creator = key(2);
+        let message = vault_transaction_message(&key(5), &[transfer_from(&key(5))]).unwrap();
+        let instruction = vault_transaction_create(&multisig, &creator, 5, 0, &message, Some("rent"));
+
+        let mut expected = VAULT_TRANSACTION_CREATE.to_vec();
+        expected.extend([0, 0]);
+        expected.extend((message.len() as u32).to_le_bytes());
+        expected.extend(&message);
+        expected.extend([1, 4, 0, 0, 0]);
+        expected.extend(b"rent");
+        assert_eq!(instruction.data, expected);
+        assert_eq!(
+            instruction.accounts,
+            vec![
+                AccountMeta::new(multisig, false),
+                AccountMeta::new(transaction_address(&multisig, 5), false),
+                AccountMeta::new_readonly(creator, true),
+                AccountMeta::new(creator, true),
+                AccountMeta::new_readonly(system_program::id(), false),
+            ]
+        );
+    }
+
+    #[test]
+    fn approves_proposals() {
+        let instruction = proposal_approve(&key(1), &key(2), 5);
+        assert_eq!(instruction.data, [&PROPOSAL_APPROVE[..], &[0][..]].concat());
+        assert_eq!(
+            instruction.accounts,
+            vec![
+                AccountMeta::new_readonly(key(1), false),
+                AccountMeta::new(key(2), true),
+                AccountMeta::new(proposal_address(&key(1), 5), false),
+            ]
+        );
+    }
+
+    #[test]
+    fn executes_with_the_message_accounts_last() {
+        let transaction = VaultTransaction {
+            address: transaction_address(&key(1), 5),
+            vault_index: 0,
+            num_signers: 1,
+            num_writable_signers: 1,
+            num_writable_non_signers: 1,
+            account_keys: vec![key(5), key(6), system_program::id()],
+            has_address_table_lookups: false,
+        };
+        let instruction = vault_transaction_execute(&key(1), &key(2), 5, &transaction).unwrap();
+        assert_eq!(instruction.data, VAULT_TRANSACTION_EXECUTE);
+        assert_eq!(
+            instruction.accounts,
+            vec![
+                AccountMeta::new_readonly(key(1), false),
+                AccountMeta::new(proposal_address(&key(1), 5), false),
+                AccountMeta::new_readonly(transaction.address, false),
+                AccountMeta::new_readonly(key(2), true),
+                AccountMeta::new(key(5), false),
+                AccountMeta::new(key(6), false),
+                AccountMeta::new_readonly(system_program::id(), false),
+            ]
+        );
+
+        let with_lookups = VaultTransaction { has_address_table_lookups: true, ..transaction };
+        assert!(vault_transaction_execute(&key(1), &key(2), 5, &with_lookups).is_err());
+    }
+
+    #[test]
+    fn approves_new_proposals_only_when_asked() {
+        let vault = vault_address(&key(1), 0);
+        let transfer = [transfer_from(&vault)];
+        let without_vote = propose_vault_transaction(&key(1), &key(2), 5, 0, &transfer, None, false).unwrap();
+        assert_eq!(without_vote.len(), 2);
+
+        let with_vote = propose_vault_transaction(&key(1), &key(2), 5, 0, &transfer, None, true).unwrap();
+        assert_eq!(with_vote.len(), 3);
+        assert_eq!(with_vote[2], proposal_approve(&key(1), &key(2), 5));
+    }
+}
@@ -0,0 +1,48 @@
+mod instructions;
+mod state;
+
+pub use instructions::*;
+pub use state::*;
+
+use solana_sdk::{pubkey, pubkey::Pubkey};
+
+/// Squads v4 multisig program.
+pub const SQUADS_PROGRAM_ID: Pubkey = pubkey!("SQDS4ep65T869zMMBKyuUq6aD6EgTu8psMjkvj52pCf");
+
+const SEED_PREFIX: &[u8] = b"multisig";
+const SEED_VAULT: &[u8] = b"vault";
+const SEED_TRANSACTION: &[u8] = b"transaction";
+const SEED_PROPOSAL: &[u8] = b"proposal";
+
+/// Vault `vault_index` of `multisig`; the account that holds the funds.
+pub fn vault_address(multisig: &Pubkey, vault_index: u8) -> Pubkey {
+    Pubkey::find_program_address(
+        &[SEED_PREFIX, multisig.as_ref(), SEED_VAULT, &[vault_index]],
+        &SQUADS_PROGRAM_ID,
+    )
+    .0
+}
+
+/// Vault transaction number `transaction_index` of `multisig`.
+pub fn transaction_address(multisig: &Pubkey, transaction_index: u64) -> Pubkey {
+    Pubkey::find_program_address(
+        &[SEED_PREFIX, multisig.as_ref(), SEED_TRANSACTION, &transaction_index.to_le_bytes()],
+        &SQUADS_PROGRAM_ID,
+    )
+    .0
+}
+
+/// Proposal voting on transaction `transaction_index` of `multisig`.
+pub fn proposal_address(multisig: &Pubkey, transaction_index: u64) -> Pubkey {
+    Pubkey::find_program_address(
+        &[
+            SEED_PREFIX,
+            multisig.as_ref(),
+            SEED_TRANSACTION,
+            &transaction_index.to_le_bytes(),
+            SEED_PROPOSAL,
+        ],
+        &SQUADS_PROGRAM_ID,
+    )
+    .0
+}
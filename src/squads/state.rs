@@ -0,0 +1,486 @@
+use solana_sdk::pubkey::Pubkey;
+
+use crate::anchor::sighash;
+
+/// Member permission bits.
+pub const PERMISSION_INITIATE: u8 = 1 << 0;
+pub const PERMISSION_VOTE: u8 = 1 << 1;
+pub const PERMISSION_EXECUTE: u8 = 1 << 2;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Member {
+    pub key: Pubkey,
+    pub permissions: u8,
+}
+
+impl Member {
+    pub fn can(&self, permission: u8) -> bool {
+        self.permissions & permission == permission
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Multisig {
+    pub address: Pubkey,
+    pub threshold: u16,
+    /// Seconds between approval and execution.
+    pub time_lock: u32,
+    /// Index of the most recently created transaction.
+    pub transaction_index: u64,
+    /// Transactions at or below this index can no longer be executed.
+    pub stale_transaction_index: u64,
+    pub members: Vec<Member>,
+}
+
+impl Multisig {
+    pub fn member(&self, key: &Pubkey) -> Option<&Member> {
+        self.members.iter().find(|member| member.key == *key)
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ProposalStatus {
+    Draft,
+    Active,
+    Rejected,
+    Approved { timestamp: i64 },
+    Executing,
+    Executed,
+    Cancelled,
+}
+
+impl ProposalStatus {
+    pub fn label(&self) -> &'static str {
+        match self {
+            ProposalStatus::Draft => "Draft",
+            ProposalStatus::Active => "Active",
+            ProposalStatus::Rejected => "Rejected",
+            ProposalStatus::Approved { .. } => "Approved",
+            ProposalStatus::Executing => "Executing",
+            ProposalStatus::Executed => "Executed",
+            ProposalStatus::Cancelled => "Cancelled",
+        }
+    }
+
+    /// Still waiting on votes or execution.
+    pub fn is_pending(&self) -> bool {
+        matches!(self, ProposalStatus::Draft | ProposalStatus::Active | ProposalStatus::Approved { .. })
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Proposal {
+    pub address: Pubkey,
+    pub transaction_index: u64,
+    pub status: ProposalStatus,
+    pub approved: Vec<Pubkey>,
+    pub rejected: Vec<Pubkey>,
+}
+
+impl Proposal {
+    pub fn has_voted(&self, member: &Pubkey) -> bool {
+        self.approved.contains(member) || self.rejected.contains(member)
+    }
+
+    /// Unix timestamp from which the approved transaction can be executed,
+    /// once the multisig's time lock has passed.
+    pub fn executable_at(&self, time_lock: u32) -> Option<i64> {
+        match self.status {
+            ProposalStatus::Approved { timestamp } => Some(timestamp.saturating_add(time_lock as i64)),
+            _ => None,
+        }
+    }
+}
+
+/// The parts of a vault transaction needed to execute it.
+#[derive(Clone, Debug, PartialEq)]
+pub struct VaultTransaction {
+    pub address: Pubkey,
+    pub vault_index: u8,
+    pub num_signers: u8,
+    pub num_writable_signers: u8,
+    pub num_writable_non_signers: u8,
+    pub account_keys: Vec<Pubkey>,
+    pub has_address_table_lookups: bool,
+}
+
+impl VaultTransaction {
+    /// Writability of message account `index`, following the key ordering of
+    /// a compiled message.
+    pub fn is_writable(&self, index: usize) -> bool {
+        let num_signers = self.num_signers as usize;
+        if index < num_signers {
+            index < self.num_writable_signers as usize
+        } else {
+            index - num_signers < self.num_writable_non_signers as usize
+        }
+    }
+}
+
+/// Little-endian reader over Borsh-serialized account data.
+struct Reader<'a> {
+    data: &'a [u8],
+}
+
+impl<'a> Reader<'a> {
+    fn new(data: &'a [u8], account: &str) -> Result<Self, String> {
+        let disc = sighash("account", account);
+        match data.strip_prefix(&disc[..]) {
+            Some(data) => Ok(Reader { data }),
+            None => Err(format!("Not a Squads {} account", account)),
+        }
+    }
+
+    fn take(&mut self, len: usize) -> Result<&'a [u8], String> {
+        if self.data.len() < len {
+            return Err("Account data is too short".to_string());
+        }
+        let (head, tail) = self.data.split_at(len);
+        self.data = tail;
+        Ok(head)
+    }
+
+    fn u8(&mut self) -> Result<u8, String> {
+        Ok(self.take(1)?[0])
+    }
+
+    fn u16(&mut self) -> Result<u16, String> {
+        Ok(u16::from_le_bytes(self.take(2)?.try_into().unwrap()))
+    }
+
+    fn u32(&mut self) -> Result<u32, String> {
+        Ok(u32::from_le_bytes(self.take(4)?.try_into().unwrap()))
+    }
+
+    fn u64(&mut self) -> Result<u64, String> {
+        Ok(u64::from_le_bytes(self.take(8)?.try_into().unwrap()))
+    }
+
+    fn i64(&mut self) -> Result<i64, String> {
+        Ok(i64::from_le_bytes(self.take(8)?.try_into().unwrap()))
+    }
+
+    fn pubkey(&mut self) -> Result<Pubkey, String> {
+        Ok(Pubkey::new_from_array(self.take(32)?.try_into().unwrap()))
+    }
+
+    fn option_pubkey(&mut self) -> Result<Option<Pubkey>, String> {
+        match self.u8()? {
+            0 => Ok(None),
+            _ => self.pubkey().map(Some),
+        }
+    }
+
+    fn pubkeys(&mut self) -> Result<Vec<Pubkey>, String> {
+        let len = self.u32()? as usize;
+        (0..len).map(|_| self.pubkey()).collect()
+    }
+
+    fn bytes(&mut self) -> Result<&'a [u8], String> {
+        let len = self.u32()? as usize;
+        self.take(len)
+    }
+}
+
+pub fn parse_multisig(address: Pubkey, data: &[u8]) -> Result<Multisig, String> {
+    let mut reader = Reader::new(data, "Multisig")?;
+    let _create_key = reader.pubkey()?;
+    let _config_authority = reader.pubkey()?;
+    let threshold = reader.u16()?;
+    let time_lock = reader.u32()?;
+    let transaction_index = reader.u64()?;
+    let stale_transaction_index = reader.u64()?;
+    let _rent_collector = reader.option_pubkey()?;
+    let _bump = reader.u8()?;
+    let len = reader.u32()? as usize;
+    let members = (0..len)
+        .map(|_| {
+            Ok(Member {
+                key: reader.pubkey()?,
+                permissions: reader.u8()?,
+            })
+        })
+        .collect::<Result<Vec<_>, String>>()?;
+
+    Ok(Multisig {
+        address,
+        threshold,
+        time_lock,
+        transaction_index,
+        stale_transaction_index,
+        members,
+    })
+}
+
+pub fn parse_proposal(address: Pubkey, data: &[u8]) -> Result<Proposal, String> {
+    let mut reader = Reader::new(data, "Proposal")?;
+    let _multisig = reader.pubkey()?;
+    let transaction_index = reader.u64()?;
+    let status = match reader.u8()? {
+        0 => ProposalStatus::Draft,
+        1 => ProposalStatus::Active,
+        2 => ProposalStatus::Rejected,
+        3 => ProposalStatus::Approved { timestamp: reader.i64()? },
+        4 => ProposalStatus::Executing,
+        5 => ProposalStatus::Executed,
+        6 => ProposalStatus::Cancelled,
+        tag => return Err(format!("Unknown proposal status {}", tag)),
+    };
+    // Every status except `Executing` carries a timestamp.
+    if !matches!(status, ProposalStatus::Approved { .. } | ProposalStatus::Executing) {
+        reader.i64()?;
+    }
+    let _bump = reader.u8()?;
+    let approved = reader.pubkeys()?;
+    let rejected = reader.pubkeys()?;
+
+    Ok(Proposal {
+        address,
+        transaction_index,
+        status,
+        approved,
+        rejected,
+    })
+}
+
+pub fn parse_vault_transaction(address: Pubkey, data: &[u8]) -> Result<VaultTransaction, String> {
+    let mut reader = Reader::new(data, "VaultTransaction")?;
+    let _multisig = reader.pubkey()?;
+    let _creator = reader.pubkey()?;
+    let _index = reader.u64()?;
+    let _bump = reader.u8()?;
+    let vault_index = reader.u8()?;
+    let _vault_bump = reader.u8()?;
+    let ephemeral_signer_bumps = reader.bytes()?;
+    if !ephemeral_signer_bumps.is_empty() {
+        return Err("Transactions with ephemeral signers are not supported".to_string());
+    }
+
+    let num_signers = reader.u8()?;
+    let num_writable_signers = reader.u8()?;
+    let num_writable_non_signers = reader.u8()?;
+    let account_keys = reader.pubkeys()?;
+
+    // Instructions are only needed by the program itself.
+    let num_instructions = reader.u32()?;
+    for _ in 0..num_instructions {
+        reader.u8()?;
+        reader.bytes()?;
+        reader.bytes()?;
+    }
+    let has_address_table_lookups = reader.u32()? > 0;
+
+    Ok(VaultTransaction {
+        address,
+        vault_index,
+        num_signers,
+        num_writable_signers,
+        num_writable_non_signers,
+        account_keys,
+        has_address_table_lookups,
+    })
+}
+
+#[cfg(feature = "web")]
+async fn fetch_account_data(client: &solana_client_wasm::WasmClient, address: &Pubkey) -> Result<Vec<u8>, String> {
+    use crate::solana::rpc_call;
+
+    rpc_call("getAccountInfo", client.get_account(address))
+        .await
+        .map(|account| account.data)
+        .map_err(|e| format!("Failed to fetch account {}: {:?}", address, e))
+}
+
+#[cfg(feature = "web")]
+pub async fn fetch_multisig(client: &solana_client_wasm::WasmClient, address: &Pubkey) -> Result<Multisig, String> {
+    parse_multisig(*address, &fetch_account_data(client, address).await?)
+}
+
+#[cfg(feature = "web")]
+pub async fn fetch_vault_transaction(
+    client: &solana_client_wasm::WasmClient,
+    multisig: &Pubkey,
+    transaction_index: u64,
+) -> Result<VaultTransaction, String> {
+    let address = super::transaction_address(multisig, transaction_index);
+    parse_vault_transaction(address, &fetch_account_data(client, &address).await?)
+}
+
+/// Proposals of the most recent `limit` transactions that are not stale,
+/// newest first. Transactions without a proposal account are skipped.
+#[cfg(feature = "web")]
+pub async fn fetch_proposals(
+    client: &solana_client_wasm::WasmClient,
+    multisig: &Multisig,
+    limit: u64,
+) -> Result<Vec<Proposal>, String> {
+    use crate::solana::rpc_call;
+
+    let first = multisig
+        .stale_transaction_index
+        .max(multisig.transaction_index.saturating_sub(limit))
+        + 1;
+    let addresses: Vec<Pubkey> = (first..=multisig.transaction_index)
+        .rev()
+        .map(|index| super::proposal_address(&multisig.address, index))
+        .collect();
+    if addresses.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let accounts = rpc_call("getMultipleAccounts", client.get_multiple_accounts(&addresses))
+        .await
+        .map_err(|e| format!("Failed to fetch proposals: {:?}", e))?;
+
+    addresses
+        .into_iter()
+        .zip(accounts)
+        .filter_map(|(address, account)| account.map(|account| parse_proposal(address, &account.data)))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MULTISIG_DISCRIMINATOR: [u8; 8] = [224, 116, 121, 186, 68, 161, 79, 236];
+    const PROPOSAL_DISCRIMINATOR: [u8; 8] = [26, 94, 189, 187, 116, 136, 53, 33];
+    const VAULT_TRANSACTION_DISCRIMINATOR: [u8; 8] = [168, 250, 162, 100, 81, 14, 162, 207];
+
+    fn key(byte: u8) -> Pubkey {
+        Pubkey::new_from_array([byte; 32])
+    }
+
+    #[test]
+    fn reads_multisig_accounts() {
+        let mut data = MULTISIG_DISCRIMINATOR.to_vec();
+        data.extend([1; 32]); // create key
+        data.extend([0; 32]); // config authority
+        data.extend(2u16.to_le_bytes());
+        data.extend(3600u32.to_le_bytes());
+        data.extend(12u64.to_le_bytes());
+        data.extend(9u64.to_le_bytes());
+        data.push(1); // rent collector
+        data.extend([2; 32]);
+        data.push(255); // bump
+        data.extend(2u32.to_le_bytes());
+        data.extend([3; 32]);
+        data.push(PERMISSION_INITIATE | PERMISSION_VOTE | PERMISSION_EXECUTE);
+        data.extend([4; 32]);
+        data.push(PERMISSION_VOTE);
+
+        let multisig = parse_multisig(key(9), &data).unwrap();
+        assert_eq!(
+            multisig,
+            Multisig {
+                address: key(9),
+                threshold: 2,
+                time_lock: 3600,
+                transaction_index: 12,
+                stale_transaction_index: 9,
+                members: vec![
+                    Member { key: key(3), permissions: 7 },
+                    Member { key: key(4), permissions: 2 },
+                ],
+            }
+        );
+        assert!(multisig.member(&key(4)).is_some_and(|member| member.can(PERMISSION_VOTE)));
+        assert!(!multisig.member(&key(4)).is_some_and(|member| member.can(PERMISSION_INITIATE)));
+
+        assert!(parse_multisig(key(9), &data[..data.len() - 1]).is_err());
+        assert!(parse_multisig(key(9), &PROPOSAL_DISCRIMINATOR).is_err());
+    }
+
+    fn proposal_data(status: &[u8]) -> Vec<u8> {
+        let mut data = PROPOSAL_DISCRIMINATOR.to_vec();
+        data.extend([1; 32]); // multisig
+        data.extend(5u64.to_le_bytes());
+        data.extend_from_slice(status);
+        data.push(254); // bump
+        data.extend(1u32.to_le_bytes());
+        data.extend([3; 32]);
+        data.extend(1u32.to_le_bytes());
+        data.extend([4; 32]);
+        data
+    }
+
+    #[test]
+    fn reads_proposal_accounts() {
+        let mut active = vec![1];
+        active.extend(1_700_000_000i64.to_le_bytes());
+        assert_eq!(
+            parse_proposal(key(9), &proposal_data(&active)).unwrap(),
+            Proposal {
+                address: key(9),
+                transaction_index: 5,
+                status: ProposalStatus::Active,
+                approved: vec![key(3)],
+                rejected: vec![key(4)],
+            }
+        );
+
+        let mut approved = vec![3];
+        approved.extend(1_700_000_000i64.to_le_bytes());
+        let proposal = parse_proposal(key(9), &proposal_data(&approved)).unwrap();
+        assert_eq!(proposal.status, ProposalStatus::Approved { timestamp: 1_700_000_000 });
+        assert_eq!(proposal.executable_at(60), Some(1_700_000_060));
+        assert!(proposal.has_voted(&key(3)) && proposal.has_voted(&key(4)));
+
+        // `Executing` is the one status without a timestamp.
+        let executing = parse_proposal(key(9), &proposal_data(&[4])).unwrap();
+        assert_eq!(executing.status, ProposalStatus::Executing);
+        assert_eq!(executing.executable_at(60), None);
+
+        assert!(parse_proposal(key(9), &proposal_data(&[7])).is_err());
+    }
+
+    fn vault_transaction_data(ephemeral_signer_bumps: &[u8], num_lookups: u32) -> Vec<u8> {
+        let mut data = VAULT_TRANSACTION_DISCRIMINATOR.to_vec();
+        data.extend([1; 32]); // multisig
+        data.extend([2; 32]); // creator
+        data.extend(5u64.to_le_bytes());
+        data.push(254); // bump
+        data.push(0); // vault index
+        data.push(253); // vault bump
+        data.extend((ephemeral_signer_bumps.len() as u32).to_le_bytes());
+        data.extend_from_slice(ephemeral_signer_bumps);
+        data.extend([1, 1, 1]);
+        data.extend(3u32.to_le_bytes());
+        data.extend([5; 32]);
+        data.extend([6; 32]);
+        data.extend([0; 32]);
+        data.extend(1u32.to_le_bytes());
+        data.push(2); // program id index
+        data.extend(2u32.to_le_bytes());
+        data.extend([0, 1]);
+        data.extend(12u32.to_le_bytes());
+        data.extend([2, 0, 0, 0, 64, 66, 15, 0, 0, 0, 0, 0]);
+        data.extend(num_lookups.to_le_bytes());
+        data
+    }
+
+    #[test]
+    fn reads_vault_transaction_accounts() {
+        let transaction = parse_vault_transaction(key(9), &vault_transaction_data(&[], 0)).unwrap();
+        assert_eq!(
+            transaction,
+            VaultTransaction {
+                address: key(9),
+                vault_index: 0,
+                num_signers: 1,
+                num_writable_signers: 1,
+                num_writable_non_signers: 1,
+                account_keys: vec![key(5), key(6), key(0)],
+                has_address_table_lookups: false,
+            }
+        );
+        assert_eq!(
+            (0..3).map(|index| transaction.is_writable(index)).collect::<Vec<_>>(),
+            vec![true, true, false]
+        );
+
+        let with_lookups = parse_vault_transaction(key(9), &vault_transaction_data(&[], 1)).unwrap();
+        assert!(with_lookups.has_address_table_lookups);
+        assert!(parse_vault_transaction(key(9), &vault_transaction_data(&[255], 0)).is_err());
+    }
+}
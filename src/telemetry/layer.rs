@@ -0,0 +1,152 @@
+use serde::Serialize;
+use serde_json::Value;
+use std::collections::BTreeMap;
+use tracing::field::{Field, Visit};
+use tracing::{span, Event, Level, Subscriber};
+use tracing_subscriber::{layer::Context, registry::LookupSpan, Layer};
+
+use super::{now_ms, TelemetrySink};
+
+/// Events from this crate are always kept; other crates' only from this
+/// level up, so framework chatter doesn't crowd out the app's own events.
+const DEPENDENCY_LEVEL: Level = Level::WARN;
+
+/// One `tracing` event with the fields of the spans it happened in.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct TelemetryEvent {
+    /// Milliseconds since the Unix epoch.
+    pub timestamp_ms: u64,
+    pub level: String,
+    pub target: String,
+    /// Names of the enclosing spans, outermost first.
+    pub spans: Vec<String>,
+    pub message: String,
+    /// Span fields, then the event's own, which win on a name clash.
+    pub fields: BTreeMap<String, Value>,
+}
+
+impl TelemetryEvent {
+    /// One-line rendering, e.g. for bug reports.
+    pub fn summary(&self) -> String {
+        let mut line = if self.spans.is_empty() {
+            format!("{} {}", self.level, self.message)
+        } else {
+            format!("{} [{}] {}", self.level, self.spans.join(":"), self.message)
+        };
+        for (name, value) in &self.fields {
+            line.push_str(&format!(" {}={}", name, value));
+        }
+        line
+    }
+}
+
+/// Fields recorded on a span, kept in its extensions until it closes.
+struct SpanFields(BTreeMap<String, Value>);
+
+#[derive(Default)]
+struct FieldVisitor {
+    message: Option<String>,
+    fields: BTreeMap<String, Value>,
+}
+
+impl Visit for FieldVisitor {
+    fn record_debug(&mut self, field: &Field, value: &dyn std::fmt::Debug) {
+        if field.name() == "message" {
+            self.message = Some(format!("{:?}", value));
+        } else {
+            self.fields.insert(field.name().to_string(), Value::from(format!("{:?}", value)));
+        }
+    }
+
+    fn record_str(&mut self, field: &Field, value: &str) {
+        if field.name() == "message" {
+            self.message = Some(value.to_string());
+        } else {
+            self.fields.insert(field.name().to_string(), Value::from(value));
+        }
+    }
+
+    fn record_i64(&mut self, field: &Field, value: i64) {
+        self.fields.insert(field.name().to_string(), Value::from(value));
+    }
+
+    fn record_u64(&mut self, field: &Field, value: u64) {
+        self.fields.insert(field.name().to_string(), Value::from(value));
+    }
+
+    fn record_f64(&mut self, field: &Field, value: f64) {
+        self.fields.insert(field.name().to_string(), Value::from(value));
+    }
+
+    fn record_bool(&mut self, field: &Field, value: bool) {
+        self.fields.insert(field.name().to_string(), Value::from(value));
+    }
+}
+
+/// `tracing` layer turning events into [`TelemetryEvent`]s for its sinks.
+pub struct TelemetryLayer {
+    sinks: Vec<Box<dyn TelemetrySink>>,
+}
+
+impl TelemetryLayer {
+    pub fn new(sinks: Vec<Box<dyn TelemetrySink>>) -> Self {
+        Self { sinks }
+    }
+}
+
+impl<S> Layer<S> for TelemetryLayer
+where
+    S: Subscriber + for<'a> LookupSpan<'a>,
+{
+    fn on_new_span(&self, attrs: &span::Attributes<'_>, id: &span::Id, ctx: Context<'_, S>) {
+        if let Some(span) = ctx.span(id) {
+            let mut visitor = FieldVisitor::default();
+            attrs.record(&mut visitor);
+            span.extensions_mut().insert(SpanFields(visitor.fields));
+        }
+    }
+
+    fn on_record(&self, id: &span::Id, values: &span::Record<'_>, ctx: Context<'_, S>) {
+        if let Some(span) = ctx.span(id) {
+            let mut visitor = FieldVisitor::default();
+            values.record(&mut visitor);
+            if let Some(SpanFields(fields)) = span.extensions_mut().get_mut::<SpanFields>() {
+                fields.extend(visitor.fields);
+            }
+        }
+    }
+
+    fn on_event(&self, event: &Event<'_>, ctx: Context<'_, S>) {
+        let metadata = event.metadata();
+        let own = metadata.target().starts_with(env!("CARGO_CRATE_NAME"));
+        if !own && *metadata.level() > DEPENDENCY_LEVEL {
+            return;
+        }
+
+        let mut spans = Vec::new();
+        let mut fields = BTreeMap::new();
+        if let Some(scope) = ctx.event_scope(event) {
+            for span in scope.from_root() {
+                spans.push(span.name().to_string());
+                if let Some(SpanFields(span_fields)) = span.extensions().get::<SpanFields>() {
+                    fields.extend(span_fields.clone());
+                }
+            }
+        }
+        let mut visitor = FieldVisitor::default();
+        event.record(&mut visitor);
+        fields.extend(visitor.fields);
+
+        let event = TelemetryEvent {
+            timestamp_ms: now_ms() as u64,
+            level: metadata.level().to_string(),
+            target: metadata.target().to_string(),
+            spans,
+            message: visitor.message.unwrap_or_default(),
+            fields,
+        };
+        for sink in &self.sinks {
+            sink.record(&event);
+        }
+    }
+}
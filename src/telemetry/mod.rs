@@ -0,0 +1,101 @@
+//! Structured client-side telemetry. `tracing` events from the app are
+//! logged to the console and handed to [`TelemetrySink`]s: the in-memory
+//! [`RingBufferSink`] always, and the [`HttpCollectorSink`] when built for
+//! the web, which posts batches to the collector picked in settings.
+//!
+//! Events carry the cluster name, never the RPC URL (which can hold an API
+//! key), and no keys, wallet addresses or transaction bytes.
+
+mod layer;
+mod sink;
+
+pub use layer::*;
+pub use sink::*;
+
+use std::fmt::Debug;
+use std::future::Future;
+use tracing::Level;
+use tracing_subscriber::{
+    filter::LevelFilter,
+    layer::SubscriberExt,
+    util::{SubscriberInitExt, TryInitError},
+};
+
+/// Installs the global subscriber: console output plus the telemetry sinks,
+/// both at `level` and above.
+pub fn init(level: Level) -> Result<(), TryInitError> {
+    #[cfg(feature = "web")]
+    let console = tracing_wasm::WASMLayer::new(tracing_wasm::WASMLayerConfig::default());
+    #[cfg(not(feature = "web"))]
+    let console = tracing_subscriber::fmt::layer();
+
+    #[allow(unused_mut)]
+    let mut sinks: Vec<Box<dyn TelemetrySink>> = vec![Box::new(RingBufferSink)];
+    #[cfg(feature = "web")]
+    sinks.push(Box::new(HttpCollectorSink::start()));
+
+    tracing_subscriber::registry()
+        .with(LevelFilter::from_level(level))
+        .with(console)
+        .with(TelemetryLayer::new(sinks))
+        .try_init()
+}
+
+/// Milliseconds since the Unix epoch, for timestamps and latencies.
+/// `std::time::Instant` is not available in the browser.
+pub fn now_ms() -> f64 {
+    #[cfg(feature = "web")]
+    {
+        js_sys::Date::now()
+    }
+
+    #[cfg(not(feature = "web"))]
+    {
+        use std::time::{SystemTime, UNIX_EPOCH};
+        SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|elapsed| elapsed.as_secs_f64() * 1000.0)
+            .unwrap_or_default()
+    }
+}
+
+/// Coarse category of an error message, recorded instead of the message
+/// itself, which can echo endpoint URLs or wallet output.
+pub fn error_kind(error: &str) -> &'static str {
+    let error = error.to_lowercase();
+    if error.contains("reject") || error.contains("denied") || error.contains("cancel") {
+        "rejected"
+    } else if error.contains("not connected") {
+        "wallet_not_connected"
+    } else if error.contains("timed out") || error.contains("timeout") || error.contains("in time") {
+        "timeout"
+    } else if error.contains("insufficient") {
+        "insufficient_funds"
+    } else if error.contains("transaction failed") {
+        "transaction_failed"
+    } else if error.contains("network") || error.contains("fetch") || error.contains("request") {
+        "network"
+    } else {
+        "other"
+    }
+}
+
+/// Awaits `operation`, recording its latency and, on failure, the
+/// [`error_kind`]. Runs inside whatever span the caller entered, so the
+/// event picks up that span's fields.
+pub async fn timed<T, E: Debug>(name: &'static str, operation: impl Future<Output = Result<T, E>>) -> Result<T, E> {
+    let started = now_ms();
+    let result = operation.await;
+    let latency_ms = (now_ms() - started).round() as u64;
+    match &result {
+        Ok(_) => tracing::info!(operation = name, latency_ms, "{} succeeded", name),
+        Err(e) => tracing::warn!(
+            operation = name,
+            latency_ms,
+            error_kind = error_kind(&format!("{:?}", e)),
+            "{} failed",
+            name
+        ),
+    }
+    result
+}
@@ -0,0 +1,112 @@
+use std::collections::VecDeque;
+use std::sync::Mutex;
+
+use super::TelemetryEvent;
+
+/// Where telemetry events go. Sinks run inside `tracing`'s dispatch, so they
+/// must not block, and must not emit `tracing` events themselves.
+pub trait TelemetrySink: Send + Sync + 'static {
+    fn record(&self, event: &TelemetryEvent);
+}
+
+/// How many events [`RingBufferSink`] keeps.
+const RING_BUFFER_CAPACITY: usize = 500;
+
+static RECENT_EVENTS: Mutex<VecDeque<TelemetryEvent>> = Mutex::new(VecDeque::new());
+
+/// Keeps the latest events in memory, dropping the oldest.
+pub struct RingBufferSink;
+
+impl TelemetrySink for RingBufferSink {
+    fn record(&self, event: &TelemetryEvent) {
+        if let Ok(mut events) = RECENT_EVENTS.lock() {
+            if events.len() == RING_BUFFER_CAPACITY {
+                events.pop_front();
+            }
+            events.push_back(event.clone());
+        }
+    }
+}
+
+/// Events kept by the [`RingBufferSink`], oldest first. Empty if the buffer
+/// is being written, e.g. when called from the panic hook mid-event.
+pub fn recent_events() -> Vec<TelemetryEvent> {
+    RECENT_EVENTS
+        .try_lock()
+        .map(|events| events.iter().cloned().collect())
+        .unwrap_or_default()
+}
+
+/// Batches are posted once this many events are pending, or every
+/// [`COLLECTOR_FLUSH_MS`], whichever comes first.
+#[cfg(feature = "web")]
+const COLLECTOR_BATCH_SIZE: usize = 50;
+#[cfg(feature = "web")]
+const COLLECTOR_FLUSH_MS: u32 = 10_000;
+
+#[cfg(feature = "web")]
+static PENDING_EVENTS: Mutex<Vec<TelemetryEvent>> = Mutex::new(Vec::new());
+
+/// Posts events in batches to the collector URL from settings, as
+/// `{ "app", "version", "events": [...] }`. Without a URL batches are
+/// dropped, so turning the collector on or off needs no reload.
+#[cfg(feature = "web")]
+pub struct HttpCollectorSink;
+
+#[cfg(feature = "web")]
+impl HttpCollectorSink {
+    /// Creates the sink and starts its flush timer.
+    pub fn start() -> Self {
+        gloo::timers::callback::Interval::new(COLLECTOR_FLUSH_MS, flush_collector).forget();
+        Self
+    }
+}
+
+#[cfg(feature = "web")]
+impl TelemetrySink for HttpCollectorSink {
+    fn record(&self, event: &TelemetryEvent) {
+        let full = match PENDING_EVENTS.lock() {
+            Ok(mut pending) => {
+                pending.push(event.clone());
+                pending.len() >= COLLECTOR_BATCH_SIZE
+            }
+            Err(_) => false,
+        };
+        if full {
+            flush_collector();
+        }
+    }
+}
+
+#[cfg(feature = "web")]
+fn flush_collector() {
+    let batch = match PENDING_EVENTS.lock() {
+        Ok(mut pending) if !pending.is_empty() => std::mem::take(&mut *pending),
+        _ => return,
+    };
+
+    // Sent later, outside the event dispatch that triggered the flush.
+    wasm_bindgen_futures::spawn_local(async move {
+        use crate::hooks::Settings;
+        use gloo::net::http::Request;
+
+        let url = Settings::load().telemetry_url;
+        if url.trim().is_empty() {
+            return;
+        }
+        let body = serde_json::json!({
+            "app": env!("CARGO_PKG_NAME"),
+            "version": env!("CARGO_PKG_VERSION"),
+            "events": batch,
+        });
+        let sent = match Request::post(url.trim()).json(&body) {
+            Ok(request) => request.send().await.map(|_| ()),
+            Err(e) => Err(e),
+        };
+        // Logged straight to the console: a tracing event would be queued
+        // for the collector that just failed.
+        if let Err(e) = sent {
+            web_sys::console::warn_1(&format!("Failed to send telemetry: {}", e).into());
+        }
+    });
+}
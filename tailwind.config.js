@@ -19,6 +19,48 @@ module.exports = {
           800: '#6b21a8',
           900: '#581c87',
         },
+        // Semantic tokens, set per theme in input.css.
+        page: {
+          from: 'rgb(var(--color-page-from) / <alpha-value>)',
+          via: 'rgb(var(--color-page-via) / <alpha-value>)',
+          to: 'rgb(var(--color-page-to) / <alpha-value>)',
+        },
+        surface: {
+          DEFAULT: 'rgb(var(--color-surface) / <alpha-value>)',
+          sunken: 'rgb(var(--color-surface-sunken) / <alpha-value>)',
+          raised: 'rgb(var(--color-surface-raised) / <alpha-value>)',
+          strong: 'rgb(var(--color-surface-strong) / <alpha-value>)',
+        },
+        line: {
+          DEFAULT: 'rgb(var(--color-line) / <alpha-value>)',
+          strong: 'rgb(var(--color-line-strong) / <alpha-value>)',
+        },
+        fg: {
+          DEFAULT: 'rgb(var(--color-fg) / <alpha-value>)',
+          muted: 'rgb(var(--color-fg-muted) / <alpha-value>)',
+          subtle: 'rgb(var(--color-fg-subtle) / <alpha-value>)',
+          faint: 'rgb(var(--color-fg-faint) / <alpha-value>)',
+        },
+        success: {
+          DEFAULT: 'rgb(var(--color-success) / <alpha-value>)',
+          fg: 'rgb(var(--color-success-fg) / <alpha-value>)',
+          surface: 'rgb(var(--color-success-surface) / <alpha-value>)',
+        },
+        danger: {
+          DEFAULT: 'rgb(var(--color-danger) / <alpha-value>)',
+          fg: 'rgb(var(--color-danger-fg) / <alpha-value>)',
+          surface: 'rgb(var(--color-danger-surface) / <alpha-value>)',
+        },
+        warning: {
+          DEFAULT: 'rgb(var(--color-warning) / <alpha-value>)',
+          fg: 'rgb(var(--color-warning-fg) / <alpha-value>)',
+          surface: 'rgb(var(--color-warning-surface) / <alpha-value>)',
+        },
+        info: {
+          DEFAULT: 'rgb(var(--color-info) / <alpha-value>)',
+          fg: 'rgb(var(--color-info-fg) / <alpha-value>)',
+          surface: 'rgb(var(--color-info-surface) / <alpha-value>)',
+        },
       },
     },
   },
@@ -0,0 +1,35 @@
+#!/bin/bash
+# Builds the app, serves it and runs pa11y-ci against the pages in
+# .pa11yci.json. Used by `npm run test:a11y` and CI.
+#
+#   PORT        port the pages in .pa11yci.json point at (default 8080)
+#   SKIP_BUILD  set to reuse an earlier `npm run build`
+set -euo pipefail
+
+cd "$(dirname "$0")/../.."
+
+PORT="${PORT:-8080}"
+
+if [ -z "${SKIP_BUILD:-}" ]; then
+  npm run build
+fi
+
+# dx 0.6 writes to target/dx/<app>/release/web/public.
+DIST="$(find target/dx -type d -path '*/release/web/public' 2>/dev/null | head -n 1)"
+if [ -z "$DIST" ]; then
+  echo "No release build found under target/dx; run npm run build first."
+  exit 1
+fi
+
+node tools/a11y/serve.js "$DIST" "$PORT" &
+SERVER_PID=$!
+trap 'kill "$SERVER_PID" 2>/dev/null || true' EXIT
+
+for _ in $(seq 30); do
+  if curl -sf "http://localhost:$PORT/" >/dev/null; then
+    break
+  fi
+  sleep 1
+done
+
+npx pa11y-ci
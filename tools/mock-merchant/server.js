@@ -0,0 +1,110 @@
+// Minimal Solana Pay transaction request endpoint.
+//
+//   npm install && npm start
+//   open http://localhost:8080/pay?link=solana:http%3A%2F%2Flocalhost%3A8787%2F
+//
+// The returned transaction transfers AMOUNT_SOL from the wallet to a throwaway
+// merchant key, which co-signs through a memo instruction. Append
+// `?mode=bad-payer` or `?mode=extra-signer` to the link to check that the app
+// rejects a wrong fee payer or a missing signature.
+const http = require('http');
+const {
+  Connection,
+  Keypair,
+  LAMPORTS_PER_SOL,
+  PublicKey,
+  SystemProgram,
+  Transaction,
+  TransactionInstruction,
+} = require('@solana/web3.js');
+
+const PORT = Number(process.env.PORT || 8787);
+const RPC_URL = process.env.RPC_URL || 'https://api.mainnet-beta.solana.com';
+const AMOUNT_SOL = Number(process.env.AMOUNT_SOL || 0.001);
+const MEMO_PROGRAM_ID = new PublicKey('MemoSq4gqABAXKb96qnH8TysNcWxMyWCqXgDLGmfcHr');
+
+const connection = new Connection(RPC_URL, 'confirmed');
+const merchant = Keypair.generate();
+
+function send(res, status, body) {
+  res.writeHead(status, {
+    'Content-Type': 'application/json',
+    'Access-Control-Allow-Origin': '*',
+    'Access-Control-Allow-Methods': 'GET, POST, OPTIONS',
+    'Access-Control-Allow-Headers': 'Content-Type, Accept',
+  });
+  res.end(body === undefined ? '' : JSON.stringify(body));
+}
+
+async function buildTransaction(account, mode) {
+  const payer = new PublicKey(account);
+  const { blockhash } = await connection.getLatestBlockhash();
+
+  const transaction = new Transaction({
+    feePayer: mode === 'bad-payer' ? merchant.publicKey : payer,
+    recentBlockhash: blockhash,
+  });
+  transaction.add(
+    SystemProgram.transfer({
+      fromPubkey: payer,
+      toPubkey: merchant.publicKey,
+      lamports: Math.round(AMOUNT_SOL * LAMPORTS_PER_SOL),
+    }),
+    new TransactionInstruction({
+      programId: MEMO_PROGRAM_ID,
+      keys: [{ pubkey: merchant.publicKey, isSigner: true, isWritable: false }],
+      data: Buffer.from('mock-merchant order'),
+    }),
+  );
+
+  if (mode === 'extra-signer') {
+    const stranger = Keypair.generate();
+    transaction.add(
+      new TransactionInstruction({
+        programId: MEMO_PROGRAM_ID,
+        keys: [{ pubkey: stranger.publicKey, isSigner: true, isWritable: false }],
+        data: Buffer.from('unexpected signer'),
+      }),
+    );
+  }
+
+  transaction.partialSign(merchant);
+  return transaction
+    .serialize({ requireAllSignatures: false, verifySignatures: false })
+    .toString('base64');
+}
+
+const server = http.createServer((req, res) => {
+  const mode = new URL(req.url, `http://localhost:${PORT}`).searchParams.get('mode');
+
+  if (req.method === 'OPTIONS') {
+    return send(res, 204);
+  }
+  if (req.method === 'GET') {
+    return send(res, 200, {
+      label: 'Mock Merchant',
+      icon: 'https://solana.com/favicon.ico',
+    });
+  }
+  if (req.method !== 'POST') {
+    return send(res, 405, { error: 'method not allowed' });
+  }
+
+  let body = '';
+  req.on('data', (chunk) => (body += chunk));
+  req.on('end', async () => {
+    try {
+      const { account } = JSON.parse(body);
+      const transaction = await buildTransaction(account, mode);
+      send(res, 200, { transaction, message: `Thanks for testing! (${AMOUNT_SOL} SOL)` });
+    } catch (err) {
+      console.error(err);
+      send(res, 400, { error: err.message });
+    }
+  });
+});
+
+server.listen(PORT, () => {
+  console.log(`Mock merchant listening on http://localhost:${PORT}`);
+  console.log(`Merchant key: ${merchant.publicKey.toBase58()}`);
+});
@@ -1,11 +1,12 @@
-import React, { useMemo, useCallback } from 'react';
+import React, { useMemo, useCallback, useEffect, useRef } from 'react';
 import ReactDOM from 'react-dom/client';
-import { ConnectionProvider, WalletProvider, useWallet } from '@solana/wallet-adapter-react';
+import { ConnectionProvider, WalletProvider, useConnection, useWallet } from '@solana/wallet-adapter-react';
 import {
   BaseWalletMultiButton,
   WalletModalProvider,
+  useWalletModal,
 } from '@solana/wallet-adapter-react-ui';
-import { VersionedTransaction } from '@solana/web3.js';
+import { Connection, VersionedTransaction } from '@solana/web3.js';
 import * as buffer from "buffer";
 window.Buffer = buffer.Buffer;
 
@@ -38,9 +39,12 @@ export const Wallet = () => {
       <WalletProvider wallets={wallets} autoConnect={true}>
         <WalletModalProvider>
           <BaseWalletMultiButton labels={LABELS} />
+          <ModalFocus />
           <Dispatcher />
           <Disconnect />
           <SignTransaction />
+          <SendTransaction />
+          <SignAllTransactions />
           <SignMessage />
         </WalletModalProvider>
       </WalletProvider>
@@ -57,6 +61,40 @@ function MountWalletAdapter() {
 }
 window.MountWalletAdapter = MountWalletAdapter;
 
+// The wallet modal traps Tab and closes on Escape but leaves focus behind.
+// Move focus into the modal when it opens and back to the connect button
+// when it closes.
+function ModalFocus() {
+  const { visible } = useWalletModal();
+  const wasVisible = useRef(false);
+  
+  useEffect(() => {
+    if (visible) {
+      wasVisible.current = true;
+      // The modal renders into a portal after this effect runs.
+      const timer = setTimeout(() => {
+        const target =
+          document.querySelector('.wallet-adapter-modal-list button') ||
+          document.querySelector('.wallet-adapter-modal-button-close');
+        if (target) {
+          target.focus();
+        }
+      }, 0);
+      return () => clearTimeout(timer);
+    }
+    
+    if (wasVisible.current) {
+      wasVisible.current = false;
+      const trigger = document.querySelector('#ore-wallet-adapter .wallet-adapter-button-trigger');
+      if (trigger) {
+        trigger.focus();
+      }
+    }
+  }, [visible]);
+  
+  return null;
+}
+
 // Dispatch wallet public key changes to Rust
 function Dispatcher() {
   const { publicKey } = useWallet();
@@ -135,6 +173,78 @@ function SignTransaction() {
   return null;
 }
 
+// Sign and send a transaction through the wallet's native
+// signAndSendTransaction. Resolves to "UNSUPPORTED" when the wallet has none,
+// so Rust can fall back to signing and sending over its own RPC.
+// msg.rpc and msg.commitment come from the app settings; without them the
+// provider's connection is used.
+function SendTransaction() {
+  const { publicKey, wallet, sendTransaction } = useWallet();
+  const { connection } = useConnection();
+  
+  const callback = useCallback(async (msg) => {
+    try {
+      const features = wallet?.adapter?.wallet?.features;
+      if (!features || !features['solana:signAndSendTransaction']) {
+        return "UNSUPPORTED";
+      }
+      
+      const tx = VersionedTransaction.deserialize(
+        Buffer.from(msg.b64, "base64")
+      );
+      
+      const target = msg.rpc
+        ? new Connection(msg.rpc, msg.commitment || 'confirmed')
+        : connection;
+      const signature = await sendTransaction(tx, target);
+      
+      return signature;
+    } catch (err) {
+      console.error('Error sending transaction:', err);
+      throw err;
+    }
+  }, [publicKey, wallet, sendTransaction, connection]);
+  
+  window.OreTxSender = callback;
+  
+  return null;
+}
+
+// Sign several transactions with a single wallet prompt
+function SignAllTransactions() {
+  const { publicKey, signTransaction, signAllTransactions } = useWallet();
+  
+  const callback = useCallback(async (msg) => {
+    try {
+      const txs = msg.b64s.map((b64) =>
+        VersionedTransaction.deserialize(Buffer.from(b64, "base64"))
+      );
+      
+      let signed;
+      if (signAllTransactions) {
+        signed = await signAllTransactions(txs);
+      } else if (signTransaction) {
+        // Wallets without batch support still work, one prompt per transaction
+        signed = [];
+        for (const tx of txs) {
+          signed.push(await signTransaction(tx));
+        }
+      } else {
+        throw new Error('Wallet does not support transaction signing');
+      }
+      
+      return signed.map((tx) => Buffer.from(tx.serialize()).toString("base64"));
+    } catch (err) {
+      console.error('Error signing transactions:', err);
+      throw err;
+    }
+  }, [publicKey, signTransaction, signAllTransactions]);
+  
+  window.OreTxsSigner = callback;
+  
+  return null;
+}
+
 // Sign message function
 function SignMessage() {
   const { publicKey, signMessage } = useWallet();